@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests;
+
+use crate::options::Input;
+use crate::pcap;
+use std::io::Read;
+
+/// # Summary
+/// A chunk of raw data believed to contain a single L2TP message.
+///
+/// # Data members
+/// * `index` - The one-based index of the packet or line the data was taken from.
+/// * `timestamp` - The capture timestamp as seconds and microseconds, if known.
+/// * `data` - The raw L2TP message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record {
+    pub index: usize,
+    pub timestamp: Option<(u32, u32)>,
+    pub data: Vec<u8>,
+}
+
+/// # Summary
+/// Read all records from the given `Input`.
+pub fn read(input: &Input, port: u16) -> Result<Vec<Record>, String> {
+    match input {
+        Input::Hex(hex) => Ok(vec![Record {
+            index: 1,
+            timestamp: None,
+            data: parse_hex(hex)?,
+        }]),
+        Input::File(path) => {
+            let data = std::fs::read(path).map_err(|e| format!("{path}: {e}"))?;
+            read_pcap(&data, port)
+        }
+        Input::Stdin => {
+            let mut data = Vec::new();
+            std::io::stdin()
+                .read_to_end(&mut data)
+                .map_err(|e| format!("stdin: {e}"))?;
+            if pcap::is_pcap(&data) {
+                read_pcap(&data, port)
+            } else {
+                let text = std::str::from_utf8(&data)
+                    .map_err(|_| "stdin is neither a pcap capture nor hex text".to_owned())?;
+                read_hex_lines(text)
+            }
+        }
+    }
+}
+
+fn read_pcap(data: &[u8], port: u16) -> Result<Vec<Record>, String> {
+    Ok(pcap::udp_payloads(data, port)?
+        .into_iter()
+        .map(|packet| Record {
+            index: packet.index,
+            timestamp: Some(packet.timestamp),
+            data: packet.payload.to_owned(),
+        })
+        .collect())
+}
+
+/// # Summary
+/// Parse one hex-encoded message per non-empty line. Lines starting with `#` are ignored.
+pub fn read_hex_lines(text: &str) -> Result<Vec<Record>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            Ok(Record {
+                index: i + 1,
+                timestamp: None,
+                data: parse_hex(line).map_err(|e| format!("line {}: {e}", i + 1))?,
+            })
+        })
+        .collect()
+}
+
+/// # Summary
+/// Parse a hex string, ignoring an optional `0x` prefix, whitespace and `:` separators.
+pub fn parse_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    let digits = hex
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ':')
+        .map(|c| {
+            c.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or_else(|| format!("Invalid hex digit '{c}'"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if digits.len() % 2 != 0 {
+        return Err("Odd number of hex digits".to_owned());
+    }
+
+    Ok(digits.chunks(2).map(|x| (x[0] << 4) | x[1]).collect())
+}
@@ -0,0 +1,37 @@
+use crate::input::*;
+
+#[test]
+fn parse_hex_valid() {
+    assert_eq!(parse_hex("0xdeadBEEF"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+    assert_eq!(
+        parse_hex(" de:ad be\tef "),
+        Ok(vec![0xde, 0xad, 0xbe, 0xef])
+    );
+    assert_eq!(parse_hex(""), Ok(Vec::new()));
+}
+
+#[test]
+fn parse_hex_invalid() {
+    assert!(parse_hex("dea").is_err());
+    assert!(parse_hex("deag").is_err());
+}
+
+#[test]
+fn hex_lines() {
+    let records = read_hex_lines("# comment\n\n0102\n  0304\n").unwrap();
+    assert_eq!(
+        records,
+        vec![
+            Record {
+                index: 3,
+                timestamp: None,
+                data: vec![0x01, 0x02]
+            },
+            Record {
+                index: 4,
+                timestamp: None,
+                data: vec![0x03, 0x04]
+            }
+        ]
+    );
+}
@@ -0,0 +1,88 @@
+//! `l2tp-dump` decodes L2TP messages from a pcap capture, a hex string or stdin and prints them
+//! either as text or as one JSON object per message.
+//!
+//! Run `l2tp-dump --help` for a list of options.
+
+mod input;
+mod options;
+mod output;
+mod pcap;
+
+use input::Record;
+use options::{Options, USAGE};
+use output::Decoded;
+use rl2tp::common::SliceReader;
+use rl2tp::Message;
+use std::process::ExitCode;
+
+fn decode<'a>(record: &'a Record, options: &Options) -> Decoded<'a> {
+    let mut reader = SliceReader::from(&record.data);
+    let mut message = Message::try_read_validate(&mut reader, options.validation.clone());
+
+    let mut reveal_errors = Vec::new();
+    if let (Ok(Message::Control(control)), Some(secret)) = (&mut message, &options.secret) {
+        reveal_errors = control.reveal_all(secret).err().unwrap_or_default();
+    }
+
+    Decoded {
+        message,
+        reveal_errors,
+    }
+}
+
+fn is_selected(decoded: &Decoded, options: &Options) -> bool {
+    let (tunnel_id, session_id) = match &decoded.message {
+        Ok(Message::Control(control)) => (control.tunnel_id, control.session_id),
+        Ok(Message::Data(data)) => (data.tunnel_id, data.session_id),
+        // Undecodable messages can't be filtered by ID, so only show them when not filtering
+        Err(_) => return options.tunnel_id.is_none() && options.session_id.is_none(),
+    };
+
+    options.tunnel_id.is_none_or(|x| x == tunnel_id)
+        && options.session_id.is_none_or(|x| x == session_id)
+}
+
+fn run(options: &Options) -> Result<bool, String> {
+    let records = input::read(&options.input, options.port)?;
+
+    let mut all_ok = true;
+    for record in records.iter() {
+        let decoded = decode(record, options);
+        if !is_selected(&decoded, options) {
+            continue;
+        }
+
+        all_ok &= decoded.message.is_ok() && decoded.reveal_errors.is_empty();
+
+        if options.json {
+            println!("{}", output::json(record, &decoded));
+        } else {
+            println!("{}", output::text(record, &decoded));
+        }
+    }
+
+    Ok(all_ok)
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("l2tp-dump: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("l2tp-dump: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use rl2tp::{ValidateReserved, ValidateUnused, ValidateVersion, ValidationOptions};
+
+pub const USAGE: &str = "\
+Usage: l2tp-dump [OPTIONS] [FILE]
+
+Decode and print L2TP messages. FILE is a pcap capture; if neither FILE nor --hex is
+given, a pcap capture or hex-encoded messages (one per line) are read from stdin.
+
+Options:
+  -x, --hex <HEX>        Decode a single hex-encoded L2TP message
+  -s, --secret <SECRET>  Reveal hidden AVPs using the shared secret
+  -j, --json             Print one JSON object per message
+  -t, --tunnel <ID>      Only print messages with the given tunnel ID
+  -S, --session <ID>     Only print messages with the given session ID
+  -p, --port <PORT>      UDP port carrying L2TP in pcap input [default: 1701]
+      --strict           Validate reserved bits, protocol version and unused fields
+      --lax              Skip all optional validation
+  -h, --help             Print this help";
+
+/// # Summary
+/// The source of raw L2TP data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Input {
+    Hex(String),
+    File(String),
+    Stdin,
+}
+
+/// # Summary
+/// Command line options of `l2tp-dump`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Options {
+    pub input: Input,
+    pub secret: Option<Vec<u8>>,
+    pub json: bool,
+    pub tunnel_id: Option<u16>,
+    pub session_id: Option<u16>,
+    pub port: u16,
+    pub validation: ValidationOptions,
+}
+
+const DEFAULT_PORT: u16 = 1701;
+
+impl Options {
+    /// # Summary
+    /// Parse command line arguments, excluding the program name.
+    ///
+    /// `Ok(None)` is returned if help was requested.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut args = args.into_iter();
+
+        let mut hex = None;
+        let mut file = None;
+        let mut secret = None;
+        let mut json = false;
+        let mut tunnel_id = None;
+        let mut session_id = None;
+        let mut port = DEFAULT_PORT;
+        // Same defaults as `Message::try_read`
+        let mut validation = ValidationOptions {
+            reserved: ValidateReserved::No,
+            version: ValidateVersion::Yes,
+            unused: ValidateUnused::No,
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for option {name}"))
+            };
+
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-x" | "--hex" => hex = Some(value(&arg)?),
+                "-s" | "--secret" => secret = Some(value(&arg)?.into_bytes()),
+                "-j" | "--json" => json = true,
+                "-t" | "--tunnel" => tunnel_id = Some(parse_number(&arg, &value(&arg)?)?),
+                "-S" | "--session" => session_id = Some(parse_number(&arg, &value(&arg)?)?),
+                "-p" | "--port" => port = parse_number(&arg, &value(&arg)?)?,
+                "--strict" => {
+                    validation = ValidationOptions {
+                        reserved: ValidateReserved::Yes,
+                        version: ValidateVersion::Yes,
+                        unused: ValidateUnused::Yes,
+                    }
+                }
+                "--lax" => {
+                    validation = ValidationOptions {
+                        reserved: ValidateReserved::No,
+                        version: ValidateVersion::No,
+                        unused: ValidateUnused::No,
+                    }
+                }
+                x if x.starts_with('-') && x != "-" => return Err(format!("Unknown option {x}")),
+                x => {
+                    if file.replace(x.to_owned()).is_some() {
+                        return Err("Only one input file may be given".to_owned());
+                    }
+                }
+            }
+        }
+
+        let input = match (hex, file) {
+            (Some(_), Some(_)) => return Err("--hex and FILE are mutually exclusive".to_owned()),
+            (Some(hex), None) => Input::Hex(hex),
+            (None, Some(file)) if file != "-" => Input::File(file),
+            (None, _) => Input::Stdin,
+        };
+
+        Ok(Some(Self {
+            input,
+            secret,
+            json,
+            tunnel_id,
+            session_id,
+            port,
+            validation,
+        }))
+    }
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u16, String> {
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => u16::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|_| format!("Invalid value for option {name}: {value}"))
+}
@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests;
+
+use crate::input::Record;
+#[cfg(any(feature = "broadband-forum", feature = "cisco"))]
+use rl2tp::avp::types::VendorSpecificAVP;
+use rl2tp::avp::{types, AVP};
+use rl2tp::common::DecodeError;
+use rl2tp::{ControlMessage, DataMessage, Message};
+use std::fmt::Write;
+
+/// # Summary
+/// The outcome of decoding a single `Record`.
+///
+/// # Data members
+/// * `message` - The decoded message, or the errors that prevented decoding it.
+/// * `reveal_errors` - Errors encountered while revealing hidden AVPs of a decoded message.
+pub struct Decoded<'a> {
+    pub message: Result<Message<&'a [u8]>, Vec<DecodeError>>,
+    pub reveal_errors: Vec<DecodeError>,
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().fold(String::new(), |mut s, x| {
+        let _ = write!(s, "{x:02x}");
+        s
+    })
+}
+
+/// # Summary
+/// Format the value of an `AVP`, decoding vendor-specific AVPs with the enabled vendor dictionaries.
+fn avp_value(avp: &AVP) -> String {
+    #[cfg(feature = "broadband-forum")]
+    if let Ok(broadband_forum) = rl2tp::avp::broadband_forum::BroadbandForumAvp::try_from_avp(avp) {
+        return format!("BroadbandForum{broadband_forum:?}");
+    }
+
+    #[cfg(feature = "cisco")]
+    if let Ok(cisco) = rl2tp::avp::cisco::CiscoAvp::try_from_avp(avp) {
+        return format!("Cisco{cisco:?}");
+    }
+
+    format!("{avp:?}")
+}
+
+fn timestamp(record: &Record) -> Option<String> {
+    record
+        .timestamp
+        .map(|(seconds, micros)| format!("{seconds}.{micros:06}"))
+}
+
+/// # Summary
+/// Render a decoded record as human-readable text.
+pub fn text(record: &Record, decoded: &Decoded) -> String {
+    let mut out = format!("#{}", record.index);
+    if let Some(timestamp) = timestamp(record) {
+        let _ = write!(out, " {timestamp}");
+    }
+
+    match &decoded.message {
+        Ok(Message::Control(control)) => {
+            let _ = write!(
+                out,
+                " control tunnel={} session={} ns={} nr={} length={}",
+                control.tunnel_id, control.session_id, control.ns, control.nr, control.length
+            );
+            for avp in control.avps.iter() {
+                let _ = write!(out, "\n    {}", avp_value(avp));
+            }
+        }
+        Ok(Message::Data(data)) => {
+            let _ = write!(
+                out,
+                " data tunnel={} session={}",
+                data.tunnel_id, data.session_id
+            );
+            if let Some((ns, nr)) = data.ns_nr {
+                let _ = write!(out, " ns={ns} nr={nr}");
+            }
+            if let Some(length) = data.length {
+                let _ = write!(out, " length={length}");
+            }
+            if data.is_prioritized {
+                out.push_str(" prioritized");
+            }
+            let _ = write!(
+                out,
+                "\n    payload ({} bytes): {}",
+                data.data.len(),
+                hex(data.data)
+            );
+        }
+        Err(errors) => {
+            for error in errors.iter() {
+                let _ = write!(out, "\n    error: {error}");
+            }
+        }
+    }
+
+    for error in decoded.reveal_errors.iter() {
+        let _ = write!(out, "\n    reveal error: {error}");
+    }
+
+    out
+}
+
+/// # Summary
+/// Render a decoded record as a single-line JSON object.
+pub fn json(record: &Record, decoded: &Decoded) -> String {
+    let mut out = format!("{{\"index\":{}", record.index);
+    if let Some(timestamp) = timestamp(record) {
+        let _ = write!(out, ",\"timestamp\":{timestamp}");
+    }
+
+    let mut errors: Vec<&DecodeError> = decoded.reveal_errors.iter().collect();
+    match &decoded.message {
+        Ok(Message::Control(control)) => json_control(&mut out, control),
+        Ok(Message::Data(data)) => json_data(&mut out, data),
+        Err(decode_errors) => {
+            out.push_str(",\"type\":\"error\"");
+            errors.extend(decode_errors.iter());
+        }
+    }
+
+    out.push_str(",\"errors\":[");
+    for (i, error) in errors.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&json_string(&error.to_string()));
+    }
+    out.push_str("]}");
+
+    out
+}
+
+fn json_control(out: &mut String, control: &ControlMessage) {
+    let _ = write!(
+        out,
+        ",\"type\":\"control\",\"tunnel_id\":{},\"session_id\":{},\"ns\":{},\"nr\":{},\"length\":{},\"avps\":[",
+        control.tunnel_id, control.session_id, control.ns, control.nr, control.length
+    );
+    for (i, avp) in control.avps.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let attribute_type = avp.get_attribute_type();
+        let _ = write!(
+            out,
+            "{{\"attribute_type\":{attribute_type},\"name\":{},\"hidden\":{},\"value\":{}}}",
+            json_string(&avp.name()),
+            matches!(
+                avp,
+                AVP::Hidden(_)
+                    | AVP::Vendor(types::Vendor {
+                        is_hidden: true,
+                        ..
+                    })
+            ),
+            json_string(&avp_value(avp))
+        );
+    }
+    out.push(']');
+}
+
+fn json_data(out: &mut String, data: &DataMessage<&[u8]>) {
+    let _ = write!(
+        out,
+        ",\"type\":\"data\",\"tunnel_id\":{},\"session_id\":{}",
+        data.tunnel_id, data.session_id
+    );
+    if let Some((ns, nr)) = data.ns_nr {
+        let _ = write!(out, ",\"ns\":{ns},\"nr\":{nr}");
+    }
+    if let Some(length) = data.length {
+        let _ = write!(out, ",\"length\":{length}");
+    }
+    let _ = write!(
+        out,
+        ",\"prioritized\":{},\"payload\":\"{}\"",
+        data.is_prioritized,
+        hex(data.data)
+    );
+}
+
+/// # Summary
+/// Quote and escape a string for inclusion in JSON output.
+pub fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
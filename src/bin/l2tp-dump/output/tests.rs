@@ -0,0 +1,100 @@
+use crate::input::Record;
+use crate::output::*;
+use rl2tp::common::SliceReader;
+use rl2tp::Message;
+
+#[test]
+fn json_string_escape() {
+    assert_eq!(json_string("plain"), "\"plain\"");
+    assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+}
+
+#[test]
+fn json_control() {
+    let record = Record {
+        index: 1,
+        timestamp: Some((10, 20)),
+        data: vec![
+            0x13, 0x20, // Flags
+            0x00, 0x14, // Length
+            0x00, 0x02, // Tunnel ID
+            0x00, 0x03, // Session ID
+            0x00, 0x04, // Ns
+            0x00, 0x05, // Nr
+            // AVP Payload
+            0x00, 0x08, // Flags and Length
+            0x00, 0x00, // Vendor ID
+            0x00, 0x00, // Attribute Type (Message Type)
+            0x00, 0x06, // Type 6 (Hello)
+        ],
+    };
+    let decoded = Decoded {
+        message: Message::try_read(&mut SliceReader::from(&record.data)),
+        reveal_errors: Vec::new(),
+    };
+
+    assert_eq!(
+        json(&record, &decoded),
+        "{\"index\":1,\"timestamp\":10.000020,\"type\":\"control\",\"tunnel_id\":2,\"session_id\":3,\
+         \"ns\":4,\"nr\":5,\"length\":20,\"avps\":[{\"attribute_type\":0,\"name\":\"MessageType\",\
+         \"hidden\":false,\"value\":\"MessageType(Hello)\"}],\"errors\":[]}"
+    );
+}
+
+#[test]
+fn json_vendor() {
+    let record = Record {
+        index: 1,
+        timestamp: None,
+        data: vec![
+            0x13, 0x20, // Flags
+            0x00, 0x1c, // Length
+            0x00, 0x02, // Tunnel ID
+            0x00, 0x03, // Session ID
+            0x00, 0x04, // Ns
+            0x00, 0x05, // Nr
+            // AVP Payload
+            0x00, 0x08, // Flags and Length
+            0x00, 0x00, // Vendor ID
+            0x00, 0x00, // Attribute Type (Message Type)
+            0x00, 0x06, // Type 6 (Hello)
+            0x00, 0x08, // Flags and Length
+            0x00, 0x09, // Vendor ID
+            0x00, 0x0e, // Attribute Type (Cisco Interface MTU)
+            0x05, 0xdc, // MTU
+        ],
+    };
+    let decoded = Decoded {
+        message: Message::try_read(&mut SliceReader::from(&record.data)),
+        reveal_errors: Vec::new(),
+    };
+
+    #[cfg(feature = "cisco")]
+    let (name, value) = ("CiscoInterfaceMtu", "CiscoInterfaceMtu(1500)");
+    #[cfg(not(feature = "cisco"))]
+    let (name, value) = (
+        "9:14",
+        "Vendor(Vendor { vendor_id: 9, attribute_type: 14, is_mandatory: false, is_hidden: false, value: [5, 220] })",
+    );
+    assert!(json(&record, &decoded).ends_with(&format!(
+        "{{\"attribute_type\":14,\"name\":\"{name}\",\"hidden\":false,\"value\":\"{value}\"}}],\"errors\":[]}}"
+    )));
+}
+
+#[test]
+fn json_error() {
+    let record = Record {
+        index: 2,
+        timestamp: None,
+        data: vec![0x00],
+    };
+    let decoded = Decoded {
+        message: Message::try_read(&mut SliceReader::from(&record.data)),
+        reveal_errors: Vec::new(),
+    };
+
+    assert_eq!(
+        json(&record, &decoded),
+        "{\"index\":2,\"type\":\"error\",\"errors\":[\"Message with incomplete flags field\"]}"
+    );
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests;
+
+/// # Summary
+/// A UDP payload extracted from a captured packet.
+///
+/// # Data members
+/// * `index` - The one-based index of the packet within the capture.
+/// * `timestamp` - The capture timestamp as seconds and microseconds.
+/// * `payload` - The UDP payload.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Packet<'a> {
+    pub index: usize,
+    pub timestamp: (u32, u32),
+    pub payload: &'a [u8],
+}
+
+const MAGIC_MICROS: u32 = 0xa1b2c3d4;
+const MAGIC_NANOS: u32 = 0xa1b23c4d;
+
+const GLOBAL_HEADER_LENGTH: usize = 24;
+const RECORD_HEADER_LENGTH: usize = 16;
+
+const LINKTYPE_NULL: u32 = 0;
+const LINKTYPE_ETHERNET: u32 = 1;
+const LINKTYPE_RAW: u32 = 101;
+const LINKTYPE_LINUX_SLL: u32 = 113;
+const LINKTYPE_IPV4: u32 = 228;
+const LINKTYPE_IPV6: u32 = 229;
+const LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86dd;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_QINQ: u16 = 0x88a8;
+
+const IP_PROTOCOL_UDP: u8 = 17;
+
+/// # Summary
+/// Indicate whether `data` starts with a classic pcap magic number.
+pub fn is_pcap(data: &[u8]) -> bool {
+    data.len() >= 4 && {
+        let magic = u32::from_le_bytes(data[..4].try_into().unwrap());
+        [MAGIC_MICROS, MAGIC_NANOS].contains(&magic)
+            || [MAGIC_MICROS, MAGIC_NANOS].contains(&magic.swap_bytes())
+    }
+}
+
+/// # Summary
+/// Extract all UDP payloads sent from or to `port` in a classic pcap capture.
+///
+/// Packets which are not UDP over IPv4/IPv6, or which are IP fragments, are skipped.
+pub fn udp_payloads(data: &[u8], port: u16) -> Result<Vec<Packet<'_>>, String> {
+    if data.len() < GLOBAL_HEADER_LENGTH || !is_pcap(data) {
+        return Err("Not a pcap capture (note: pcapng is not supported)".to_owned());
+    }
+
+    let magic = u32::from_le_bytes(data[..4].try_into().unwrap());
+    let little_endian = [MAGIC_MICROS, MAGIC_NANOS].contains(&magic);
+    let nanos = magic == MAGIC_NANOS || magic.swap_bytes() == MAGIC_NANOS;
+    let read_u32 = |offset: usize| {
+        let octets = data[offset..offset + 4].try_into().unwrap();
+        if little_endian {
+            u32::from_le_bytes(octets)
+        } else {
+            u32::from_be_bytes(octets)
+        }
+    };
+
+    let link_type = read_u32(20) & 0xffff;
+
+    let mut result = Vec::new();
+    let mut offset = GLOBAL_HEADER_LENGTH;
+    let mut index = 0;
+    while offset + RECORD_HEADER_LENGTH <= data.len() {
+        index += 1;
+        let seconds = read_u32(offset);
+        let fraction = read_u32(offset + 4);
+        let captured_length = read_u32(offset + 8) as usize;
+        offset += RECORD_HEADER_LENGTH;
+
+        let frame = data
+            .get(offset..offset + captured_length)
+            .ok_or_else(|| format!("Truncated pcap record {index}"))?;
+        offset += captured_length;
+
+        let micros = if nanos { fraction / 1000 } else { fraction };
+        if let Some(payload) = link_payload(link_type, frame).and_then(|x| udp_payload(x, port)) {
+            result.push(Packet {
+                index,
+                timestamp: (seconds, micros),
+                payload,
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+/// Strip the link layer header and return the IP packet, if any.
+fn link_payload(link_type: u32, frame: &[u8]) -> Option<&[u8]> {
+    match link_type {
+        LINKTYPE_NULL => frame.get(4..),
+        LINKTYPE_RAW | LINKTYPE_IPV4 | LINKTYPE_IPV6 => Some(frame),
+        LINKTYPE_ETHERNET => {
+            let mut offset = 12;
+            loop {
+                let ethertype = u16::from_be_bytes(frame.get(offset..offset + 2)?.try_into().ok()?);
+                offset += 2;
+                match ethertype {
+                    ETHERTYPE_VLAN | ETHERTYPE_QINQ => offset += 2,
+                    ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => return frame.get(offset..),
+                    _ => return None,
+                }
+            }
+        }
+        LINKTYPE_LINUX_SLL => frame.get(16..),
+        LINKTYPE_LINUX_SLL2 => frame.get(20..),
+        _ => None,
+    }
+}
+
+/// Return the payload of an IPv4 or IPv6 UDP datagram sent from or to `port`.
+fn udp_payload(packet: &[u8], port: u16) -> Option<&[u8]> {
+    let version = packet.first()? >> 4;
+    let udp = match version {
+        4 => {
+            let header_length = ((packet[0] & 0x0f) as usize) * 4;
+            let fragment = u16::from_be_bytes(packet.get(6..8)?.try_into().ok()?);
+            let more_fragments = fragment & 0x2000 != 0;
+            let fragment_offset = fragment & 0x1fff;
+            if *packet.get(9)? != IP_PROTOCOL_UDP || more_fragments || fragment_offset != 0 {
+                return None;
+            }
+            let total_length = u16::from_be_bytes(packet.get(2..4)?.try_into().ok()?) as usize;
+            packet.get(header_length..total_length.min(packet.len()))?
+        }
+        6 => {
+            const HEADER_LENGTH: usize = 40;
+            if *packet.get(6)? != IP_PROTOCOL_UDP {
+                return None;
+            }
+            packet.get(HEADER_LENGTH..)?
+        }
+        _ => return None,
+    };
+
+    const UDP_HEADER_LENGTH: usize = 8;
+    let source_port = u16::from_be_bytes(udp.get(0..2)?.try_into().ok()?);
+    let destination_port = u16::from_be_bytes(udp.get(2..4)?.try_into().ok()?);
+    if source_port != port && destination_port != port {
+        return None;
+    }
+    let length = u16::from_be_bytes(udp.get(4..6)?.try_into().ok()?) as usize;
+    udp.get(UDP_HEADER_LENGTH..length.clamp(UDP_HEADER_LENGTH, udp.len()))
+}
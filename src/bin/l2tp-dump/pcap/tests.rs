@@ -0,0 +1,107 @@
+use crate::pcap::*;
+
+const L2TP: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+fn global_header(link_type: u32) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&0xa1b2c3d4u32.to_le_bytes()); // Magic
+    data.extend_from_slice(&2u16.to_le_bytes()); // Major version
+    data.extend_from_slice(&4u16.to_le_bytes()); // Minor version
+    data.extend_from_slice(&0u32.to_le_bytes()); // Zone
+    data.extend_from_slice(&0u32.to_le_bytes()); // Sigfigs
+    data.extend_from_slice(&65535u32.to_le_bytes()); // Snaplen
+    data.extend_from_slice(&link_type.to_le_bytes()); // Link type
+    data
+}
+
+fn record(data: &mut Vec<u8>, frame: &[u8]) {
+    data.extend_from_slice(&10u32.to_le_bytes()); // Seconds
+    data.extend_from_slice(&20u32.to_le_bytes()); // Microseconds
+    data.extend_from_slice(&(frame.len() as u32).to_le_bytes()); // Captured length
+    data.extend_from_slice(&(frame.len() as u32).to_le_bytes()); // Original length
+    data.extend_from_slice(frame);
+}
+
+fn ipv4_udp(source_port: u16, destination_port: u16, payload: &[u8]) -> Vec<u8> {
+    let udp_length = 8 + payload.len() as u16;
+    let total_length = 20 + udp_length;
+    let mut packet = vec![
+        0x45, 0x00, // Version, IHL, TOS
+    ];
+    packet.extend_from_slice(&total_length.to_be_bytes());
+    packet.extend_from_slice(&[
+        0x00, 0x00, // Identification
+        0x40, 0x00, // Flags (DF) and fragment offset
+        0x40, 0x11, // TTL, protocol (UDP)
+        0x00, 0x00, // Checksum
+        0x0a, 0x00, 0x00, 0x01, // Source address
+        0x0a, 0x00, 0x00, 0x02, // Destination address
+    ]);
+    packet.extend_from_slice(&source_port.to_be_bytes());
+    packet.extend_from_slice(&destination_port.to_be_bytes());
+    packet.extend_from_slice(&udp_length.to_be_bytes());
+    packet.extend_from_slice(&[0x00, 0x00]); // Checksum
+    packet.extend_from_slice(payload);
+    packet
+}
+
+#[test]
+fn ethernet_ipv4() {
+    let mut frame = vec![0x00; 12]; // MAC addresses
+    frame.extend_from_slice(&[0x08, 0x00]); // IPv4
+    frame.extend_from_slice(&ipv4_udp(1701, 1701, &L2TP));
+
+    let mut data = global_header(1);
+    record(&mut data, &frame);
+
+    assert!(is_pcap(&data));
+    assert_eq!(
+        udp_payloads(&data, 1701),
+        Ok(vec![Packet {
+            index: 1,
+            timestamp: (10, 20),
+            payload: &L2TP
+        }])
+    );
+}
+
+#[test]
+fn vlan_and_port_filter() {
+    let mut frame = vec![0x00; 12]; // MAC addresses
+    frame.extend_from_slice(&[0x81, 0x00, 0x00, 0x05]); // 802.1Q tag
+    frame.extend_from_slice(&[0x08, 0x00]); // IPv4
+    frame.extend_from_slice(&ipv4_udp(53, 1024, &L2TP));
+
+    let mut data = global_header(1);
+    record(&mut data, &frame);
+    record(&mut data, &ipv4_udp(1701, 1024, &L2TP)[..]);
+
+    // Second record is not valid Ethernet, first record is filtered by port
+    assert_eq!(udp_payloads(&data, 1701), Ok(Vec::new()));
+    assert_eq!(udp_payloads(&data, 53).unwrap().len(), 1);
+}
+
+#[test]
+fn raw_ipv4() {
+    let mut data = global_header(101);
+    record(&mut data, &ipv4_udp(1024, 1701, &L2TP));
+
+    let packets = udp_payloads(&data, 1701).unwrap();
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].payload, &L2TP);
+}
+
+#[test]
+fn truncated_record() {
+    let mut data = global_header(101);
+    record(&mut data, &ipv4_udp(1024, 1701, &L2TP));
+    data.truncate(data.len() - 1);
+
+    assert!(udp_payloads(&data, 1701).is_err());
+}
+
+#[test]
+fn not_pcap() {
+    assert!(!is_pcap(b"1320"));
+    assert!(udp_payloads(&[0x00; 32], 1701).is_err());
+}
@@ -7,9 +7,15 @@ pub use result_str::*;
 mod slice_reader;
 pub use slice_reader::*;
 
+mod slice_writer;
+pub use slice_writer::*;
+
 mod vec_writer;
 pub use vec_writer::*;
 
+#[cfg(feature = "bytes")]
+mod bytes_io;
+
 mod writer;
 pub use writer::*;
 
@@ -18,3 +24,33 @@ pub use decode_error::*;
 
 mod decode_result;
 pub use decode_result::*;
+
+mod encode_error;
+pub use encode_error::*;
+
+mod encode_result;
+pub use encode_result::*;
+
+mod overflow_error;
+pub use overflow_error::*;
+
+mod build_error;
+pub use build_error::*;
+
+mod build_result;
+pub use build_result::*;
+
+mod proxy_auth_error;
+pub use proxy_auth_error::*;
+
+mod session_error;
+pub use session_error::*;
+
+mod session_result;
+pub use session_result::*;
+
+mod bundle_error;
+pub use bundle_error::*;
+
+mod bundle_result;
+pub use bundle_result::*;
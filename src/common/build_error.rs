@@ -0,0 +1,18 @@
+use crate::avp::avp_name;
+use crate::common::EncodeError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum BuildError {
+    #[error("Duplicate AVP ({}) in control message", avp_name(*.0))]
+    DuplicateAVP(u16),
+
+    #[error("AVP ({}) may not be hidden", avp_name(*.0))]
+    UnhideableAVP(u16),
+
+    #[error("Hidden AVP requested without a shared secret")]
+    MissingSecret,
+
+    #[error(transparent)]
+    Encode(#[from] EncodeError),
+}
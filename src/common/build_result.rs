@@ -0,0 +1,3 @@
+use crate::common::BuildError;
+
+pub type BuildResult<T> = Result<T, BuildError>;
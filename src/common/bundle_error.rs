@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum BundleError {
+    #[error("Link without multilink PPP")]
+    NotMultilink,
+
+    #[error("Link without a usable endpoint discriminator")]
+    MissingEndpointDiscriminator,
+
+    #[error("Link MRRU ({1}) differs from that of its bundle ({0})")]
+    MrruMismatch(u16, u16),
+
+    #[error("Link sequence number header format differs from that of its bundle")]
+    SequenceNumberFormatMismatch,
+}
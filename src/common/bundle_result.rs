@@ -0,0 +1,3 @@
+use crate::common::BundleError;
+
+pub type BundleResult<T> = Result<T, BundleError>;
@@ -0,0 +1,104 @@
+//! `Reader` and `Writer` implementations for the `bytes` crate, enabled by the `bytes` feature.
+//!
+//! Reading from `Bytes` is zero-copy: the payload of a `DataMessage<Bytes>` shares the buffer it was read from.
+
+#[cfg(test)]
+mod tests;
+
+use crate::common::{Reader, Writer};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+impl Reader<Bytes> for Bytes {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Bytes::is_empty(self)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        Bytes::len(self)
+    }
+
+    #[inline]
+    fn subreader(&mut self, length: usize) -> Self {
+        self.split_to(length)
+    }
+
+    #[inline]
+    fn bytes(&mut self, length: usize) -> Option<Bytes> {
+        if length > Bytes::len(self) {
+            self.clear();
+            return None;
+        }
+        Some(self.split_to(length))
+    }
+
+    #[inline]
+    unsafe fn read_u8_unchecked(&mut self) -> u8 {
+        self.get_u8()
+    }
+
+    #[inline]
+    unsafe fn read_u16_be_unchecked(&mut self) -> u16 {
+        self.get_u16()
+    }
+
+    #[inline]
+    unsafe fn read_u32_be_unchecked(&mut self) -> u32 {
+        self.get_u32()
+    }
+
+    #[inline]
+    unsafe fn read_u64_be_unchecked(&mut self) -> u64 {
+        self.get_u64()
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, length: usize) {
+        self.advance(length);
+    }
+}
+
+impl Writer for BytesMut {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        BytesMut::is_empty(self)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        BytesMut::len(self)
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+
+    #[inline]
+    fn write_bytes_at(&mut self, bytes: &[u8], offset: usize) {
+        assert!(offset + bytes.len() <= BytesMut::len(self));
+
+        self[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[inline]
+    fn write_u8(&mut self, value: u8) {
+        self.put_u8(value);
+    }
+
+    #[inline]
+    fn write_u16_be(&mut self, value: u16) {
+        self.put_u16(value);
+    }
+
+    #[inline]
+    fn write_u32_be(&mut self, value: u32) {
+        self.put_u32(value);
+    }
+
+    #[inline]
+    fn write_u64_be(&mut self, value: u64) {
+        self.put_u64(value);
+    }
+}
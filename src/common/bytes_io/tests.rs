@@ -0,0 +1,67 @@
+use crate::common::{Reader, Writer};
+use crate::{DataMessage, Message};
+use bytes::{Bytes, BytesMut};
+
+#[test]
+fn read_integers() {
+    let mut r = Bytes::from_static(&[
+        0x00, // 8
+        0x01, 0x02, // 16
+        0x03, 0x04, 0x05, 0x06, // 32
+    ]);
+
+    assert_eq!(Reader::len(&r), 7);
+    unsafe {
+        assert_eq!(r.read_u8_unchecked(), 0x00);
+        assert_eq!(r.read_u16_be_unchecked(), 0x0102);
+        assert_eq!(r.read_u32_be_unchecked(), 0x03040506);
+    }
+    assert!(Reader::is_empty(&r));
+}
+
+#[test]
+fn bytes_out_of_range() {
+    let mut r = Bytes::from_static(&[0x00, 0x01]);
+    assert_eq!(r.bytes(3), None);
+}
+
+#[test]
+fn write_integers() {
+    let mut w = BytesMut::new();
+
+    w.write_u8(0x00);
+    w.write_u16_be(0x0102);
+    w.write_u32_be(0x03040506);
+    w.write_bytes_at(&[0xff], 0);
+
+    assert_eq!(Writer::len(&w), 7);
+    assert_eq!(&w[..], [0xff, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+}
+
+#[test]
+fn data_message_zero_copy() {
+    let payload = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]);
+
+    let mut w = BytesMut::new();
+    Message::Data(DataMessage {
+        is_prioritized: false,
+        length: None,
+        tunnel_id: 5,
+        session_id: 6,
+        ns_nr: None,
+        offset: None,
+        data: payload.clone(),
+    })
+    .write(&mut w);
+
+    let input = w.freeze();
+    let msg = Message::try_read(&mut input.clone()).unwrap();
+    let data = match msg {
+        Message::Data(data) => data.data,
+        Message::Control(_) => panic!(),
+    };
+
+    assert_eq!(data, payload);
+    // The payload shares the buffer it was read from
+    assert_eq!(data.as_ptr(), input[input.len() - payload.len()..].as_ptr());
+}
@@ -1,12 +1,12 @@
 use crate::avp::avp_name;
 use thiserror::Error;
 
-#[derive(Error, Debug, PartialEq)]
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
 pub enum DecodeError {
     #[error("Incomplete AVP ({})", avp_name(*.0))]
     IncompleteAVP(u16),
 
-    #[error("MessageType AVP with unknown message type ({0})")]
+    #[error("Mandatory MessageType AVP with unknown message type ({0})")]
     UnknownMessageType(u16),
 
     #[error("AVP ({}) with invalid UTF-8 string payload", avp_name(*.0))]
@@ -33,9 +33,21 @@ pub enum DecodeError {
     #[error("Hidden AVP with invalid original length ({0})")]
     InvalidOriginalAVPLength(u16),
 
+    #[error("Hidden AVP ({}) without preceding RandomVector", avp_name(*.0))]
+    HiddenAVPWithoutRandomVector(u16),
+
     #[error("AVP with unsupported vendor ID ({0}) encountered")]
     UnsupportedVendorId(u16),
 
+    #[error("Hidden vendor-specific AVP ({0}:{1}) cannot be decoded")]
+    HiddenVendorAVP(u16, u16),
+
+    #[error("Unrecognized vendor-specific AVP ({0}:{1}) with the M bit set")]
+    UnrecognizedMandatoryVendorAVP(u16, u16),
+
+    #[error("AVP ({}) is not vendor-specific", avp_name(*.0))]
+    NotVendorAVP(u16),
+
     #[error("Message with invalid version field ({0})")]
     InvalidVersion(u8),
 
@@ -80,4 +92,92 @@ pub enum DecodeError {
 
     #[error("First AVP of control message is not MessageType")]
     ControlMessageTypeNotFirst,
+
+    #[error("Incomplete PPP frame")]
+    IncompletePppFrame,
+
+    #[error("PPP frame with invalid control field ({0:#04x})")]
+    InvalidPppControlField(u8),
+
+    #[error("PPP frame with invalid protocol field ({0:#06x})")]
+    InvalidPppProtocol(u16),
+
+    #[error("Read error when parsing PPP frame")]
+    PppFrameReadError,
+
+    #[error("Incomplete PPP control packet")]
+    IncompletePppControlPacket,
+
+    #[error("PPP control packet with invalid length ({0})")]
+    InvalidPppControlPacketLength(u16),
+
+    #[error("Incomplete LCP option")]
+    IncompleteLcpOption,
+
+    #[error("LCP option ({0}) with invalid length ({1})")]
+    InvalidLcpOptionLength(u8, u8),
+
+    #[error("Aborted HDLC frame")]
+    HdlcFrameAborted,
+
+    #[error("Incomplete HDLC frame")]
+    IncompleteHdlcFrame,
+
+    #[error("HDLC frame with invalid frame check sequence")]
+    InvalidHdlcFcs,
+
+    #[error("HDLC frame exceeding maximum length ({0})")]
+    OversizeHdlcFrame(usize),
+}
+
+impl DecodeError {
+    /// # Summary
+    /// Get the name of the `DecodeError` variant, e.g. for use as a metric label.
+    pub fn name(&self) -> &'static str {
+        use DecodeError::*;
+        match self {
+            IncompleteAVP(_) => "IncompleteAVP",
+            UnknownMessageType(_) => "UnknownMessageType",
+            InvalidUtf8(_) => "InvalidUtf8",
+            InvalidResultCodeErrorType(_) => "InvalidResultCodeErrorType",
+            AVPReadError(_) => "AVPReadError",
+            InvalidAVPLength(_) => "InvalidAVPLength",
+            UnknownAvp(_) => "UnknownAvp",
+            EmptyHiddenAVP => "EmptyHiddenAVP",
+            MisalignedHiddenAVP => "MisalignedHiddenAVP",
+            InvalidOriginalAVPLength(_) => "InvalidOriginalAVPLength",
+            HiddenAVPWithoutRandomVector(_) => "HiddenAVPWithoutRandomVector",
+            UnsupportedVendorId(_) => "UnsupportedVendorId",
+            HiddenVendorAVP(..) => "HiddenVendorAVP",
+            UnrecognizedMandatoryVendorAVP(..) => "UnrecognizedMandatoryVendorAVP",
+            NotVendorAVP(_) => "NotVendorAVP",
+            InvalidVersion(_) => "InvalidVersion",
+            InvalidReservedBits => "InvalidReservedBits",
+            IncompleteFlags => "IncompleteFlags",
+            InvalidOffset(_) => "InvalidOffset",
+            IncompleteDataMessageHeader => "IncompleteDataMessageHeader",
+            IncompleteDataMessagePayload => "IncompleteDataMessagePayload",
+            EmptyDataMessagePayload => "EmptyDataMessagePayload",
+            MessageReadError => "MessageReadError",
+            ForbiddenControlMessagePriority => "ForbiddenControlMessagePriority",
+            ForbiddenControlMessageOffset => "ForbiddenControlMessageOffset",
+            ControlMessageWithoutLength => "ControlMessageWithoutLength",
+            ControlMessageWithoutNsNr => "ControlMessageWithoutNsNr",
+            IncompleteControlMessageHeader => "IncompleteControlMessageHeader",
+            IncompleteControlMessagePayload => "IncompleteControlMessagePayload",
+            ControlMessageTypeNotFirst => "ControlMessageTypeNotFirst",
+            IncompletePppFrame => "IncompletePppFrame",
+            InvalidPppControlField(_) => "InvalidPppControlField",
+            InvalidPppProtocol(_) => "InvalidPppProtocol",
+            PppFrameReadError => "PppFrameReadError",
+            IncompletePppControlPacket => "IncompletePppControlPacket",
+            InvalidPppControlPacketLength(_) => "InvalidPppControlPacketLength",
+            IncompleteLcpOption => "IncompleteLcpOption",
+            InvalidLcpOptionLength(..) => "InvalidLcpOptionLength",
+            HdlcFrameAborted => "HdlcFrameAborted",
+            IncompleteHdlcFrame => "IncompleteHdlcFrame",
+            InvalidHdlcFcs => "InvalidHdlcFcs",
+            OversizeHdlcFrame(_) => "OversizeHdlcFrame",
+        }
+    }
 }
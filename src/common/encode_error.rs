@@ -0,0 +1,17 @@
+use crate::avp::avp_name;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum EncodeError {
+    #[error("AVP ({}) with oversize length ({})", avp_name(*.0), .1)]
+    OversizeAVP(u16, usize),
+
+    #[error("RADIUS attribute (vendor type {0}) with oversize length ({1})")]
+    OversizeRadiusAttribute(u8, usize),
+
+    #[error("Message with oversize length ({0})")]
+    OversizeMessage(usize),
+
+    #[error("Writer capacity exhausted")]
+    WriterCapacityExhausted,
+}
@@ -0,0 +1,3 @@
+use crate::common::EncodeError;
+
+pub type EncodeResult<T> = Result<T, EncodeError>;
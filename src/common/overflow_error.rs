@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+#[error("Insufficient writer capacity ({capacity} octets available, {required} required)")]
+pub struct OverflowError {
+    pub capacity: usize,
+    pub required: usize,
+}
@@ -0,0 +1,21 @@
+use crate::avp::avp_name;
+use crate::avp::types::ProxyAuthenType;
+use thiserror::Error;
+
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum ProxyAuthError {
+    #[error("Proxy authentication found in a message other than IncomingCallConnected")]
+    UnexpectedMessageType,
+
+    #[error("Proxy authentication without required AVP ({})", avp_name(*.0))]
+    MissingAVP(u16),
+
+    #[error("Proxy authentication AVP ({}) is still hidden", avp_name(*.0))]
+    HiddenAVP(u16),
+
+    #[error("Proxy authentication AVP ({}) with invalid length ({})", avp_name(*.0), .1)]
+    InvalidLength(u16, usize),
+
+    #[error("Unsupported proxy authentication type ({0:?})")]
+    UnsupportedType(ProxyAuthenType),
+}
@@ -0,0 +1,22 @@
+use crate::avp::avp_name;
+use crate::avp::types::MessageType;
+use crate::common::BuildError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SessionError {
+    #[error("Control message without MessageType AVP")]
+    MissingMessageType,
+
+    #[error("Control message for another session ({0})")]
+    WrongSession(u16),
+
+    #[error("Control message ({:?}) without required AVP ({})", .0, avp_name(*.1))]
+    MissingAVP(MessageType, u16),
+
+    #[error("Control message ({0:?}) not handled by sessions")]
+    UnhandledMessageType(MessageType),
+
+    #[error(transparent)]
+    Build(#[from] BuildError),
+}
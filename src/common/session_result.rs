@@ -0,0 +1,3 @@
+use crate::common::SessionError;
+
+pub type SessionResult<T> = Result<T, SessionError>;
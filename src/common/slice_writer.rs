@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests;
+
+use crate::common::{OverflowError, Writer};
+
+/// # Summary
+/// A `Writer` which encodes into a caller-provided, fixed-capacity byte slice.
+///
+/// Writes exceeding the capacity of the slice are discarded and flag the `SliceWriter` as overflowed,
+/// which is reported by `finish`. The number of written bytes keeps counting past the capacity so that
+/// the required capacity can be reported.
+#[derive(Debug, Eq, PartialEq)]
+pub struct SliceWriter<'a> {
+    data: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    #[inline]
+    pub fn from(data: &'a mut [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// # Summary
+    /// Get the capacity of the underlying slice.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// # Summary
+    /// Get the written part of the underlying slice, or an `OverflowError` if its capacity was exceeded.
+    #[inline]
+    pub fn finish(self) -> Result<&'a mut [u8], OverflowError> {
+        if self.has_overflowed() {
+            return Err(OverflowError {
+                capacity: self.data.len(),
+                required: self.position,
+            });
+        }
+        Ok(&mut self.data[..self.position])
+    }
+
+    #[inline]
+    fn write_array<const N: usize>(&mut self, bytes: [u8; N]) {
+        self.write_bytes(&bytes);
+    }
+}
+
+impl Writer for SliceWriter<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.position == 0
+    }
+
+    #[inline]
+    fn has_overflowed(&self) -> bool {
+        self.position > self.data.len()
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        let end = self.position + bytes.len();
+        if let Some(destination) = self.data.get_mut(self.position..end) {
+            destination.copy_from_slice(bytes);
+        }
+        self.position = end;
+    }
+
+    #[inline]
+    fn write_bytes_at(&mut self, bytes: &[u8], offset: usize) {
+        assert!(offset + bytes.len() <= self.position);
+
+        // Bytes beyond the capacity were never stored, so there is nothing to overwrite
+        if let Some(destination) = self.data.get_mut(offset..offset + bytes.len()) {
+            destination.copy_from_slice(bytes);
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, value: u8) {
+        self.write_array(value.to_be_bytes());
+    }
+
+    #[inline]
+    fn write_u16_be(&mut self, value: u16) {
+        self.write_array(value.to_be_bytes());
+    }
+
+    #[inline]
+    fn write_u32_be(&mut self, value: u32) {
+        self.write_array(value.to_be_bytes());
+    }
+
+    #[inline]
+    fn write_u64_be(&mut self, value: u64) {
+        self.write_array(value.to_be_bytes());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::common::{OverflowError, SliceWriter, Writer};
+
+#[test]
+fn write_integers() {
+    let mut buffer = [0xff; 16];
+    let mut w = SliceWriter::from(&mut buffer);
+
+    w.write_u8(0x00);
+    w.write_u16_be(0x0102);
+    w.write_u32_be(0x03040506);
+    w.write_u64_be(0x0708090a0b0c0d0e);
+
+    assert_eq!(w.len(), 15);
+    assert!(!w.has_overflowed());
+    assert_eq!(
+        w.finish(),
+        Ok(&mut [
+            0x00, // 8
+            0x01, 0x02, // 16
+            0x03, 0x04, 0x05, 0x06, // 32
+            0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e // 64
+        ][..])
+    );
+}
+
+#[test]
+fn write_bytes_at() {
+    let mut buffer = [0x00; 4];
+    let mut w = SliceWriter::from(&mut buffer);
+
+    w.write_bytes(&[0x00, 0x00, 0x03, 0x04]);
+    w.write_bytes_at(&[0x01, 0x02], 0);
+
+    assert_eq!(w.finish(), Ok(&mut [0x01, 0x02, 0x03, 0x04][..]));
+}
+
+#[test]
+fn overflow() {
+    let mut buffer = [0x00; 4];
+    let mut w = SliceWriter::from(&mut buffer);
+
+    w.write_u16_be(0x0102);
+    w.write_u32_be(0x03040506);
+    w.write_bytes_at(&[0xff, 0xff], 4);
+
+    assert_eq!(w.capacity(), 4);
+    assert_eq!(w.len(), 6);
+    assert!(w.has_overflowed());
+    assert_eq!(
+        w.finish(),
+        Err(OverflowError {
+            capacity: 4,
+            required: 6
+        })
+    );
+
+    // Nothing is written past the point of overflow
+    assert_eq!(buffer, [0x01, 0x02, 0x00, 0x00]);
+}
+
+#[test]
+fn exact_capacity() {
+    let mut buffer = [0x00; 2];
+    let mut w = SliceWriter::from(&mut buffer);
+
+    w.write_u16_be(0x0102);
+
+    assert!(!w.has_overflowed());
+    assert_eq!(w.finish(), Ok(&mut [0x01, 0x02][..]));
+}
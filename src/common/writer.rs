@@ -9,6 +9,15 @@ pub trait Writer {
     /// Get the number of written bytes.
     fn len(&self) -> usize;
 
+    /// # Summary
+    /// Indicate whether a write has exceeded the capacity of the `Writer`.
+    ///
+    /// Writers without a fixed capacity never overflow, hence the default implementation.
+    #[inline]
+    fn has_overflowed(&self) -> bool {
+        false
+    }
+
     /// # Summary
     /// Write byte slice.
     fn write_bytes(&mut self, bytes: &[u8]);
@@ -49,6 +49,19 @@
 //! unsafe { msg.write(&mut w) };
 //! ```
 //!
+//! ## Build an L2TP control message and write it to a `Vec`
+//! ```
+//! use rl2tp::{ControlMessage, common::VecWriter, avp::{AVP, types::MessageType}};
+//!
+//! let mut w = VecWriter::new();
+//! ControlMessage::builder(MessageType::IncomingCallRequest)
+//!     .tunnel(5)
+//!     .avp(AVP::AssignedSessionId(7.into()))
+//!     .avp(AVP::CallSerialNumber(1.into()))
+//!     .write(&mut w)
+//!     .unwrap();
+//! ```
+//!
 //! ## Write an L2TP data message to a buffer
 //!
 //! ```
@@ -73,9 +86,20 @@
 //!
 //! # Cargo Features
 //! * `benchmarks` - Enable benchmarking with [criterion.rs](https://github.com/bheisler/criterion.rs).
+//! * `broadband-forum` - Decode Broadband Forum vendor-specific AVPs, carrying TR-101 access line information.
+//! * `bytes` - Implement `Reader` for `bytes::Bytes` and `Writer` for `bytes::BytesMut`.
+//! * `cisco` - Decode common Cisco vendor-specific AVPs, as sent by Cisco LACs and L2TPv3 peers in draft mode.
+//! * `metrics` - Export tunnel statistics in the OpenMetrics text format, optionally over HTTP.
+//! * `ms-chap` - Support verifying MS-CHAPv1 proxy authentication.
+//! * `tracing` - Emit diagnostics using [tracing](https://github.com/tokio-rs/tracing). Individual AVPs and data
+//!   messages are logged at the `TRACE` level, while malformed messages, state changes and other notable events are
+//!   logged at the `DEBUG` level.
 
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 
+#[macro_use]
+mod trace;
+
 pub mod common;
 
 pub use common::Reader;
@@ -83,3 +107,16 @@ pub use common::Writer;
 
 mod message;
 pub use message::*;
+
+pub mod mib;
+
+pub mod ppp;
+
+pub mod proxy_auth;
+
+pub mod session;
+
+pub mod tunnel;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
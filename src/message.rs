@@ -6,7 +6,7 @@ mod tests;
 pub mod avp;
 
 mod control_message;
-pub use control_message::ControlMessage;
+pub use control_message::{ControlMessage, ControlMessageBuilder};
 
 mod data_message;
 pub use data_message::DataMessage;
@@ -14,7 +14,7 @@ pub use data_message::DataMessage;
 mod flags;
 use flags::{Flags, MessageFlagType};
 
-use crate::common::{DecodeError, Reader, Writer};
+use crate::common::{DecodeError, EncodeResult, Reader, Writer};
 use core::borrow::Borrow;
 
 /// # Summary
@@ -55,7 +55,7 @@ impl<T> Message<T>
 where
     T: Borrow<[u8]>,
 {
-    const PROTOCOL_VERSION: u8 = 2;
+    pub(crate) const PROTOCOL_VERSION: u8 = 2;
 
     /// # Summary
     /// Attempt to read a `Message` using a `Reader`.
@@ -79,6 +79,27 @@ where
     pub fn try_read_validate(
         reader: &mut impl Reader<T>,
         validation_options: ValidationOptions,
+    ) -> Result<Self, Vec<DecodeError>> {
+        let result = Self::try_read_flags(reader, validation_options);
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(Message::Data(data)) => trace!(
+                tunnel_id = data.tunnel_id,
+                session_id = data.session_id,
+                ns_nr = ?data.ns_nr,
+                length = data.data.borrow().len(),
+                "Read data message"
+            ),
+            Ok(Message::Control(_)) => (),
+            Err(errors) => debug!(?errors, "Failed to read message"),
+        }
+        result
+    }
+
+    #[inline]
+    fn try_read_flags(
+        reader: &mut impl Reader<T>,
+        validation_options: ValidationOptions,
     ) -> Result<Self, Vec<DecodeError>> {
         let flags = Flags::read(reader).map_err(|x| vec![x])?;
 
@@ -107,8 +128,29 @@ where
         }
     }
 
+    /// # Summary
+    /// Attempt to write a `Message` using a mutable `Writer`.
+    ///
+    /// Oversize messages and AVPs are reported without writing anything, whereas `Writer` overflow is
+    /// reported after writing.
+    #[inline]
+    pub fn try_write(&self, writer: &mut impl Writer) -> EncodeResult<()> {
+        let result = match self {
+            Message::Control(control) => control.try_write(writer),
+            Message::Data(data) => data.try_write(writer),
+        };
+        #[cfg(feature = "tracing")]
+        if let Err(error) = &result {
+            debug!(%error, "Failed to write message");
+        }
+        result
+    }
+
     /// # Summary
     /// Write a `Message` using a mutable `Writer`.
+    ///
+    /// # Panics
+    /// Panics if the message or any of its AVPs is oversize. See `try_write` for a non-panicking alternative.
     #[inline]
     pub fn write(&self, writer: &mut impl Writer) {
         match self {
@@ -8,9 +8,17 @@ use header::Header;
 
 pub mod types;
 
+#[cfg(feature = "broadband-forum")]
+pub mod broadband_forum;
+
+#[cfg(feature = "cisco")]
+pub mod cisco;
+
 use enum_dispatch::enum_dispatch;
 
-use crate::common::{DecodeError, DecodeResult, Reader, SliceReader, VecWriter, Writer};
+use crate::common::{
+    DecodeError, DecodeResult, EncodeError, EncodeResult, Reader, SliceReader, VecWriter, Writer,
+};
 use core::borrow::Borrow;
 use std::ops::DerefMut;
 
@@ -18,8 +26,11 @@ use std::ops::DerefMut;
 /// An `AVP` is a representation of an L2TP Attribute Value Pair, of which one or more may be present in a `ControlMessage`.
 ///
 /// ## Note
-/// Currently only Attribute Value Pairs defined in the L2TP specification are supported,
-/// although the specification itself purposefully leaves room for user-specified ones.
+/// Vendor-specific Attribute Value Pairs are carried undecoded as `Vendor` AVPs. Reading one with the M bit set
+/// fails with `UnrecognizedMandatoryVendorAVP` unless it is in an enabled vendor dictionary, as an unrecognized
+/// mandatory AVP requires the session or tunnel to be torn down.
+/// With the `broadband-forum` or `cisco` features enabled, the values of Broadband Forum or common Cisco AVPs can be
+/// decoded through `types::VendorSpecificAVP`, using `broadband_forum::BroadbandForumAvp` or `cisco::CiscoAvp`.
 #[enum_dispatch]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AVP {
@@ -62,12 +73,40 @@ pub enum AVP {
     ProxyAuthenResponse(types::ProxyAuthenResponse),
     CallErrors(types::CallErrors),
     Accm(types::Accm),
+    PppDisconnectCauseCode(types::PppDisconnectCauseCode),
+    ControlConnectionDs(types::ControlConnectionDs),
+    SessionDs(types::SessionDs),
+    LcpWantOptions(types::LcpWantOptions),
+    LcpAllowOptions(types::LcpAllowOptions),
+    LnsLastSentLcpConfReq(types::LnsLastSentLcpConfReq),
+    LnsLastReceivedLcpConfReq(types::LnsLastReceivedLcpConfReq),
+    ModemOnHoldCapable(types::ModemOnHoldCapable),
+    ModemOnHoldStatus(types::ModemOnHoldStatus),
+    FailoverCapability(types::FailoverCapability),
+    TunnelRecovery(types::TunnelRecovery),
+    SuggestedControlSequence(types::SuggestedControlSequence),
+    FailoverSessionState(types::FailoverSessionState),
+    MulticastCapability(types::MulticastCapability),
+    NewOutgoingSessions(types::NewOutgoingSessions),
+    NewOutgoingSessionsAcknowledgement(types::NewOutgoingSessionsAcknowledgement),
+    WithdrawOutgoingSessions(types::WithdrawOutgoingSessions),
+    MulticastPacketsPriority(types::MulticastPacketsPriority),
+    ConnectSpeedUpdate(types::ConnectSpeedUpdate),
+    ConnectSpeedUpdateEnable(types::ConnectSpeedUpdateEnable),
     Hidden(types::Hidden),
+    Vendor(types::Vendor),
 }
 
 #[enum_dispatch(AVP)]
 pub(crate) trait QueryableAVP {
+    fn get_attribute_type(&self) -> u16;
     fn get_length(&self) -> usize;
+
+    /// Whether the M bit is set when writing this AVP.
+    #[inline]
+    fn is_mandatory(&self) -> bool {
+        true
+    }
 }
 
 #[enum_dispatch(AVP)]
@@ -77,7 +116,9 @@ pub(crate) trait WritableAVP {
 
 use AVP::*;
 
-pub(crate) fn avp_name(attribute_type: u16) -> String {
+/// # Summary
+/// Get the name of an AVP given its attribute type, falling back to the numeric attribute type for unknown AVPs.
+pub fn avp_name(attribute_type: u16) -> String {
     let result = match attribute_type {
         0u16 => "MessageType",
         1u16 => "ResultCode",
@@ -118,12 +159,64 @@ pub(crate) fn avp_name(attribute_type: u16) -> String {
         37u16 => "PrivateGroupId",
         38u16 => "RxConnectSpeed",
         39u16 => "SequencingRequired",
+        46u16 => "PppDisconnectCauseCode",
+        47u16 => "ControlConnectionDs",
+        48u16 => "SessionDs",
+        49u16 => "LcpWantOptions",
+        50u16 => "LcpAllowOptions",
+        51u16 => "LnsLastSentLcpConfReq",
+        52u16 => "LnsLastReceivedLcpConfReq",
+        53u16 => "ModemOnHoldCapable",
+        54u16 => "ModemOnHoldStatus",
+        76u16 => "FailoverCapability",
+        77u16 => "TunnelRecovery",
+        78u16 => "SuggestedControlSequence",
+        79u16 => "FailoverSessionState",
+        80u16 => "MulticastCapability",
+        81u16 => "NewOutgoingSessions",
+        82u16 => "NewOutgoingSessionsAcknowledgement",
+        83u16 => "WithdrawOutgoingSessions",
+        84u16 => "MulticastPacketsPriority",
+        97u16 => "ConnectSpeedUpdate",
+        98u16 => "ConnectSpeedUpdateEnable",
         x => return format!("{x}"),
     };
 
     result.to_owned()
 }
 
+/// # Summary
+/// Get the name of a vendor-specific AVP given its vendor ID and attribute type.
+///
+/// Falls back to `vendor_id:attribute_type` for AVPs missing from the enabled vendor dictionaries.
+pub fn vendor_avp_name(vendor_id: u16, attribute_type: u16) -> String {
+    vendor_dictionary_name(vendor_id, attribute_type)
+        .unwrap_or_else(|| format!("{vendor_id}:{attribute_type}"))
+}
+
+/// Look up the name of a vendor-specific AVP in the enabled vendor dictionaries.
+#[cfg_attr(
+    not(any(feature = "broadband-forum", feature = "cisco")),
+    allow(unused_variables)
+)]
+fn vendor_dictionary_name(vendor_id: u16, attribute_type: u16) -> Option<String> {
+    #[cfg(feature = "broadband-forum")]
+    if vendor_id == broadband_forum::VENDOR_ID {
+        if let Some(name) = broadband_forum::name(attribute_type) {
+            return Some(format!("BroadbandForum{name}"));
+        }
+    }
+
+    #[cfg(feature = "cisco")]
+    if vendor_id == cisco::VENDOR_ID {
+        if let Some(name) = cisco::name(attribute_type) {
+            return Some(format!("Cisco{name}"));
+        }
+    }
+
+    None
+}
+
 fn decode_avp<T: Borrow<[u8]>>(
     attribute_type: u16,
     reader: &mut impl Reader<T>,
@@ -168,6 +261,28 @@ fn decode_avp<T: Borrow<[u8]>>(
         37u16 => PrivateGroupId(types::PrivateGroupId::try_read(reader)?),
         38u16 => RxConnectSpeed(types::RxConnectSpeed::try_read(reader)?),
         39u16 => SequencingRequired(types::SequencingRequired::default()),
+        46u16 => PppDisconnectCauseCode(types::PppDisconnectCauseCode::try_read(reader)?),
+        47u16 => ControlConnectionDs(types::ControlConnectionDs::try_read(reader)?),
+        48u16 => SessionDs(types::SessionDs::try_read(reader)?),
+        49u16 => LcpWantOptions(types::LcpWantOptions::try_read(reader)?),
+        50u16 => LcpAllowOptions(types::LcpAllowOptions::try_read(reader)?),
+        51u16 => LnsLastSentLcpConfReq(types::LnsLastSentLcpConfReq::try_read(reader)?),
+        52u16 => LnsLastReceivedLcpConfReq(types::LnsLastReceivedLcpConfReq::try_read(reader)?),
+        53u16 => ModemOnHoldCapable(types::ModemOnHoldCapable::try_read(reader)?),
+        54u16 => ModemOnHoldStatus(types::ModemOnHoldStatus::try_read(reader)?),
+        76u16 => FailoverCapability(types::FailoverCapability::try_read(reader)?),
+        77u16 => TunnelRecovery(types::TunnelRecovery::try_read(reader)?),
+        78u16 => SuggestedControlSequence(types::SuggestedControlSequence::try_read(reader)?),
+        79u16 => FailoverSessionState(types::FailoverSessionState::try_read(reader)?),
+        80u16 => MulticastCapability(types::MulticastCapability::try_read(reader)?),
+        81u16 => NewOutgoingSessions(types::NewOutgoingSessions::try_read(reader)?),
+        82u16 => NewOutgoingSessionsAcknowledgement(
+            types::NewOutgoingSessionsAcknowledgement::try_read(reader)?,
+        ),
+        83u16 => WithdrawOutgoingSessions(types::WithdrawOutgoingSessions::try_read(reader)?),
+        84u16 => MulticastPacketsPriority(types::MulticastPacketsPriority::try_read(reader)?),
+        97u16 => ConnectSpeedUpdate(types::ConnectSpeedUpdate::try_read(reader)?),
+        98u16 => ConnectSpeedUpdateEnable(types::ConnectSpeedUpdateEnable::default()),
         x => Err(DecodeError::UnknownAvp(x))?,
     })
 }
@@ -182,7 +297,9 @@ impl AVP {
     /// # Summary
     /// Convert this `AVP` into a `Hidden` AVP using the L2TP-protocol-specified encryption and padding algorithm.
     ///
-    /// If this `AVP` is _already_ a `Hidden` AVP, then return it unaltered.
+    /// If this `AVP` is _already_ a `Hidden` AVP or is a `Vendor` AVP, then return it unaltered.
+    ///
+    /// Fails if this `AVP` or the resulting `Hidden` AVP exceeds the maximum AVP length.
     ///
     /// # Parameters
     /// * `secret` - A shared secret.
@@ -195,10 +312,12 @@ impl AVP {
         random_vector: &types::RandomVector,
         length_padding: &[u8],
         alignment_padding: &[u8; Self::CRYPTO_CHUNK_SIZE],
-    ) -> Self {
+    ) -> EncodeResult<Self> {
         match &self {
-            Hidden(_) => self,
+            Hidden(_) | Vendor(_) => Ok(self),
             avp => {
+                avp.get_checked_length()?;
+
                 let chunk_size: usize = Self::CRYPTO_CHUNK_SIZE;
 
                 let mut writer = VecWriter::new();
@@ -215,7 +334,6 @@ impl AVP {
                     writer.data.len() + Header::LENGTH as usize - Self::ATTRIBUTE_TYPE_SIZE;
 
                 // Overwrite Attribute Type with AVP length
-                assert!(length <= Self::MAX_LENGTH as usize);
                 let length_octets = (length as u16).to_be_bytes();
                 writer.write_bytes_at(&length_octets, 0);
 
@@ -270,10 +388,14 @@ impl AVP {
                     }
                 }
 
-                Hidden(types::Hidden {
+                let hidden = Hidden(types::Hidden {
                     attribute_type: u16::from_be_bytes(attribute_type_octets),
                     value: input,
-                })
+                });
+                // Padding may push the hidden AVP over the maximum length
+                hidden.get_checked_length()?;
+
+                Ok(hidden)
             }
         }
     }
@@ -372,13 +494,31 @@ impl AVP {
                 result.push(Err(DecodeError::InvalidAVPLength(header.payload_length)));
                 break;
             }
-            if header.vendor_id != 0 {
-                result.push(Err(DecodeError::UnsupportedVendorId(header.vendor_id)));
-                reader.skip_bytes(header.payload_length as usize);
-                continue;
-            }
-
-            let avp = if header.flags.is_hidden() {
+            let avp = if header.vendor_id != 0 {
+                // Vendor-specific AVP
+                let value = reader
+                    .bytes(header.payload_length as usize)
+                    .map(|x| x.borrow().to_owned())
+                    .unwrap_or_default();
+                let is_mandatory = header.flags.is_mandatory();
+                if is_mandatory
+                    && vendor_dictionary_name(header.vendor_id, header.attribute_type).is_none()
+                {
+                    // The session or tunnel must be torn down, so don't let the AVP pass silently
+                    result.push(Err(DecodeError::UnrecognizedMandatoryVendorAVP(
+                        header.vendor_id,
+                        header.attribute_type,
+                    )));
+                    continue;
+                }
+                Ok(Self::Vendor(types::Vendor {
+                    vendor_id: header.vendor_id,
+                    attribute_type: header.attribute_type,
+                    is_mandatory,
+                    is_hidden: header.flags.is_hidden(),
+                    value,
+                }))
+            } else if header.flags.is_hidden() {
                 // Hidden AVP
                 let hidden_data = reader
                     .bytes(header.payload_length as usize)
@@ -391,7 +531,15 @@ impl AVP {
             } else {
                 // Regular AVP
                 let mut subreader = reader.subreader(header.payload_length as usize);
-                decode_avp(header.attribute_type, &mut subreader)
+                match decode_avp(header.attribute_type, &mut subreader) {
+                    // Unknown message types may only be ignored if the M bit is clear
+                    Ok(MessageType(message_type))
+                        if !message_type.is_known() && header.flags.is_mandatory() =>
+                    {
+                        Err(DecodeError::UnknownMessageType(message_type.get_code()))
+                    }
+                    avp => avp,
+                }
             };
             result.push(avp);
         }
@@ -399,6 +547,25 @@ impl AVP {
         result
     }
 
+    /// # Summary
+    /// Get the attribute type of this `AVP`.
+    ///
+    /// For a `Hidden` AVP this is the attribute type of the encapsulated AVP.
+    #[inline]
+    pub fn get_attribute_type(&self) -> u16 {
+        QueryableAVP::get_attribute_type(self)
+    }
+
+    /// # Summary
+    /// Get the name of this `AVP`, taking the vendor ID of `Vendor` AVPs into account.
+    #[inline]
+    pub fn name(&self) -> String {
+        match self {
+            Vendor(vendor) => vendor_avp_name(vendor.vendor_id, vendor.attribute_type),
+            avp => avp_name(avp.get_attribute_type()),
+        }
+    }
+
     /// # Summary
     /// Get the length of this `AVP`.
     #[inline]
@@ -406,25 +573,80 @@ impl AVP {
         QueryableAVP::get_length(self)
     }
 
+    /// # Summary
+    /// Check whether this `AVP` is written with the M (mandatory) bit set.
+    #[inline]
+    pub fn is_mandatory(&self) -> bool {
+        QueryableAVP::is_mandatory(self)
+    }
+
     #[inline]
-    fn make_flags_and_length(is_mandatory: bool, is_hidden: bool, length: usize) -> [u8; 2] {
-        assert!(length <= Self::MAX_LENGTH as usize);
+    fn make_flags_and_length(&self, is_hidden: bool, length: usize) -> EncodeResult<[u8; 2]> {
+        if length > Self::MAX_LENGTH as usize {
+            return Err(EncodeError::OversizeAVP(self.get_attribute_type(), length));
+        }
 
         let msb = ((length >> 8) & 0x3) as u8;
         let lsb = length as u8;
-        let m_bit = is_mandatory as u8;
+        let m_bit = self.is_mandatory() as u8;
         let h_bit = (is_hidden as u8) << 1;
         let octet1 = (msb << 6) | m_bit | h_bit;
         let octet2 = lsb;
-        [octet1, octet2]
+        Ok([octet1, octet2])
+    }
+
+    /// # Summary
+    /// Attempt to write an `AVP` using a `Writer`.
+    ///
+    /// Nothing is written if the `AVP` exceeds the maximum AVP length.
+    #[inline]
+    pub fn try_write(&self, writer: &mut impl Writer) -> EncodeResult<()> {
+        self.write_checked(writer)?;
+
+        if writer.has_overflowed() {
+            return Err(EncodeError::WriterCapacityExhausted);
+        }
+
+        Ok(())
     }
 
     /// # Summary
     /// Write an `AVP` using a `Writer`.
+    ///
+    /// # Panics
+    /// Panics if the `AVP` exceeds the maximum AVP length. See `try_write` for a non-panicking alternative.
     #[inline]
     pub fn write(&self, writer: &mut impl Writer) {
-        const VENDOR_ID: u16 = 0;
-        const IS_MANDATORY: bool = true;
+        if let Err(e) = self.write_checked(writer) {
+            panic!("{e}");
+        }
+    }
+
+    /// # Summary
+    /// Get the total encoded length of this `AVP` including its header, or an error if it exceeds the maximum AVP length.
+    #[inline]
+    pub(crate) fn get_checked_length(&self) -> EncodeResult<usize> {
+        let length = Header::LENGTH as usize + self.get_length();
+        if length > Self::MAX_LENGTH as usize {
+            return Err(EncodeError::OversizeAVP(self.get_attribute_type(), length));
+        }
+
+        Ok(length)
+    }
+
+    /// # Summary
+    /// Write an `AVP` using a `Writer` if it doesn't exceed the maximum AVP length.
+    ///
+    /// Note: `Writer` overflow is left for the caller to check.
+    #[inline]
+    pub(crate) fn write_checked(&self, writer: &mut impl Writer) -> EncodeResult<()> {
+        self.get_checked_length()?;
+
+        let (vendor_id, is_hidden) = match self {
+            Vendor(vendor) => (vendor.vendor_id, vendor.is_hidden),
+            Hidden(_) => (0, true),
+            _ => (0, false),
+        };
 
         // Save header position
         let start_position = writer.len();
@@ -433,7 +655,7 @@ impl AVP {
         writer.write_bytes(&[0, 0]);
 
         // Write rest of header
-        writer.write_u16_be(VENDOR_ID);
+        writer.write_u16_be(vendor_id);
 
         // Write payload
         WritableAVP::write(self, writer);
@@ -442,11 +664,11 @@ impl AVP {
         let end_position = writer.len();
         let length = end_position - start_position;
 
-        let is_hidden = matches!(self, Hidden(_));
-
-        let flags_and_length = Self::make_flags_and_length(IS_MANDATORY, is_hidden, length);
+        let flags_and_length = self.make_flags_and_length(is_hidden, length)?;
 
         // Oerwrite dummy octets
         writer.write_bytes_at(&flags_and_length, start_position);
+
+        Ok(())
     }
 }
@@ -0,0 +1,141 @@
+//! Broadband Forum (formerly DSL Forum) vendor-specific AVPs, relaying TR-101 access line information to the LNS.
+//!
+//! The attribute types match the RADIUS vendor-specific attributes of RFC 4679, to which the AVPs can be mapped.
+
+use crate::avp::types::{fixed_value, VendorSpecificAVP};
+use crate::common::{DecodeError, DecodeResult, EncodeError, EncodeResult, VecWriter, Writer};
+
+/// # Summary
+/// The SMI network management private enterprise code of the Broadband Forum.
+pub const VENDOR_ID: u16 = 3561;
+
+/// # Summary
+/// The RADIUS Vendor-Specific attribute type (RFC 2865).
+pub const RADIUS_VENDOR_SPECIFIC: u8 = 26;
+
+/// # Summary
+/// A Broadband Forum vendor-specific AVP with a typed value, decoded and encoded through `VendorSpecificAVP`.
+///
+/// Data rates are in bits per second.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BroadbandForumAvp {
+    AgentCircuitId(Vec<u8>),
+    AgentRemoteId(Vec<u8>),
+    ActualDataRateUpstream(u32),
+    ActualDataRateDownstream(u32),
+    MinimumDataRateUpstream(u32),
+    MinimumDataRateDownstream(u32),
+    AttainableDataRateUpstream(u32),
+    AttainableDataRateDownstream(u32),
+}
+
+use BroadbandForumAvp::*;
+
+/// # Summary
+/// Get the name of a Broadband Forum AVP given its attribute type, or `None` if it is not in the dictionary.
+pub fn name(attribute_type: u16) -> Option<&'static str> {
+    Some(match attribute_type {
+        1u16 => "AgentCircuitId",
+        2u16 => "AgentRemoteId",
+        129u16 => "ActualDataRateUpstream",
+        130u16 => "ActualDataRateDownstream",
+        131u16 => "MinimumDataRateUpstream",
+        132u16 => "MinimumDataRateDownstream",
+        133u16 => "AttainableDataRateUpstream",
+        134u16 => "AttainableDataRateDownstream",
+        _ => return None,
+    })
+}
+
+impl VendorSpecificAVP for BroadbandForumAvp {
+    const VENDOR_ID: u16 = VENDOR_ID;
+
+    fn try_read_value(attribute_type: u16, value: &[u8]) -> DecodeResult<Self> {
+        let data_rate = || fixed_value(value).map(u32::from_be_bytes);
+
+        Ok(match attribute_type {
+            1u16 => AgentCircuitId(value.to_owned()),
+            2u16 => AgentRemoteId(value.to_owned()),
+            129u16 => ActualDataRateUpstream(data_rate()?),
+            130u16 => ActualDataRateDownstream(data_rate()?),
+            131u16 => MinimumDataRateUpstream(data_rate()?),
+            132u16 => MinimumDataRateDownstream(data_rate()?),
+            133u16 => AttainableDataRateUpstream(data_rate()?),
+            134u16 => AttainableDataRateDownstream(data_rate()?),
+            x => return Err(DecodeError::UnknownAvp(x)),
+        })
+    }
+
+    fn write_value(&self, writer: &mut impl Writer) {
+        match self {
+            AgentCircuitId(x) | AgentRemoteId(x) => writer.write_bytes(x),
+            ActualDataRateUpstream(x)
+            | ActualDataRateDownstream(x)
+            | MinimumDataRateUpstream(x)
+            | MinimumDataRateDownstream(x)
+            | AttainableDataRateUpstream(x)
+            | AttainableDataRateDownstream(x) => writer.write_u32_be(*x),
+        }
+    }
+
+    /// The attribute type is also the RADIUS vendor type.
+    fn get_attribute_type(&self) -> u16 {
+        match self {
+            AgentCircuitId(_) => 1,
+            AgentRemoteId(_) => 2,
+            ActualDataRateUpstream(_) => 129,
+            ActualDataRateDownstream(_) => 130,
+            MinimumDataRateUpstream(_) => 131,
+            MinimumDataRateDownstream(_) => 132,
+            AttainableDataRateUpstream(_) => 133,
+            AttainableDataRateDownstream(_) => 134,
+        }
+    }
+}
+
+impl BroadbandForumAvp {
+    const RADIUS_HEADER_LENGTH: usize = 8;
+
+    /// # Summary
+    /// Try to decode the vendor type and value of a Broadband Forum RADIUS vendor-specific attribute.
+    pub fn try_from_radius(vendor_type: u8, value: &[u8]) -> DecodeResult<Self> {
+        Self::try_read_value(vendor_type as u16, value)
+    }
+
+    /// # Summary
+    /// Write this Broadband Forum AVP as a RADIUS Vendor-Specific attribute using a `Writer`.
+    ///
+    /// Nothing is written if the value does not fit in a RADIUS attribute.
+    pub fn write_radius_attribute(&self, writer: &mut impl Writer) -> EncodeResult<()> {
+        let mut value = VecWriter::new();
+        self.write_value(&mut value);
+        let length = Self::RADIUS_HEADER_LENGTH + value.len();
+        if length > u8::MAX as usize {
+            return Err(EncodeError::OversizeRadiusAttribute(
+                self.get_attribute_type() as u8,
+                length,
+            ));
+        }
+
+        writer.write_u8(RADIUS_VENDOR_SPECIFIC);
+        writer.write_u8(length as u8);
+        writer.write_u32_be(VENDOR_ID as u32);
+        writer.write_u8(self.get_attribute_type() as u8);
+        writer.write_u8((length - 6) as u8);
+        writer.write_bytes(&value.data);
+
+        if writer.has_overflowed() {
+            return Err(EncodeError::WriterCapacityExhausted);
+        }
+
+        Ok(())
+    }
+
+    /// # Summary
+    /// Encode this Broadband Forum AVP as a RADIUS Vendor-Specific attribute.
+    pub fn to_radius_attribute(&self) -> EncodeResult<Vec<u8>> {
+        let mut writer = VecWriter::new();
+        self.write_radius_attribute(&mut writer)?;
+        Ok(writer.data)
+    }
+}
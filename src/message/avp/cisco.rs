@@ -0,0 +1,126 @@
+//! A dictionary of common Cisco vendor-specific AVPs, as sent by Cisco LACs and by L2TPv3 peers in draft mode.
+//!
+//! The agent circuit and remote identifiers of PPPoE intermediate agents aren't Cisco AVPs. Cisco LACs forward
+//! them using the Broadband Forum vendor ID, so they are decoded by `broadband_forum::BroadbandForumAvp`.
+use crate::avp::types::{fixed_value, VendorSpecificAVP};
+use crate::common::{DecodeError, DecodeResult, Writer};
+
+/// # Summary
+/// The SMI network management private enterprise code of Cisco Systems.
+pub const VENDOR_ID: u16 = 9;
+
+/// # Summary
+/// A Cisco vendor-specific AVP with a typed value, decoded and encoded through `VendorSpecificAVP`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CiscoAvp {
+    AssignedConnectionId(u32),
+    PseudowireCapabilitiesList(Vec<u16>),
+    LocalSessionId(u32),
+    RemoteSessionId(u32),
+    AssignedCookie(Vec<u8>),
+    RemoteEndId(Vec<u8>),
+    PseudowireType(u16),
+    CircuitStatus(u16),
+    SessionTieBreaker(u64),
+    DraftAvpVersion(u16),
+    MessageDigest(Vec<u8>),
+    AuthNonce(Vec<u8>),
+    InterfaceMtu(u16),
+}
+
+use CiscoAvp::*;
+
+/// # Summary
+/// Get the name of a Cisco AVP given its attribute type, or `None` if it is not in the dictionary.
+pub fn name(attribute_type: u16) -> Option<&'static str> {
+    Some(match attribute_type {
+        1u16 => "AssignedConnectionId",
+        2u16 => "PseudowireCapabilitiesList",
+        3u16 => "LocalSessionId",
+        4u16 => "RemoteSessionId",
+        5u16 => "AssignedCookie",
+        6u16 => "RemoteEndId",
+        7u16 => "PseudowireType",
+        8u16 => "CircuitStatus",
+        9u16 => "SessionTieBreaker",
+        10u16 => "DraftAvpVersion",
+        12u16 => "MessageDigest",
+        13u16 => "AuthNonce",
+        14u16 => "InterfaceMtu",
+        _ => return None,
+    })
+}
+
+impl VendorSpecificAVP for CiscoAvp {
+    const VENDOR_ID: u16 = VENDOR_ID;
+
+    fn try_read_value(attribute_type: u16, value: &[u8]) -> DecodeResult<Self> {
+        let u16_value = || fixed_value(value).map(u16::from_be_bytes);
+        let u32_value = || fixed_value(value).map(u32::from_be_bytes);
+
+        Ok(match attribute_type {
+            1u16 => AssignedConnectionId(u32_value()?),
+            2u16 => {
+                let pseudowire_types = value.chunks_exact(2);
+                if !pseudowire_types.remainder().is_empty() {
+                    return Err(DecodeError::InvalidAVPLength(value.len() as u16));
+                }
+                PseudowireCapabilitiesList(
+                    pseudowire_types
+                        .map(|x| u16::from_be_bytes([x[0], x[1]]))
+                        .collect(),
+                )
+            }
+            3u16 => LocalSessionId(u32_value()?),
+            4u16 => RemoteSessionId(u32_value()?),
+            5u16 => AssignedCookie(value.to_owned()),
+            6u16 => RemoteEndId(value.to_owned()),
+            7u16 => PseudowireType(u16_value()?),
+            8u16 => CircuitStatus(u16_value()?),
+            9u16 => SessionTieBreaker(u64::from_be_bytes(fixed_value(value)?)),
+            10u16 => DraftAvpVersion(u16_value()?),
+            12u16 => MessageDigest(value.to_owned()),
+            13u16 => AuthNonce(value.to_owned()),
+            14u16 => InterfaceMtu(u16_value()?),
+            x => return Err(DecodeError::UnknownAvp(x)),
+        })
+    }
+
+    fn write_value(&self, writer: &mut impl Writer) {
+        match self {
+            AssignedConnectionId(x) | LocalSessionId(x) | RemoteSessionId(x) => {
+                writer.write_u32_be(*x)
+            }
+            PseudowireCapabilitiesList(pseudowire_types) => {
+                for pseudowire_type in pseudowire_types.iter() {
+                    writer.write_u16_be(*pseudowire_type);
+                }
+            }
+            AssignedCookie(x) | RemoteEndId(x) | MessageDigest(x) | AuthNonce(x) => {
+                writer.write_bytes(x)
+            }
+            PseudowireType(x) | CircuitStatus(x) | DraftAvpVersion(x) | InterfaceMtu(x) => {
+                writer.write_u16_be(*x)
+            }
+            SessionTieBreaker(x) => writer.write_u64_be(*x),
+        }
+    }
+
+    fn get_attribute_type(&self) -> u16 {
+        match self {
+            AssignedConnectionId(_) => 1,
+            PseudowireCapabilitiesList(_) => 2,
+            LocalSessionId(_) => 3,
+            RemoteSessionId(_) => 4,
+            AssignedCookie(_) => 5,
+            RemoteEndId(_) => 6,
+            PseudowireType(_) => 7,
+            CircuitStatus(_) => 8,
+            SessionTieBreaker(_) => 9,
+            DraftAvpVersion(_) => 10,
+            MessageDigest(_) => 12,
+            AuthNonce(_) => 13,
+            InterfaceMtu(_) => 14,
+        }
+    }
+}
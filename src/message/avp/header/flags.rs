@@ -14,7 +14,6 @@ impl Flags {
         (self.data >> i) & 0x1 != 0
     }
 
-    #[allow(dead_code)] // Remove upon first use
     #[inline]
     pub fn is_mandatory(&self) -> bool {
         self.get_bit(0)
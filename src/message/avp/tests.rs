@@ -1 +1,6 @@
+#[cfg(feature = "broadband-forum")]
+mod broadband_forum;
+#[cfg(feature = "cisco")]
+mod cisco;
+mod vendor;
 mod write_read;
@@ -0,0 +1,88 @@
+use crate::avp::broadband_forum::*;
+use crate::avp::types::VendorSpecificAVP;
+use crate::avp::*;
+use crate::common::{DecodeError, EncodeError, SliceReader};
+
+fn access_line() -> Vec<BroadbandForumAvp> {
+    vec![
+        BroadbandForumAvp::AgentCircuitId(b"dslam-1 atm 1/1:0.35".to_vec()),
+        BroadbandForumAvp::AgentRemoteId(b"subscriber-1".to_vec()),
+        BroadbandForumAvp::ActualDataRateUpstream(1_024_000),
+        BroadbandForumAvp::ActualDataRateDownstream(16_384_000),
+        BroadbandForumAvp::MinimumDataRateUpstream(64_000),
+        BroadbandForumAvp::MinimumDataRateDownstream(512_000),
+        BroadbandForumAvp::AttainableDataRateUpstream(1_200_000),
+        BroadbandForumAvp::AttainableDataRateDownstream(20_000_000),
+    ]
+}
+
+#[test]
+fn read() {
+    let data = [
+        0x00, 0x0a, // Flags and Length
+        0x0d, 0xe9, // Vendor ID
+        0x00, 0x82, // Attribute Type (Actual Data Rate Downstream)
+        0x00, 0xfa, 0x00, 0x00, // Data rate
+    ];
+    let mut r = SliceReader::from(&data);
+    let avp = AVP::try_read_greedy(&mut r).remove(0).unwrap();
+
+    assert_eq!(avp.name(), "BroadbandForumActualDataRateDownstream");
+    assert_eq!(
+        BroadbandForumAvp::try_from_avp(&avp),
+        Ok(BroadbandForumAvp::ActualDataRateDownstream(16_384_000))
+    );
+}
+
+#[test]
+fn read_errors() {
+    let cases = [
+        (3, vec![], DecodeError::UnknownAvp(3)),
+        (129, vec![0x00, 0x01], DecodeError::InvalidAVPLength(2)),
+        (134, vec![0x00; 5], DecodeError::InvalidAVPLength(5)),
+    ];
+
+    for (vendor_type, value, error) in cases {
+        assert_eq!(
+            BroadbandForumAvp::try_from_radius(vendor_type, &value),
+            Err(error)
+        );
+    }
+}
+
+#[test]
+fn radius() {
+    let input = BroadbandForumAvp::ActualDataRateUpstream(1_024_000);
+    assert_eq!(
+        input.to_radius_attribute(),
+        Ok(vec![
+            0x1a, 0x0c, // Type (Vendor-Specific) and Length
+            0x00, 0x00, 0x0d, 0xe9, // Vendor ID
+            0x81, 0x06, // Vendor Type and Length
+            0x00, 0x0f, 0xa0, 0x00, // Data rate
+        ])
+    );
+
+    for input in access_line() {
+        let attribute = input.to_radius_attribute().unwrap();
+        assert_eq!(attribute[1] as usize, attribute.len());
+        assert_eq!(
+            BroadbandForumAvp::try_from_radius(attribute[6], &attribute[8..]),
+            Ok(input)
+        );
+    }
+
+    let input = BroadbandForumAvp::AgentRemoteId(vec![0x61; 248]);
+    assert_eq!(
+        input.to_radius_attribute(),
+        Err(EncodeError::OversizeRadiusAttribute(2, 256))
+    );
+}
+
+#[test]
+fn names() {
+    assert_eq!(name(1), Some("AgentCircuitId"));
+    assert_eq!(name(135), None);
+    assert_eq!(vendor_avp_name(3561, 2), "BroadbandForumAgentRemoteId");
+    assert_eq!(vendor_avp_name(3561, 135), "3561:135");
+}
@@ -0,0 +1,72 @@
+use crate::avp::cisco::*;
+use crate::avp::types::VendorSpecificAVP;
+use crate::avp::*;
+use crate::common::{DecodeError, SliceReader, VecWriter};
+
+#[test]
+fn write_read() {
+    let cases = [
+        CiscoAvp::AssignedConnectionId(0xdeadbeef),
+        CiscoAvp::PseudowireCapabilitiesList(vec![0x0004, 0x0005]),
+        CiscoAvp::LocalSessionId(0x1337),
+        CiscoAvp::RemoteSessionId(0xbeef),
+        CiscoAvp::AssignedCookie(vec![0xde, 0xad, 0xbe, 0xef]),
+        CiscoAvp::RemoteEndId(b"pe-1".to_vec()),
+        CiscoAvp::PseudowireType(0x0005),
+        CiscoAvp::CircuitStatus(0x0001),
+        CiscoAvp::SessionTieBreaker(0xdeadbeef13371337),
+        CiscoAvp::DraftAvpVersion(1),
+        CiscoAvp::MessageDigest(vec![0x01; 16]),
+        CiscoAvp::AuthNonce(vec![0x02; 8]),
+        CiscoAvp::InterfaceMtu(1500),
+    ];
+
+    for input in cases {
+        let mut w = VecWriter::new();
+        input.write_value(&mut w);
+
+        let output = CiscoAvp::try_read_value(input.get_attribute_type(), &w.data);
+        assert_eq!(output, Ok(input));
+    }
+}
+
+#[test]
+fn read() {
+    let data = [
+        0x00, 0x08, // Flags and Length
+        0x00, 0x09, // Vendor ID
+        0x00, 0x0e, // Attribute Type (Interface MTU)
+        0x05, 0xdc, // MTU
+    ];
+    let mut r = SliceReader::from(&data);
+    let avp = AVP::try_read_greedy(&mut r).remove(0).unwrap();
+
+    assert_eq!(avp.name(), "CiscoInterfaceMtu");
+    assert_eq!(
+        CiscoAvp::try_from_avp(&avp),
+        Ok(CiscoAvp::InterfaceMtu(1500))
+    );
+}
+
+#[test]
+fn read_errors() {
+    let cases = [
+        (11, vec![], DecodeError::UnknownAvp(11)),
+        (14, vec![0x05], DecodeError::InvalidAVPLength(1)),
+        (9, vec![0x00; 4], DecodeError::InvalidAVPLength(4)),
+        (2, vec![0x00, 0x04, 0x00], DecodeError::InvalidAVPLength(3)),
+    ];
+
+    for (attribute_type, value, error) in cases {
+        assert_eq!(CiscoAvp::try_read_value(attribute_type, &value), Err(error));
+    }
+}
+
+#[test]
+fn names() {
+    assert_eq!(name(1), Some("AssignedConnectionId"));
+    assert_eq!(name(11), None);
+    assert_eq!(vendor_avp_name(9, 8), "CiscoCircuitStatus");
+    assert_eq!(vendor_avp_name(9, 11), "9:11");
+    assert_eq!(vendor_avp_name(311, 1), "311:1");
+}
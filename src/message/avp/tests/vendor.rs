@@ -0,0 +1,102 @@
+use crate::avp::types::{fixed_value, VendorSpecificAVP};
+use crate::avp::*;
+use crate::common::{DecodeError, DecodeResult, SliceReader, VecWriter, Writer};
+
+/// A single AVP dictionary of a vendor that is not in any of the vendor dictionaries.
+#[derive(Debug, Eq, PartialEq)]
+struct Mtu(u16);
+
+impl VendorSpecificAVP for Mtu {
+    const VENDOR_ID: u16 = 311;
+
+    fn try_read_value(attribute_type: u16, value: &[u8]) -> DecodeResult<Self> {
+        match attribute_type {
+            1u16 => Ok(Mtu(u16::from_be_bytes(fixed_value(value)?))),
+            x => Err(DecodeError::UnknownAvp(x)),
+        }
+    }
+
+    fn write_value(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(self.0);
+    }
+
+    fn get_attribute_type(&self) -> u16 {
+        1
+    }
+}
+
+#[test]
+fn write_read() {
+    for is_mandatory in [false, true] {
+        let avp = Mtu(1500).to_avp(is_mandatory);
+        assert_eq!(
+            avp,
+            AVP::Vendor(types::Vendor {
+                vendor_id: 311,
+                attribute_type: 1,
+                is_mandatory,
+                is_hidden: false,
+                value: vec![0x05, 0xdc],
+            })
+        );
+
+        let mut w = VecWriter::new();
+        avp.write(&mut w);
+        assert_eq!(
+            w.data,
+            [
+                is_mandatory as u8,
+                0x08, // Flags and Length
+                0x01,
+                0x37, // Vendor ID
+                0x00,
+                0x01, // Attribute Type
+                0x05,
+                0xdc, // MTU
+            ]
+        );
+    }
+
+    let mut r = SliceReader::from(&[
+        0x00, 0x08, // Flags and Length
+        0x01, 0x37, // Vendor ID
+        0x00, 0x01, // Attribute Type
+        0x05, 0xdc, // MTU
+    ]);
+    let avp = AVP::try_read_greedy(&mut r).remove(0).unwrap();
+    assert_eq!(Mtu::try_from_avp(&avp), Ok(Mtu(1500)));
+}
+
+#[test]
+fn read_errors() {
+    let vendor = |vendor_id, attribute_type, is_hidden, value: &[u8]| types::Vendor {
+        vendor_id,
+        attribute_type,
+        is_mandatory: false,
+        is_hidden,
+        value: value.to_vec(),
+    };
+    let cases = [
+        (
+            vendor(9, 1, false, &[0x05, 0xdc]),
+            DecodeError::UnsupportedVendorId(9),
+        ),
+        (
+            vendor(311, 1, true, &[0x05, 0xdc]),
+            DecodeError::HiddenVendorAVP(311, 1),
+        ),
+        (vendor(311, 2, false, &[]), DecodeError::UnknownAvp(2)),
+        (
+            vendor(311, 1, false, &[0x05]),
+            DecodeError::InvalidAVPLength(1),
+        ),
+    ];
+
+    for (input, error) in cases {
+        assert_eq!(Mtu::try_from_vendor(&input), Err(error));
+    }
+    assert_eq!(
+        Mtu::try_from_avp(&AVP::HostName(b"lac".to_vec().into())),
+        Err(DecodeError::NotVendorAVP(7))
+    );
+}
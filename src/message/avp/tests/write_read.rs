@@ -1,5 +1,5 @@
 use crate::avp::*;
-use crate::common::{SliceReader, VecWriter};
+use crate::common::{DecodeError, EncodeError, SliceReader, SliceWriter, VecWriter, Writer};
 
 macro_rules! io_tests {
     [$($name:ident => $input:expr),+] => {
@@ -11,6 +11,9 @@ macro_rules! io_tests {
             let mut w = VecWriter::new();
             $input.write(&mut w);
 
+            // Assert that the precomputed length matches the encoded length
+            assert_eq!(w.data.len(), 6 + input.get_length());
+
             // Deserialize to output
             let mut r = SliceReader::from(&w.data);
             let avps = AVP::try_read_greedy(&mut r);
@@ -45,6 +48,27 @@ called_number => AVP::CalledNumber("TestingNumber".to_owned().into()),
 calling_number => AVP::CallingNumber("TestingNumber".to_owned().into()),
 challenge => AVP::Challenge(vec![0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08].into()),
 challenge_response => AVP::ChallengeResponse([0x00,0x01, 0x02, 0x03, 0x04,0x05,0x06,0x07,0x08,0x09,0x0a,0x0b,0x0c,0x0d,0x0e,0x0f].into()),
+control_connection_ds => AVP::ControlConnectionDs(types::ControlConnectionDs{phb: types::PhbId::from_dscp(46)}),
+connect_speed_update => AVP::ConnectSpeedUpdate(types::ConnectSpeedUpdate{
+    remote_session_id: 0x1337,
+    tx_connect_speed: 0xdeadbeef,
+    rx_connect_speed: 0x13371337
+}),
+connect_speed_update_64 => AVP::ConnectSpeedUpdate(types::ConnectSpeedUpdate{
+    remote_session_id: 0x1337,
+    tx_connect_speed: 0xdeadbeef13371337,
+    rx_connect_speed: 0x13371337
+}),
+connect_speed_update_enable => AVP::ConnectSpeedUpdateEnable(types::ConnectSpeedUpdateEnable{}),
+failover_capability => AVP::FailoverCapability(types::FailoverCapability{
+    control_channel: true,
+    data_channel: false,
+    recovery_time: 0x13371337
+}),
+failover_session_state => AVP::FailoverSessionState(types::FailoverSessionState{
+    sender_session_id: 0x1337,
+    receiver_session_id: 0xbeef
+}),
 firmware_revision => AVP::FirmwareRevision(0x1337.into()),
 framing_capabilities => AVP::FramingCapabilities(types::FramingCapabilities::new(true, true)),
 framing_type => AVP::FramingType(types::FramingType::new(true, true)),
@@ -52,11 +76,35 @@ host_name => AVP::HostName("test-host.com".as_bytes().to_owned().into()),
 initial_received_lcp_conf_req => AVP::InitialReceivedLcpConfReq(vec![0xde,0xad,0xbe,0xef].into()),
 last_received_lcp_conf_req => AVP::LastReceivedLcpConfReq(vec![0xde,0xad,0xbe,0xef].into()),
 last_sent_lcp_conf_req => AVP::LastSentLcpConfReq(vec![0xde,0xad,0xbe,0xef].into()),
+lcp_allow_options => AVP::LcpAllowOptions(vec![0x11,0x04,0x05,0xd4].into()),
+lcp_want_options => AVP::LcpWantOptions(vec![0x13,0x03,0x00].into()),
+lns_last_received_lcp_conf_req => AVP::LnsLastReceivedLcpConfReq(vec![0xde,0xad,0xbe,0xef].into()),
+lns_last_sent_lcp_conf_req => AVP::LnsLastSentLcpConfReq(vec![0xde,0xad,0xbe,0xef].into()),
 maximum_bps => AVP::MaximumBps(0x13371337.into()),
 message_type => AVP::MessageType(types::MessageType::IncomingCallConnected),
+message_type_reserved => AVP::MessageType(types::MessageType::from_code(13)),
+message_type_unknown => AVP::MessageType(types::MessageType::from_code(0x1337)),
+modem_on_hold_capable => AVP::ModemOnHoldCapable(types::ModemOnHoldCapable{timeout: 0x1337}),
+modem_on_hold_status => AVP::ModemOnHoldStatus(types::ModemOnHoldStatus{on_hold: true}),
+multicast_capability => AVP::MulticastCapability(types::MulticastCapability{version: 1}),
+multicast_packets_priority => AVP::MulticastPacketsPriority(types::MulticastPacketsPriority{flows: vec![
+    types::MulticastFlowPriority{
+        priority: 7,
+        source_address: [192, 0, 2, 1].into(),
+        group_address: [233, 252, 0, 1].into()
+    }
+]}),
 minimum_bps => AVP::MinimumBps(0x13371337.into()),
+new_outgoing_sessions => AVP::NewOutgoingSessions(types::NewOutgoingSessions{session_ids: vec![0x1337, 0xbeef]}),
+new_outgoing_sessions_acknowledgement => AVP::NewOutgoingSessionsAcknowledgement(types::NewOutgoingSessionsAcknowledgement{session_ids: vec![0x1337]}),
 physical_channel_id => AVP::PhysicalChannelId([0xde,0xad,0xbe,0xef].into()),
 private_group_id => AVP::PrivateGroupId(vec![0xde,0xad,0xbe,0xef].into()),
+ppp_disconnect_cause_code => AVP::PppDisconnectCauseCode(types::PppDisconnectCauseCode{
+    disconnect_code: types::PppDisconnectCode::MagicNumberError.into(),
+    control_protocol_number: 0xc021,
+    direction: types::PppDisconnectDirection::AtPeer.into(),
+    message: Some("Looped back".to_owned())
+}),
 protocol_version => AVP::ProtocolVersion(types::ProtocolVersion{version: 0xf0, revision: 0x0d}),
 proxy_authen_challenge => AVP::ProxyAuthenChallenge(vec![0xde,0xad,0xbe,0xef].into()),
 proxy_authen_name => AVP::ProxyAuthenName(vec![0xde,0xad,0xbe,0xef].into()),
@@ -74,10 +122,27 @@ result_code => AVP::ResultCode(types::ResultCode{
 }),
 rx_connect_speed => AVP::RxConnectSpeed(0xdeadbeef.into()),
 sequencing_required => AVP::SequencingRequired(types::SequencingRequired{}),
+session_ds => AVP::SessionDs(types::SessionDs{phbs: vec![types::PhbId::from_dscp(46), types::PhbId(0x1337)]}),
 sub_address => AVP::SubAddress("subaddress-value".to_owned().into()),
+suggested_control_sequence => AVP::SuggestedControlSequence(types::SuggestedControlSequence{
+    suggested_ns: 0x1337,
+    suggested_nr: 0xbeef
+}),
 tie_breaker => AVP::TieBreaker(0xdeadbeef13371337.into()),
+tunnel_recovery => AVP::TunnelRecovery(types::TunnelRecovery{
+    recover_tunnel_id: 0x1337,
+    recover_remote_tunnel_id: 0xbeef
+}),
 tx_connect_speed => AVP::TxConnectSpeed(0xdeadbeef.into()),
-vendor_name => AVP::VendorName("test vendor".to_owned().into())
+vendor => AVP::Vendor(types::Vendor{
+    vendor_id: 9,
+    attribute_type: 0x1337,
+    is_mandatory: false,
+    is_hidden: false,
+    value: vec![0xde,0xad,0xbe,0xef]
+}),
+vendor_name => AVP::VendorName("test vendor".to_owned().into()),
+withdraw_outgoing_sessions => AVP::WithdrawOutgoingSessions(types::WithdrawOutgoingSessions{session_ids: vec![0xbeef]})
 ];
 
 #[test]
@@ -94,7 +159,8 @@ fn hidden() {
     // Serialize input
     let hidden = input
         .clone()
-        .hide(&secret, &rv, &length_padding, &alignment_padding);
+        .hide(&secret, &rv, &length_padding, &alignment_padding)
+        .unwrap();
     let mut w = VecWriter::new();
     hidden.write(&mut w);
 
@@ -112,3 +178,242 @@ fn hidden() {
     let revealed = output.reveal(&secret, &rv).unwrap();
     assert_eq!(revealed, input);
 }
+
+#[test]
+fn oversize() {
+    let input = AVP::HostName(vec![0x61; 1018].into());
+
+    let mut w = VecWriter::new();
+    assert_eq!(
+        input.try_write(&mut w),
+        Err(EncodeError::OversizeAVP(7, 1024))
+    );
+    assert!(w.is_empty());
+
+    let input = AVP::HostName(vec![0x61; 1017].into());
+    assert_eq!(input.try_write(&mut w), Ok(()));
+    assert_eq!(w.len(), 1023);
+}
+
+#[test]
+fn hide_oversize() {
+    let rv = [0xde, 0xad, 0xbe, 0xef].into();
+    let input = AVP::HostName(vec![0x61; 2000].into());
+    assert_eq!(
+        input.hide(b"secret", &rv, &[], &[0; 16]),
+        Err(EncodeError::OversizeAVP(7, 2006))
+    );
+}
+
+#[test]
+fn writer_capacity_exhausted() {
+    let input = AVP::VendorName("test vendor".to_owned().into());
+
+    let mut buffer = [0x00; 8];
+    let mut w = SliceWriter::from(&mut buffer);
+    assert_eq!(
+        input.try_write(&mut w),
+        Err(EncodeError::WriterCapacityExhausted)
+    );
+}
+
+#[test]
+fn mandatory_bit() {
+    let input = AVP::PppDisconnectCauseCode(types::PppDisconnectCauseCode {
+        disconnect_code: 0,
+        control_protocol_number: 0,
+        direction: 0,
+        message: None,
+    });
+    assert!(!input.is_mandatory());
+
+    let mut w = VecWriter::new();
+    input.write(&mut w);
+    assert_eq!(w.data[0] & 0x01, 0);
+
+    let input = AVP::VendorName("test vendor".to_owned().into());
+    assert!(input.is_mandatory());
+
+    let mut w = VecWriter::new();
+    input.write(&mut w);
+    assert_eq!(w.data[0] & 0x01, 1);
+}
+
+#[test]
+fn vendor_flags() {
+    let data = [
+        0x02, 0x0a, // Flags and Length
+        0x00, 0x09, // Vendor ID
+        0x00, 0x01, // Attribute Type
+        0xde, 0xad, 0xbe, 0xef, // Value
+    ];
+    let expected = AVP::Vendor(types::Vendor {
+        vendor_id: 9,
+        attribute_type: 1,
+        is_mandatory: false,
+        is_hidden: true,
+        value: vec![0xde, 0xad, 0xbe, 0xef],
+    });
+
+    let mut r = SliceReader::from(&data);
+    assert_eq!(AVP::try_read_greedy(&mut r), vec![Ok(expected.clone())]);
+    assert_eq!(expected.get_attribute_type(), 1);
+
+    let mut w = VecWriter::new();
+    expected.write(&mut w);
+    assert_eq!(w.data, data);
+
+    let rv = [0xde, 0xad, 0xbe, 0xef].into();
+    let hidden = expected.clone().hide(b"secret", &rv, &[], &[0; 16]);
+    assert_eq!(hidden, Ok(expected));
+}
+
+#[test]
+fn message_type_mandatory_bit() {
+    let avp = |flags, code: u16| {
+        let mut data = vec![flags, 0x08, 0x00, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&code.to_be_bytes());
+        AVP::try_read_greedy(&mut SliceReader::from(&data))
+    };
+
+    assert_eq!(
+        avp(0x01, 30),
+        vec![Err(DecodeError::UnknownMessageType(30))]
+    );
+    assert_eq!(avp(0x01, 5), vec![Err(DecodeError::UnknownMessageType(5))]);
+    assert_eq!(
+        avp(0x00, 30),
+        vec![Ok(AVP::MessageType(types::MessageType::from_code(30)))]
+    );
+    assert_eq!(
+        avp(0x00, 5),
+        vec![Ok(AVP::MessageType(types::MessageType::from_code(5)))]
+    );
+    assert_eq!(
+        avp(0x01, 6),
+        vec![Ok(AVP::MessageType(types::MessageType::Hello))]
+    );
+}
+
+#[test]
+fn ppp_disconnect_codes() {
+    use types::PppDisconnectCode;
+
+    let cause = |disconnect_code| types::PppDisconnectCauseCode {
+        disconnect_code,
+        control_protocol_number: 0,
+        direction: types::PppDisconnectDirection::Global.into(),
+        message: None,
+    };
+    assert_eq!(
+        cause(7).as_disconnect_code(),
+        PppDisconnectCode::MagicNumberError
+    );
+    assert_eq!(
+        cause(8).as_disconnect_code(),
+        PppDisconnectCode::LcpEchoRequestTimeout
+    );
+    assert_eq!(cause(42).as_disconnect_code(), PppDisconnectCode::Other(42));
+    for code in [0, 8, 42] {
+        assert_eq!(u16::from(cause(code).as_disconnect_code()), code);
+    }
+}
+
+#[test]
+fn message_type_metadata() {
+    use types::MessageType;
+
+    for code in 0..=30 {
+        let message_type = MessageType::from_code(code);
+        assert_eq!(message_type.get_code(), code);
+        assert_eq!(
+            message_type.is_known(),
+            message_type.abbreviation().is_some()
+        );
+    }
+
+    let message_type = MessageType::from_code(10);
+    assert_eq!(message_type, MessageType::IncomingCallRequest);
+    assert_eq!(message_type.name(), "Incoming-Call-Request");
+    assert_eq!(message_type.abbreviation(), Some("ICRQ"));
+    assert!(!message_type.is_ignorable());
+
+    assert_eq!(MessageType::from_code(20).name(), "Unknown");
+    assert!(MessageType::from_code(5).is_ignorable());
+    assert!(!AVP::MessageType(MessageType::from_code(20)).is_mandatory());
+}
+
+#[test]
+fn message_type_canonical_codes() {
+    use types::MessageType;
+
+    for (code, reserved) in [
+        (0, true),
+        (5, true),
+        (13, true),
+        (20, false),
+        (0x1337, false),
+    ] {
+        let message_type = MessageType::from_code(code);
+        assert_eq!(matches!(message_type, MessageType::Reserved(_)), reserved);
+        assert_eq!(matches!(message_type, MessageType::Unknown(_)), !reserved);
+        assert_eq!(message_type.get_code(), code);
+
+        let mut w = VecWriter::new();
+        AVP::MessageType(message_type).write(&mut w);
+        let mut r = SliceReader::from(&w.data);
+        assert_eq!(
+            AVP::try_read_greedy(&mut r),
+            vec![Ok(AVP::MessageType(message_type))]
+        );
+    }
+    assert_ne!(MessageType::from_code(5), MessageType::from_code(13));
+}
+
+#[test]
+fn unrecognized_mandatory_vendor() {
+    let data = [
+        0x01, 0x08, // Flags and Length
+        0x00, 0x0b, // Vendor ID
+        0x00, 0x01, // Attribute Type
+        0xde, 0xad, // Value
+        0x00, 0x08, // Flags and Length
+        0x00, 0x00, // Vendor ID
+        0x00, 0x00, // Attribute Type (Message Type)
+        0x00, 0x06, // Type 6 (Hello)
+    ];
+    let mut r = SliceReader::from(&data);
+    assert_eq!(
+        AVP::try_read_greedy(&mut r),
+        vec![
+            Err(DecodeError::UnrecognizedMandatoryVendorAVP(11, 1)),
+            Ok(AVP::MessageType(types::MessageType::Hello))
+        ]
+    );
+}
+
+#[test]
+fn connect_speed_update_length() {
+    let input = AVP::ConnectSpeedUpdate(types::ConnectSpeedUpdate {
+        remote_session_id: 1,
+        tx_connect_speed: 2,
+        rx_connect_speed: u32::MAX as u64 + 1,
+    });
+    assert_eq!(input.get_length(), 20);
+
+    let data = [
+        0x00, 0x14, // Flags and Length
+        0x00, 0x00, // Vendor ID
+        0x00, 0x61, // Attribute Type (Connect Speed Update)
+        0x00, 0x00, // Reserved
+        0x00, 0x01, // Remote Session ID
+        0x00, 0x00, 0x00, 0x02, // Tx Connect Speed
+        0x00, 0x00, 0x00, 0x03, // Rx Connect Speed
+        0x00, 0x00, // Trailing octets
+    ];
+    let mut r = SliceReader::from(&data);
+    assert_eq!(
+        AVP::try_read_greedy(&mut r),
+        vec![Err(DecodeError::InvalidAVPLength(14))]
+    );
+}
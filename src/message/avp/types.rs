@@ -115,5 +115,68 @@ pub use call_errors::*;
 mod accm;
 pub use accm::*;
 
+mod ppp_disconnect_cause_code;
+pub use ppp_disconnect_cause_code::*;
+
+mod control_connection_ds;
+pub use control_connection_ds::*;
+
+mod session_ds;
+pub use session_ds::*;
+
+mod lcp_want_options;
+pub use lcp_want_options::*;
+
+mod lcp_allow_options;
+pub use lcp_allow_options::*;
+
+mod lns_last_sent_lcp_conf_req;
+pub use lns_last_sent_lcp_conf_req::*;
+
+mod lns_last_received_lcp_conf_req;
+pub use lns_last_received_lcp_conf_req::*;
+
+mod modem_on_hold_capable;
+pub use modem_on_hold_capable::*;
+
+mod modem_on_hold_status;
+pub use modem_on_hold_status::*;
+
+mod connect_speed_update;
+pub use connect_speed_update::*;
+
+mod connect_speed_update_enable;
+pub use connect_speed_update_enable::*;
+
+mod failover_capability;
+pub use failover_capability::*;
+
+mod tunnel_recovery;
+pub use tunnel_recovery::*;
+
+mod suggested_control_sequence;
+pub use suggested_control_sequence::*;
+
+mod failover_session_state;
+pub use failover_session_state::*;
+
+mod multicast_capability;
+pub use multicast_capability::*;
+
+mod new_outgoing_sessions;
+pub use new_outgoing_sessions::*;
+
+mod new_outgoing_sessions_acknowledgement;
+pub use new_outgoing_sessions_acknowledgement::*;
+
+mod withdraw_outgoing_sessions;
+pub use withdraw_outgoing_sessions::*;
+
+mod multicast_packets_priority;
+pub use multicast_packets_priority::*;
+
 mod hidden;
 pub use hidden::*;
+
+mod vendor;
+pub use vendor::*;
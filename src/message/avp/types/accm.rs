@@ -9,7 +9,7 @@ pub struct Accm {
 }
 
 impl Accm {
-    const ATTRIBUTE_TYPE: u16 = 35;
+    pub(crate) const ATTRIBUTE_TYPE: u16 = 35;
     const LENGTH: usize = 10;
 
     #[inline]
@@ -41,6 +41,11 @@ impl Accm {
 }
 
 impl QueryableAVP for Accm {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
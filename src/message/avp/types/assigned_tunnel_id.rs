@@ -36,6 +36,11 @@ impl From<AssignedTunnelId> for u16 {
 }
 
 impl QueryableAVP for AssignedTunnelId {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
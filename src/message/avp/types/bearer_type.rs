@@ -38,6 +38,11 @@ impl BearerType {
 }
 
 impl QueryableAVP for BearerType {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
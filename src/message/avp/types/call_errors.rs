@@ -1,7 +1,7 @@
 use crate::avp::{QueryableAVP, WritableAVP};
 use crate::common::{DecodeError, DecodeResult, Reader, Writer};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct CallErrors {
     pub crc_errors: u32,
     pub framing_errors: u32,
@@ -12,7 +12,7 @@ pub struct CallErrors {
 }
 
 impl CallErrors {
-    const ATTRIBUTE_TYPE: u16 = 34;
+    pub(crate) const ATTRIBUTE_TYPE: u16 = 34;
     const LENGTH: usize = 26;
 
     #[inline]
@@ -43,6 +43,11 @@ impl CallErrors {
 }
 
 impl QueryableAVP for CallErrors {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -36,6 +36,11 @@ impl From<CallSerialNumber> for u32 {
 }
 
 impl QueryableAVP for CallSerialNumber {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -40,6 +40,11 @@ impl From<CallingNumber> for String {
 }
 
 impl QueryableAVP for CallingNumber {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         self.value.len()
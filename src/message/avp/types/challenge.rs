@@ -39,6 +39,11 @@ impl From<Challenge> for Vec<u8> {
 }
 
 impl QueryableAVP for Challenge {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         self.value.len()
@@ -43,6 +43,11 @@ impl From<ChallengeResponse> for [u8; G_CHALLENGE_RESPONSE_LENGTH] {
 }
 
 impl QueryableAVP for ChallengeResponse {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
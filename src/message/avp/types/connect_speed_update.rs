@@ -0,0 +1,99 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// The current connect speeds of a single session, as carried in `ConnectSpeedUpdateNotification` and
+/// `ConnectSpeedUpdateRequest` messages (RFC 5515).
+///
+/// Speeds are encoded as 32-bit values when both of them fit, and as 64-bit values otherwise.
+///
+/// # Data members
+/// * `remote_session_id` - The session identifier assigned by the receiver of the AVP.
+/// * `tx_connect_speed` - The current transmit connect speed in bits per second.
+/// * `rx_connect_speed` - The current receive connect speed in bits per second.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConnectSpeedUpdate {
+    pub remote_session_id: u16,
+    pub tx_connect_speed: u64,
+    pub rx_connect_speed: u64,
+}
+
+impl ConnectSpeedUpdate {
+    const ATTRIBUTE_TYPE: u16 = 97;
+    const LENGTH_32: usize = 12;
+    const LENGTH_64: usize = 20;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        let length = reader.len();
+        if length < Self::LENGTH_32 {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+        if length != Self::LENGTH_32 && length != Self::LENGTH_64 {
+            return Err(DecodeError::InvalidAVPLength(length as u16));
+        }
+
+        // Skip reserved
+        reader.skip_bytes(2);
+
+        let remote_session_id = unsafe { reader.read_u16_be_unchecked() };
+        let (tx_connect_speed, rx_connect_speed) = if length == Self::LENGTH_64 {
+            unsafe {
+                (
+                    reader.read_u64_be_unchecked(),
+                    reader.read_u64_be_unchecked(),
+                )
+            }
+        } else {
+            unsafe {
+                (
+                    reader.read_u32_be_unchecked() as u64,
+                    reader.read_u32_be_unchecked() as u64,
+                )
+            }
+        };
+
+        Ok(Self {
+            remote_session_id,
+            tx_connect_speed,
+            rx_connect_speed,
+        })
+    }
+
+    #[inline]
+    fn is_64_bit(&self) -> bool {
+        self.tx_connect_speed > u32::MAX as u64 || self.rx_connect_speed > u32::MAX as u64
+    }
+}
+
+impl QueryableAVP for ConnectSpeedUpdate {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        if self.is_64_bit() {
+            Self::LENGTH_64
+        } else {
+            Self::LENGTH_32
+        }
+    }
+}
+
+impl WritableAVP for ConnectSpeedUpdate {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(0);
+        writer.write_u16_be(self.remote_session_id);
+        if self.is_64_bit() {
+            writer.write_u64_be(self.tx_connect_speed);
+            writer.write_u64_be(self.rx_connect_speed);
+        } else {
+            writer.write_u32_be(self.tx_connect_speed as u32);
+            writer.write_u32_be(self.rx_connect_speed as u32);
+        }
+    }
+}
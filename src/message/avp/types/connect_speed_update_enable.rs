@@ -0,0 +1,28 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::Writer;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConnectSpeedUpdateEnable {}
+
+impl ConnectSpeedUpdateEnable {
+    const ATTRIBUTE_TYPE: u16 = 98;
+}
+
+impl QueryableAVP for ConnectSpeedUpdateEnable {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        0
+    }
+}
+
+impl WritableAVP for ConnectSpeedUpdateEnable {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+    }
+}
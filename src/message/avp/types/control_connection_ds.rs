@@ -0,0 +1,85 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// A 16-bit PHB identification code as defined by RFC 3140.
+///
+/// PHBs identified by a standard DSCP carry it in the six most significant bits, while the least significant bit is
+/// set for PHBs which aren't identified by a DSCP.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PhbId(pub u16);
+
+impl PhbId {
+    const GROUP_BIT: u16 = 0x0002;
+    const NON_DSCP_BIT: u16 = 0x0001;
+
+    /// # Summary
+    /// Create the `PhbId` of a single PHB identified by a DSCP.
+    #[inline]
+    pub fn from_dscp(dscp: u8) -> Self {
+        Self(((dscp & 0x3f) as u16) << 10)
+    }
+
+    /// # Summary
+    /// Get the DSCP identifying this PHB, if it is identified by one.
+    #[inline]
+    pub fn dscp(&self) -> Option<u8> {
+        if self.0 & Self::NON_DSCP_BIT != 0 {
+            return None;
+        }
+        Some((self.0 >> 10) as u8)
+    }
+
+    /// # Summary
+    /// Indicate whether this identifies a set of PHBs rather than a single PHB.
+    #[inline]
+    pub fn is_group(&self) -> bool {
+        self.0 & Self::GROUP_BIT != 0
+    }
+}
+
+/// # Summary
+/// The PHB requested for the control connection in `StartControlConnectionRequest` and
+/// `StartControlConnectionReply` messages (RFC 3308).
+///
+/// # Data members
+/// * `phb` - The PHB to be applied to control messages.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ControlConnectionDs {
+    pub phb: PhbId,
+}
+
+impl ControlConnectionDs {
+    const ATTRIBUTE_TYPE: u16 = 47;
+    const LENGTH: usize = 2;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        let phb = PhbId(unsafe { reader.read_u16_be_unchecked() });
+        Ok(Self { phb })
+    }
+}
+
+impl QueryableAVP for ControlConnectionDs {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for ControlConnectionDs {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(self.phb.0);
+    }
+}
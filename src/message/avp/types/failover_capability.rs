@@ -0,0 +1,69 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// Announces the failover capabilities of an endpoint in `StartControlConnectionRequest` and
+/// `StartControlConnectionReply` messages (RFC 4951).
+///
+/// # Data members
+/// * `control_channel` - Indicates support for control channel failover.
+/// * `data_channel` - Indicates support for data channel failover, i.e. sessions survive a failover of the peer.
+/// * `recovery_time` - The time in milliseconds the peer should wait for the sender to recover after a failure.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FailoverCapability {
+    pub control_channel: bool,
+    pub data_channel: bool,
+    pub recovery_time: u32,
+}
+
+impl FailoverCapability {
+    const ATTRIBUTE_TYPE: u16 = 76;
+    const LENGTH: usize = 6;
+    const CONTROL_CHANNEL_BIT: u16 = 0x0002;
+    const DATA_CHANNEL_BIT: u16 = 0x0001;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        let flags = unsafe { reader.read_u16_be_unchecked() };
+        let recovery_time = unsafe { reader.read_u32_be_unchecked() };
+
+        Ok(Self {
+            control_channel: flags & Self::CONTROL_CHANNEL_BIT != 0,
+            data_channel: flags & Self::DATA_CHANNEL_BIT != 0,
+            recovery_time,
+        })
+    }
+}
+
+impl QueryableAVP for FailoverCapability {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for FailoverCapability {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        let mut flags = 0;
+        if self.control_channel {
+            flags |= Self::CONTROL_CHANNEL_BIT;
+        }
+        if self.data_channel {
+            flags |= Self::DATA_CHANNEL_BIT;
+        }
+
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(flags);
+        writer.write_u32_be(self.recovery_time);
+    }
+}
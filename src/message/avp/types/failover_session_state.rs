@@ -0,0 +1,60 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// The state of a single session as queried in a `FailoverSessionQuery` or reported in a `FailoverSessionResponse` message
+/// (RFC 4951).
+///
+/// # Data members
+/// * `sender_session_id` - The session identifier assigned by the sender, or zero if the session doesn't exist at the sender.
+/// * `receiver_session_id` - The session identifier assigned by the receiver.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FailoverSessionState {
+    pub sender_session_id: u16,
+    pub receiver_session_id: u16,
+}
+
+impl FailoverSessionState {
+    const ATTRIBUTE_TYPE: u16 = 79;
+    const LENGTH: usize = 6;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        // Skip reserved
+        reader.skip_bytes(2);
+
+        let sender_session_id = unsafe { reader.read_u16_be_unchecked() };
+        let receiver_session_id = unsafe { reader.read_u16_be_unchecked() };
+
+        Ok(Self {
+            sender_session_id,
+            receiver_session_id,
+        })
+    }
+}
+
+impl QueryableAVP for FailoverSessionState {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for FailoverSessionState {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(0);
+        writer.write_u16_be(self.sender_session_id);
+        writer.write_u16_be(self.receiver_session_id);
+    }
+}
@@ -36,6 +36,11 @@ impl From<FirmwareRevision> for u16 {
 }
 
 impl QueryableAVP for FirmwareRevision {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -41,6 +41,11 @@ impl FramingCapabilities {
 }
 
 impl QueryableAVP for FramingCapabilities {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -39,6 +39,11 @@ impl FramingType {
 }
 
 impl QueryableAVP for FramingType {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -8,6 +8,11 @@ pub struct Hidden {
 }
 
 impl QueryableAVP for Hidden {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        self.attribute_type
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         self.value.len()
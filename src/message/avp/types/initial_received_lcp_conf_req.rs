@@ -1,5 +1,6 @@
 use crate::avp::{QueryableAVP, WritableAVP};
-use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+use crate::common::{DecodeError, DecodeResult, Reader, VecWriter, Writer};
+use crate::ppp::LcpOption;
 use core::borrow::Borrow;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -24,6 +25,22 @@ impl InitialReceivedLcpConfReq {
                 .to_owned(),
         })
     }
+
+    /// # Summary
+    /// Attempt to decode the contained LCP Configure-Request options.
+    #[inline]
+    pub fn options(&self) -> DecodeResult<Vec<LcpOption>> {
+        LcpOption::try_read_all(&self.value)
+    }
+
+    /// # Summary
+    /// Create an AVP containing the given LCP Configure-Request options.
+    #[inline]
+    pub fn from_options(options: &[LcpOption]) -> Self {
+        let mut writer = VecWriter::new();
+        LcpOption::write_all(options, &mut writer);
+        Self { value: writer.data }
+    }
 }
 
 impl From<Vec<u8>> for InitialReceivedLcpConfReq {
@@ -39,6 +56,11 @@ impl From<InitialReceivedLcpConfReq> for Vec<u8> {
 }
 
 impl QueryableAVP for InitialReceivedLcpConfReq {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         self.value.len()
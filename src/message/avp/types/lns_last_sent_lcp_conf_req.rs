@@ -0,0 +1,79 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, VecWriter, Writer};
+use crate::ppp::LcpOption;
+use core::borrow::Borrow;
+
+/// # Summary
+/// The last LCP Configure-Request sent by the LNS to the client, for handing LCP state back to the LAC
+/// (RFC 3437).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LnsLastSentLcpConfReq {
+    pub value: Vec<u8>,
+}
+
+impl LnsLastSentLcpConfReq {
+    const ATTRIBUTE_TYPE: u16 = 51;
+
+    #[inline]
+    pub fn try_read<T: Borrow<[u8]>>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.is_empty() {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        Ok(Self {
+            value: reader
+                .bytes(reader.len())
+                .ok_or(DecodeError::AVPReadError(Self::ATTRIBUTE_TYPE))?
+                .borrow()
+                .to_owned(),
+        })
+    }
+
+    /// # Summary
+    /// Attempt to decode the contained LCP Configure-Request options.
+    #[inline]
+    pub fn options(&self) -> DecodeResult<Vec<LcpOption>> {
+        LcpOption::try_read_all(&self.value)
+    }
+
+    /// # Summary
+    /// Create an AVP containing the given LCP Configure-Request options.
+    #[inline]
+    pub fn from_options(options: &[LcpOption]) -> Self {
+        let mut writer = VecWriter::new();
+        LcpOption::write_all(options, &mut writer);
+        Self { value: writer.data }
+    }
+}
+
+impl From<Vec<u8>> for LnsLastSentLcpConfReq {
+    fn from(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+}
+
+impl From<LnsLastSentLcpConfReq> for Vec<u8> {
+    fn from(value: LnsLastSentLcpConfReq) -> Self {
+        value.value
+    }
+}
+
+impl QueryableAVP for LnsLastSentLcpConfReq {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        self.value.len()
+    }
+}
+
+impl WritableAVP for LnsLastSentLcpConfReq {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_bytes(&self.value);
+    }
+}
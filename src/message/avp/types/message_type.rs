@@ -3,7 +3,17 @@ use crate::common::{DecodeError, DecodeResult, Reader, Writer};
 
 use phf::phf_map;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// # Summary
+/// The type of a `ControlMessage`.
+///
+/// Codes 0, 5 and 13 are reserved and decode as `Reserved`, while codes not known to this crate decode as `Unknown`.
+/// Both are only decoded when the M bit of the `MessageType` AVP is clear, in which case the message may be
+/// ignored and acknowledged with a ZLB (RFC 2661 section 4.4.1). An unknown message type with the M bit set
+/// fails to decode with `UnknownMessageType`, and the tunnel must be cleared.
+///
+/// `Reserved` and `Unknown` message types can only be obtained from `from_code`, so each code maps to exactly one
+/// `MessageType` and equal codes compare, hash and order equally.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum MessageType {
     StartControlConnectionRequest,
     StartControlConnectionReply,
@@ -19,10 +29,36 @@ pub enum MessageType {
     CallDisconnectNotify,
     WanErrorNotify,
     SetLinkInfo,
+    ModemStatus,
+    FailoverSessionQuery,
+    FailoverSessionResponse,
+    MulticastSessionRequest,
+    MulticastSessionResponse,
+    MulticastSessionEstablishment,
+    MulticastSessionInformation,
+    MulticastSessionEndNotify,
+    ConnectSpeedUpdateNotification,
+    ConnectSpeedUpdateRequest,
+    Reserved(MessageCode),
+    Unknown(MessageCode),
 }
 
 use MessageType::*;
 
+/// # Summary
+/// The code of a `Reserved` or `Unknown` `MessageType`, see `MessageType::from_code`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MessageCode(u16);
+
+impl MessageCode {
+    /// # Summary
+    /// Get the code as an integer.
+    #[inline]
+    pub const fn get(&self) -> u16 {
+        self.0
+    }
+}
+
 static MESSAGE_CODE_TO_TYPE: phf::Map<u16, MessageType> = phf_map! {
     1u16 => StartControlConnectionRequest,
     2u16 => StartControlConnectionReply,
@@ -38,6 +74,16 @@ static MESSAGE_CODE_TO_TYPE: phf::Map<u16, MessageType> = phf_map! {
     14u16 => CallDisconnectNotify,
     15u16 => WanErrorNotify,
     16u16 => SetLinkInfo,
+    17u16 => ModemStatus,
+    21u16 => FailoverSessionQuery,
+    22u16 => FailoverSessionResponse,
+    23u16 => MulticastSessionRequest,
+    24u16 => MulticastSessionResponse,
+    25u16 => MulticastSessionEstablishment,
+    26u16 => MulticastSessionInformation,
+    27u16 => MulticastSessionEndNotify,
+    28u16 => ConnectSpeedUpdateNotification,
+    29u16 => ConnectSpeedUpdateRequest,
 };
 
 impl MessageType {
@@ -51,14 +97,106 @@ impl MessageType {
         }
         let id = unsafe { reader.read_u16_be_unchecked() };
 
-        match MESSAGE_CODE_TO_TYPE.get(&id) {
-            Some(&t) => Ok(t),
-            None => Err(DecodeError::UnknownMessageType(id)),
+        Ok(Self::from_code(id))
+    }
+
+    /// # Summary
+    /// Get the `MessageType` with the given code.
+    #[inline]
+    pub fn from_code(code: u16) -> Self {
+        match MESSAGE_CODE_TO_TYPE.get(&code) {
+            Some(&t) => t,
+            None if matches!(code, 0 | 5 | 13) => Reserved(MessageCode(code)),
+            None => Unknown(MessageCode(code)),
+        }
+    }
+
+    /// # Summary
+    /// Check whether this is a `MessageType` known to this crate, i.e. neither `Reserved` nor `Unknown`.
+    #[inline]
+    pub const fn is_known(&self) -> bool {
+        !matches!(self, Reserved(_) | Unknown(_))
+    }
+
+    /// # Summary
+    /// Check whether a received message of this type may be safely ignored, apart from acknowledging it.
+    ///
+    /// This holds for `Reserved` and `Unknown` message types, which are only decoded when the M bit is clear.
+    /// Such messages advance the expected Ns of the tunnel but leave the tunnel and session states unchanged.
+    #[inline]
+    pub const fn is_ignorable(&self) -> bool {
+        !self.is_known()
+    }
+
+    /// # Summary
+    /// Get the name of this `MessageType`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            StartControlConnectionRequest => "Start-Control-Connection-Request",
+            StartControlConnectionReply => "Start-Control-Connection-Reply",
+            StartControlConnectionConnected => "Start-Control-Connection-Connected",
+            StopControlConnectionNotification => "Stop-Control-Connection-Notification",
+            Hello => "Hello",
+            OutgoingCallRequest => "Outgoing-Call-Request",
+            OutgoingCallReply => "Outgoing-Call-Reply",
+            OutgoingCallConnected => "Outgoing-Call-Connected",
+            IncomingCallRequest => "Incoming-Call-Request",
+            IncomingCallReply => "Incoming-Call-Reply",
+            IncomingCallConnected => "Incoming-Call-Connected",
+            CallDisconnectNotify => "Call-Disconnect-Notify",
+            WanErrorNotify => "WAN-Error-Notify",
+            SetLinkInfo => "Set-Link-Info",
+            ModemStatus => "Modem-Status",
+            FailoverSessionQuery => "Failover-Session-Query",
+            FailoverSessionResponse => "Failover-Session-Response",
+            MulticastSessionRequest => "Multicast-Session-Request",
+            MulticastSessionResponse => "Multicast-Session-Response",
+            MulticastSessionEstablishment => "Multicast-Session-Establishment",
+            MulticastSessionInformation => "Multicast-Session-Information",
+            MulticastSessionEndNotify => "Multicast-Session-End-Notify",
+            ConnectSpeedUpdateNotification => "Connect-Speed-Update-Notification",
+            ConnectSpeedUpdateRequest => "Connect-Speed-Update-Request",
+            Reserved(_) => "Reserved",
+            Unknown(_) => "Unknown",
         }
     }
 
+    /// # Summary
+    /// Get the abbreviation of this `MessageType` used in its defining RFC, or `None` if it is not a known one.
+    pub const fn abbreviation(&self) -> Option<&'static str> {
+        Some(match self {
+            StartControlConnectionRequest => "SCCRQ",
+            StartControlConnectionReply => "SCCRP",
+            StartControlConnectionConnected => "SCCCN",
+            StopControlConnectionNotification => "StopCCN",
+            Hello => "HELLO",
+            OutgoingCallRequest => "OCRQ",
+            OutgoingCallReply => "OCRP",
+            OutgoingCallConnected => "OCCN",
+            IncomingCallRequest => "ICRQ",
+            IncomingCallReply => "ICRP",
+            IncomingCallConnected => "ICCN",
+            CallDisconnectNotify => "CDN",
+            WanErrorNotify => "WEN",
+            SetLinkInfo => "SLI",
+            ModemStatus => "MDMST",
+            FailoverSessionQuery => "FSQ",
+            FailoverSessionResponse => "FSR",
+            MulticastSessionRequest => "MSRQ",
+            MulticastSessionResponse => "MSRP",
+            MulticastSessionEstablishment => "MSE",
+            MulticastSessionInformation => "MSI",
+            MulticastSessionEndNotify => "MSEN",
+            ConnectSpeedUpdateNotification => "CSUN",
+            ConnectSpeedUpdateRequest => "CSURQ",
+            Reserved(_) | Unknown(_) => return None,
+        })
+    }
+
+    /// # Summary
+    /// Get the code of this `MessageType`.
     #[inline]
-    const fn get_code(&self) -> u16 {
+    pub const fn get_code(&self) -> u16 {
         match self {
             StartControlConnectionRequest => 1u16,
             StartControlConnectionReply => 2u16,
@@ -74,15 +212,36 @@ impl MessageType {
             CallDisconnectNotify => 14u16,
             WanErrorNotify => 15u16,
             SetLinkInfo => 16u16,
+            ModemStatus => 17u16,
+            FailoverSessionQuery => 21u16,
+            FailoverSessionResponse => 22u16,
+            MulticastSessionRequest => 23u16,
+            MulticastSessionResponse => 24u16,
+            MulticastSessionEstablishment => 25u16,
+            MulticastSessionInformation => 26u16,
+            MulticastSessionEndNotify => 27u16,
+            ConnectSpeedUpdateNotification => 28u16,
+            ConnectSpeedUpdateRequest => 29u16,
+            Reserved(code) | Unknown(code) => code.get(),
         }
     }
 }
 
 impl QueryableAVP for MessageType {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
     }
+
+    #[inline]
+    fn is_mandatory(&self) -> bool {
+        self.is_known()
+    }
 }
 
 impl WritableAVP for MessageType {
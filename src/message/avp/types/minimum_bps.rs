@@ -36,6 +36,11 @@ impl From<MinimumBps> for u32 {
 }
 
 impl QueryableAVP for MinimumBps {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -0,0 +1,47 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// Announces support for modem-on-hold in call establishment requests and replies (RFC 3573).
+///
+/// # Data members
+/// * `timeout` - The maximum time in seconds the sender allows a call to be on hold.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModemOnHoldCapable {
+    pub timeout: u16,
+}
+
+impl ModemOnHoldCapable {
+    const ATTRIBUTE_TYPE: u16 = 53;
+    const LENGTH: usize = 2;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        let timeout = unsafe { reader.read_u16_be_unchecked() };
+        Ok(Self { timeout })
+    }
+}
+
+impl QueryableAVP for ModemOnHoldCapable {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for ModemOnHoldCapable {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(self.timeout);
+    }
+}
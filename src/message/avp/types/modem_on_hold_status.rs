@@ -0,0 +1,50 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// Signals that the modem of a call was put on hold or resumed in a `ModemStatus` message (RFC 3573).
+///
+/// # Data members
+/// * `on_hold` - Indicates whether the call is on hold.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModemOnHoldStatus {
+    pub on_hold: bool,
+}
+
+impl ModemOnHoldStatus {
+    pub(crate) const ATTRIBUTE_TYPE: u16 = 54;
+    const LENGTH: usize = 1;
+    const HOLD_BIT: u8 = 0x01;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        let status = unsafe { reader.read_u8_unchecked() };
+        Ok(Self {
+            on_hold: status & Self::HOLD_BIT != 0,
+        })
+    }
+}
+
+impl QueryableAVP for ModemOnHoldStatus {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for ModemOnHoldStatus {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u8(if self.on_hold { Self::HOLD_BIT } else { 0 });
+    }
+}
@@ -0,0 +1,48 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// Announces support for multicast sessions in `StartControlConnectionRequest` and `StartControlConnectionReply`
+/// messages (RFC 4045).
+///
+/// # Data members
+/// * `version` - The version of the multicast extension supported by the sender.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MulticastCapability {
+    pub version: u8,
+}
+
+impl MulticastCapability {
+    const ATTRIBUTE_TYPE: u16 = 80;
+    const LENGTH: usize = 1;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        let version = unsafe { reader.read_u8_unchecked() };
+        Ok(Self { version })
+    }
+}
+
+impl QueryableAVP for MulticastCapability {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for MulticastCapability {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u8(self.version);
+    }
+}
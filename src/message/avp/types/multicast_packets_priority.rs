@@ -0,0 +1,80 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+use std::net::Ipv4Addr;
+
+/// # Summary
+/// The priority of the packets of a multicast flow relative to the unicast traffic of the outgoing sessions.
+///
+/// # Data members
+/// * `priority` - The priority of the flow, where higher values take precedence.
+/// * `source_address` - The source address of the flow.
+/// * `group_address` - The multicast group address of the flow.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MulticastFlowPriority {
+    pub priority: u8,
+    pub source_address: Ipv4Addr,
+    pub group_address: Ipv4Addr,
+}
+
+/// # Summary
+/// The priorities of multicast flows carried by a multicast session (RFC 4045).
+///
+/// # Data members
+/// * `flows` - The multicast flows and their priorities.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MulticastPacketsPriority {
+    pub flows: Vec<MulticastFlowPriority>,
+}
+
+impl MulticastPacketsPriority {
+    const ATTRIBUTE_TYPE: u16 = 84;
+    const FLOW_LENGTH: usize = 10;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() % Self::FLOW_LENGTH != 0 {
+            return Err(DecodeError::InvalidAVPLength(reader.len() as u16));
+        }
+
+        let flows = (0..reader.len() / Self::FLOW_LENGTH)
+            .map(|_| {
+                // Skip reserved
+                reader.skip_bytes(1);
+                let priority = unsafe { reader.read_u8_unchecked() };
+                let source_address = unsafe { reader.read_u32_be_unchecked() }.into();
+                let group_address = unsafe { reader.read_u32_be_unchecked() }.into();
+                MulticastFlowPriority {
+                    priority,
+                    source_address,
+                    group_address,
+                }
+            })
+            .collect();
+        Ok(Self { flows })
+    }
+}
+
+impl QueryableAVP for MulticastPacketsPriority {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        self.flows.len() * Self::FLOW_LENGTH
+    }
+}
+
+impl WritableAVP for MulticastPacketsPriority {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        for flow in self.flows.iter() {
+            writer.write_u8(0);
+            writer.write_u8(flow.priority);
+            writer.write_u32_be(flow.source_address.into());
+            writer.write_u32_be(flow.group_address.into());
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// Acknowledges the unicast sessions added to the outgoing sessions of a multicast session (RFC 4045).
+///
+/// # Data members
+/// * `session_ids` - The identifiers assigned by the receiver to the sessions which were added.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewOutgoingSessionsAcknowledgement {
+    pub session_ids: Vec<u16>,
+}
+
+impl NewOutgoingSessionsAcknowledgement {
+    const ATTRIBUTE_TYPE: u16 = 82;
+    const SESSION_ID_LENGTH: usize = 2;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() % Self::SESSION_ID_LENGTH != 0 {
+            return Err(DecodeError::InvalidAVPLength(reader.len() as u16));
+        }
+
+        let session_ids = (0..reader.len() / Self::SESSION_ID_LENGTH)
+            .map(|_| unsafe { reader.read_u16_be_unchecked() })
+            .collect();
+        Ok(Self { session_ids })
+    }
+}
+
+impl QueryableAVP for NewOutgoingSessionsAcknowledgement {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        self.session_ids.len() * Self::SESSION_ID_LENGTH
+    }
+}
+
+impl WritableAVP for NewOutgoingSessionsAcknowledgement {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        self.session_ids
+            .iter()
+            .for_each(|id| writer.write_u16_be(*id));
+    }
+}
@@ -45,6 +45,11 @@ impl From<PhysicalChannelId> for [u8; G_PHYSICAL_CHANNEL_ID_LENGTH] {
 }
 
 impl QueryableAVP for PhysicalChannelId {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -0,0 +1,131 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, ResultStr, Writer};
+use core::borrow::Borrow;
+use num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
+
+/// # Summary
+/// The global disconnect codes of RFC 3145, which are shared by all PPP control protocols.
+///
+/// Codes missing from this table, such as those specific to a control protocol, are preserved as `Other`.
+#[derive(Clone, Copy, Debug, IntoPrimitive, FromPrimitive, Eq, PartialEq)]
+#[repr(u16)]
+pub enum PppDisconnectCode {
+    NoInformationAvailable = 0,
+    AdministrativeDisconnect = 1,
+    LcpRenegotiationDisabled = 2,
+    NormalDisconnection = 3,
+    CompulsoryEncryptionRefused = 4,
+    FsmTimeout = 5,
+    NoRecognizableLcpPackets = 6,
+    MagicNumberError = 7,
+    LcpEchoRequestTimeout = 8,
+    #[num_enum(catch_all)]
+    Other(u16),
+}
+
+/// # Summary
+/// The direction of a PPP disconnect cause relative to the sender of the AVP.
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive, Eq, PartialEq)]
+#[repr(u8)]
+pub enum PppDisconnectDirection {
+    Global,
+    AtPeer,
+    AtLocal,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PppDisconnectCauseCode {
+    pub disconnect_code: u16,
+    pub control_protocol_number: u16,
+    pub direction: u8,
+    pub message: Option<String>,
+}
+
+impl PppDisconnectCauseCode {
+    const ATTRIBUTE_TYPE: u16 = 46;
+    const FIXED_LENGTH: usize = 5;
+
+    #[inline]
+    pub fn try_read<T: Borrow<[u8]>>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::FIXED_LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        let disconnect_code = unsafe { reader.read_u16_be_unchecked() };
+        let control_protocol_number = unsafe { reader.read_u16_be_unchecked() };
+        let direction = unsafe { reader.read_u8_unchecked() };
+
+        let message = if !reader.is_empty() {
+            let data = reader
+                .bytes(reader.len())
+                .ok_or(DecodeError::AVPReadError(Self::ATTRIBUTE_TYPE))?;
+            Some(
+                std::str::from_utf8(data.borrow())
+                    .map_err(|_| DecodeError::InvalidUtf8(Self::ATTRIBUTE_TYPE))?
+                    .to_owned(),
+            )
+        } else {
+            None
+        };
+
+        Ok(Self {
+            disconnect_code,
+            control_protocol_number,
+            direction,
+            message,
+        })
+    }
+
+    /// # Summary
+    /// Get the disconnect code as one of the codes shared by all PPP control protocols.
+    ///
+    /// Other codes yield `PppDisconnectCode::Other`, left for the caller to interpret together with
+    /// `control_protocol_number`.
+    #[inline]
+    pub fn as_disconnect_code(&self) -> PppDisconnectCode {
+        self.disconnect_code.into()
+    }
+
+    /// # Summary
+    /// Get the direction of the disconnect cause.
+    #[inline]
+    pub fn as_direction(&self) -> ResultStr<PppDisconnectDirection> {
+        let maybe_direction = self.direction.try_into();
+        maybe_direction.map_err(|_| "Invalid PppDisconnectDirection")
+    }
+}
+
+impl QueryableAVP for PppDisconnectCauseCode {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        if let Some(value) = &self.message {
+            Self::FIXED_LENGTH + value.len()
+        } else {
+            Self::FIXED_LENGTH
+        }
+    }
+
+    /// RFC 3145 requires the M bit to be cleared, since peers unaware of the AVP can safely ignore it.
+    #[inline]
+    fn is_mandatory(&self) -> bool {
+        false
+    }
+}
+
+impl WritableAVP for PppDisconnectCauseCode {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(self.disconnect_code);
+        writer.write_u16_be(self.control_protocol_number);
+        writer.write_u8(self.direction);
+        if let Some(value) = &self.message {
+            writer.write_bytes(value.as_bytes());
+        }
+    }
+}
@@ -24,6 +24,11 @@ impl ProtocolVersion {
 }
 
 impl QueryableAVP for ProtocolVersion {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
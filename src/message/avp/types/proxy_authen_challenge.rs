@@ -39,6 +39,11 @@ impl From<ProxyAuthenChallenge> for Vec<u8> {
 }
 
 impl QueryableAVP for ProxyAuthenChallenge {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         self.value.len()
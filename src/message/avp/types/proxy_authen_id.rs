@@ -25,6 +25,11 @@ impl ProxyAuthenId {
 }
 
 impl QueryableAVP for ProxyAuthenId {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
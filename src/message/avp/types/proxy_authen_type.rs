@@ -34,6 +34,11 @@ impl ProxyAuthenType {
 }
 
 impl QueryableAVP for ProxyAuthenType {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
@@ -44,6 +44,11 @@ impl Q931CauseCode {
 }
 
 impl QueryableAVP for Q931CauseCode {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         if let Some(value) = &self.advisory {
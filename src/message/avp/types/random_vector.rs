@@ -43,6 +43,11 @@ impl From<RandomVector> for [u8; G_RANDOM_VECTOR_LENGTH] {
 }
 
 impl QueryableAVP for RandomVector {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
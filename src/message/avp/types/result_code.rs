@@ -15,7 +15,7 @@ pub struct ResultCode {
 }
 
 impl ResultCode {
-    const ATTRIBUTE_TYPE: u16 = 1;
+    pub(crate) const ATTRIBUTE_TYPE: u16 = 1;
     const FIXED_LENGTH: usize = 2;
     const ERROR_LENGTH: usize = 2;
 
@@ -39,6 +39,11 @@ impl ResultCode {
 }
 
 impl QueryableAVP for ResultCode {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         let mut length = Self::FIXED_LENGTH;
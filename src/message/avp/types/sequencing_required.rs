@@ -9,6 +9,11 @@ impl SequencingRequired {
 }
 
 impl QueryableAVP for SequencingRequired {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         0
@@ -0,0 +1,54 @@
+use crate::avp::types::PhbId;
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// The PHBs requested for the data messages of a session in call establishment requests and accepted in the
+/// replies (RFC 3308).
+///
+/// # Data members
+/// * `phbs` - The PHBs to be applied to data messages, in order of preference.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionDs {
+    pub phbs: Vec<PhbId>,
+}
+
+impl SessionDs {
+    const ATTRIBUTE_TYPE: u16 = 48;
+    const PHB_LENGTH: usize = 2;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.is_empty() {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+        if reader.len() % Self::PHB_LENGTH != 0 {
+            return Err(DecodeError::InvalidAVPLength(reader.len() as u16));
+        }
+
+        let phbs = (0..reader.len() / Self::PHB_LENGTH)
+            .map(|_| PhbId(unsafe { reader.read_u16_be_unchecked() }))
+            .collect();
+        Ok(Self { phbs })
+    }
+}
+
+impl QueryableAVP for SessionDs {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        self.phbs.len() * Self::PHB_LENGTH
+    }
+}
+
+impl WritableAVP for SessionDs {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        self.phbs.iter().for_each(|phb| writer.write_u16_be(phb.0));
+    }
+}
@@ -40,6 +40,11 @@ impl From<SubAddress> for String {
 }
 
 impl QueryableAVP for SubAddress {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         self.value.len()
@@ -0,0 +1,59 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// The control channel sequence numbers the recovering endpoint should continue the recovered tunnel with (RFC 4951).
+///
+/// # Data members
+/// * `suggested_ns` - The Ns of the next control message to be sent by the recovering endpoint.
+/// * `suggested_nr` - The Nr of the next control message to be sent by the recovering endpoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SuggestedControlSequence {
+    pub suggested_ns: u16,
+    pub suggested_nr: u16,
+}
+
+impl SuggestedControlSequence {
+    const ATTRIBUTE_TYPE: u16 = 78;
+    const LENGTH: usize = 6;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        // Skip reserved
+        reader.skip_bytes(2);
+
+        let suggested_ns = unsafe { reader.read_u16_be_unchecked() };
+        let suggested_nr = unsafe { reader.read_u16_be_unchecked() };
+
+        Ok(Self {
+            suggested_ns,
+            suggested_nr,
+        })
+    }
+}
+
+impl QueryableAVP for SuggestedControlSequence {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for SuggestedControlSequence {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(0);
+        writer.write_u16_be(self.suggested_ns);
+        writer.write_u16_be(self.suggested_nr);
+    }
+}
@@ -36,6 +36,11 @@ impl From<TieBreaker> for u64 {
 }
 
 impl QueryableAVP for TieBreaker {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         Self::LENGTH
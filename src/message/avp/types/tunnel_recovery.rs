@@ -0,0 +1,59 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// Identifies the tunnel being recovered in the `StartControlConnectionRequest` of a recovery tunnel (RFC 4951).
+///
+/// # Data members
+/// * `recover_tunnel_id` - The tunnel identifier assigned to the tunnel being recovered by the sender.
+/// * `recover_remote_tunnel_id` - The tunnel identifier assigned to the tunnel being recovered by the receiver.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TunnelRecovery {
+    pub recover_tunnel_id: u16,
+    pub recover_remote_tunnel_id: u16,
+}
+
+impl TunnelRecovery {
+    const ATTRIBUTE_TYPE: u16 = 77;
+    const LENGTH: usize = 6;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() < Self::LENGTH {
+            return Err(DecodeError::IncompleteAVP(Self::ATTRIBUTE_TYPE));
+        }
+
+        // Skip reserved
+        reader.skip_bytes(2);
+
+        let recover_tunnel_id = unsafe { reader.read_u16_be_unchecked() };
+        let recover_remote_tunnel_id = unsafe { reader.read_u16_be_unchecked() };
+
+        Ok(Self {
+            recover_tunnel_id,
+            recover_remote_tunnel_id,
+        })
+    }
+}
+
+impl QueryableAVP for TunnelRecovery {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl WritableAVP for TunnelRecovery {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        writer.write_u16_be(0);
+        writer.write_u16_be(self.recover_tunnel_id);
+        writer.write_u16_be(self.recover_remote_tunnel_id);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::avp::{QueryableAVP, WritableAVP, AVP};
+use crate::common::{DecodeError, DecodeResult, VecWriter, Writer};
+
+/// # Summary
+/// A vendor-specific AVP, i.e. one with a nonzero vendor ID, carried undecoded.
+///
+/// Dictionaries implementing `VendorSpecificAVP`, such as `avp::cisco`, decode the values of well-known vendor AVPs.
+///
+/// # Data members
+/// * `vendor_id` - The SMI network management private enterprise code of the vendor.
+/// * `attribute_type` - The attribute type, as assigned by the vendor.
+/// * `is_mandatory` - Whether the M bit is set.
+/// * `is_hidden` - Whether the H bit is set, in which case `value` is hidden.
+/// * `value` - The attribute value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Vendor {
+    pub vendor_id: u16,
+    pub attribute_type: u16,
+    pub is_mandatory: bool,
+    pub is_hidden: bool,
+    pub value: Vec<u8>,
+}
+
+impl QueryableAVP for Vendor {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        self.attribute_type
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        self.value.len()
+    }
+
+    #[inline]
+    fn is_mandatory(&self) -> bool {
+        self.is_mandatory
+    }
+}
+
+impl WritableAVP for Vendor {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(self.attribute_type);
+        writer.write_bytes(&self.value);
+    }
+}
+
+/// # Summary
+/// A typed vendor-specific AVP from a vendor dictionary such as `avp::cisco`, encoded as a `Vendor` AVP.
+///
+/// Implementors provide the value encoding, while the vendor ID, H bit and `AVP` wrapping are shared.
+pub trait VendorSpecificAVP: Sized {
+    /// The vendor ID of the AVPs of this dictionary.
+    const VENDOR_ID: u16;
+
+    /// Try to decode the value of the AVP with the given attribute type.
+    fn try_read_value(attribute_type: u16, value: &[u8]) -> DecodeResult<Self>;
+
+    /// Write the value of this AVP using a `Writer`.
+    fn write_value(&self, writer: &mut impl Writer);
+
+    /// # Summary
+    /// Get the attribute type of this AVP.
+    fn get_attribute_type(&self) -> u16;
+
+    /// # Summary
+    /// Try to decode a `Vendor` AVP.
+    ///
+    /// Hidden AVPs can't be decoded, since revealing them is only specified for AVPs of the L2TP specification.
+    fn try_from_vendor(vendor: &Vendor) -> DecodeResult<Self> {
+        if vendor.vendor_id != Self::VENDOR_ID {
+            return Err(DecodeError::UnsupportedVendorId(vendor.vendor_id));
+        }
+        if vendor.is_hidden {
+            return Err(DecodeError::HiddenVendorAVP(
+                vendor.vendor_id,
+                vendor.attribute_type,
+            ));
+        }
+
+        Self::try_read_value(vendor.attribute_type, &vendor.value)
+    }
+
+    /// # Summary
+    /// Try to decode an `AVP`, failing with `NotVendorAVP` for AVPs of the L2TP specification.
+    fn try_from_avp(avp: &AVP) -> DecodeResult<Self> {
+        match avp {
+            AVP::Vendor(vendor) => Self::try_from_vendor(vendor),
+            avp => Err(DecodeError::NotVendorAVP(avp.get_attribute_type())),
+        }
+    }
+
+    /// # Summary
+    /// Encode this AVP as an `AVP`.
+    ///
+    /// # Parameters
+    /// * `is_mandatory` - Whether to set the M bit.
+    fn to_avp(&self, is_mandatory: bool) -> AVP {
+        let mut writer = VecWriter::new();
+        self.write_value(&mut writer);
+
+        AVP::Vendor(Vendor {
+            vendor_id: Self::VENDOR_ID,
+            attribute_type: self.get_attribute_type(),
+            is_mandatory,
+            is_hidden: false,
+            value: writer.data,
+        })
+    }
+}
+
+/// # Summary
+/// Get a fixed length vendor-specific AVP value as an array, for use in `VendorSpecificAVP::try_read_value`.
+pub fn fixed_value<const N: usize>(value: &[u8]) -> DecodeResult<[u8; N]> {
+    value
+        .try_into()
+        .map_err(|_| DecodeError::InvalidAVPLength(value.len() as u16))
+}
@@ -40,6 +40,11 @@ impl From<VendorName> for String {
 }
 
 impl QueryableAVP for VendorName {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
     #[inline]
     fn get_length(&self) -> usize {
         self.value.len()
@@ -0,0 +1,52 @@
+use crate::avp::{QueryableAVP, WritableAVP};
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+
+/// # Summary
+/// Removes unicast sessions from the outgoing sessions a multicast session is replicated to, in a
+/// `MulticastSessionInformation` message (RFC 4045).
+///
+/// # Data members
+/// * `session_ids` - The identifiers assigned by the receiver to the sessions to be removed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawOutgoingSessions {
+    pub session_ids: Vec<u16>,
+}
+
+impl WithdrawOutgoingSessions {
+    const ATTRIBUTE_TYPE: u16 = 83;
+    const SESSION_ID_LENGTH: usize = 2;
+
+    #[inline]
+    pub fn try_read<T>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        if reader.len() % Self::SESSION_ID_LENGTH != 0 {
+            return Err(DecodeError::InvalidAVPLength(reader.len() as u16));
+        }
+
+        let session_ids = (0..reader.len() / Self::SESSION_ID_LENGTH)
+            .map(|_| unsafe { reader.read_u16_be_unchecked() })
+            .collect();
+        Ok(Self { session_ids })
+    }
+}
+
+impl QueryableAVP for WithdrawOutgoingSessions {
+    #[inline]
+    fn get_attribute_type(&self) -> u16 {
+        Self::ATTRIBUTE_TYPE
+    }
+
+    #[inline]
+    fn get_length(&self) -> usize {
+        self.session_ids.len() * Self::SESSION_ID_LENGTH
+    }
+}
+
+impl WritableAVP for WithdrawOutgoingSessions {
+    #[inline]
+    fn write(&self, writer: &mut impl Writer) {
+        writer.write_u16_be(Self::ATTRIBUTE_TYPE);
+        self.session_ids
+            .iter()
+            .for_each(|id| writer.write_u16_be(*id));
+    }
+}
@@ -1,8 +1,12 @@
-use crate::common::{DecodeError, Reader, Writer};
+mod builder;
+pub use builder::ControlMessageBuilder;
+
+use crate::common::{DecodeError, EncodeError, EncodeResult, Reader, Writer};
 use crate::message::flags::{Flags, MessageFlagType};
 use crate::message::*;
-use avp::AVP;
+use avp::{types, AVP};
 use core::borrow::Borrow;
+use rand_core::RngCore;
 
 /// # Summary
 /// A `ControlMessage` is a representation of an L2TP control message which is the primary link control mechanism of the protocol.
@@ -25,6 +29,102 @@ pub struct ControlMessage {
 }
 
 impl ControlMessage {
+    /// # Summary
+    /// Create a `ControlMessageBuilder` for a message of the given `MessageType`.
+    #[inline]
+    pub fn builder<'a>(message_type: avp::types::MessageType) -> ControlMessageBuilder<'a> {
+        ControlMessageBuilder::new(message_type)
+    }
+
+    /// # Summary
+    /// Get the `MessageType` of this message, if its first AVP is a `MessageType` AVP.
+    #[inline]
+    pub fn message_type(&self) -> Option<avp::types::MessageType> {
+        match self.avps.first() {
+            Some(AVP::MessageType(message_type)) => Some(*message_type),
+            _ => None,
+        }
+    }
+
+    /// # Summary
+    /// Reveal every `Hidden` AVP of this message using the most recent preceding `RandomVector` AVP.
+    ///
+    /// AVPs which can't be revealed are left hidden and the corresponding errors are returned.
+    ///
+    /// # Parameters
+    /// * `secret` - A shared secret.
+    pub fn reveal_all(&mut self, secret: &[u8]) -> Result<(), Vec<DecodeError>> {
+        let mut errors = Vec::new();
+        let mut random_vector = None;
+
+        for avp in self.avps.iter_mut() {
+            match avp {
+                AVP::RandomVector(rv) => random_vector = Some(*rv),
+                AVP::Hidden(hidden) => match &random_vector {
+                    Some(rv) => match avp.clone().reveal(secret, rv) {
+                        Ok(revealed) => *avp = revealed,
+                        Err(e) => {
+                            debug!(
+                                tunnel_id = self.tunnel_id,
+                                session_id = self.session_id,
+                                error = %e,
+                                "Failed to reveal hidden AVP"
+                            );
+                            errors.push(e)
+                        }
+                    },
+                    None => errors.push(DecodeError::HiddenAVPWithoutRandomVector(
+                        hidden.attribute_type,
+                    )),
+                },
+                _ => (),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// # Summary
+    /// Hide every AVP of this message for which `predicate` returns `true`.
+    ///
+    /// The selected AVPs are moved to the end of the message and preceded by a freshly generated `RandomVector` AVP,
+    /// which leaves the `RandomVector`s of previously hidden AVPs intact. `MessageType`, `RandomVector` and already
+    /// hidden AVPs are never selected.
+    ///
+    /// Fails, leaving the message unaltered, if any of the selected AVPs exceeds the maximum AVP length once hidden.
+    ///
+    /// # Parameters
+    /// * `secret` - A shared secret.
+    /// * `rng` - A random number generator used for the `RandomVector` and for length and alignment padding.
+    /// * `predicate` - Selects the AVPs to hide.
+    pub fn hide_selected(
+        &mut self,
+        secret: &[u8],
+        rng: &mut dyn RngCore,
+        mut predicate: impl FnMut(&AVP) -> bool,
+    ) -> EncodeResult<()> {
+        let (selected, kept): (Vec<_>, Vec<_>) = self.avps.iter().cloned().partition(|avp| {
+            !matches!(
+                avp,
+                AVP::MessageType(_) | AVP::RandomVector(_) | AVP::Hidden(_)
+            ) && predicate(avp)
+        });
+        trace!(
+            tunnel_id = self.tunnel_id,
+            session_id = self.session_id,
+            count = selected.len(),
+            "Hiding AVPs"
+        );
+        let hidden = hide_avps(selected, secret, rng)?;
+        self.avps = kept;
+        self.avps.extend(hidden);
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn try_read<T: Borrow<[u8]>>(
         flags: Flags,
@@ -58,6 +158,7 @@ impl ControlMessage {
         let session_id = unsafe { reader.read_u16_be_unchecked() };
         let ns = unsafe { reader.read_u16_be_unchecked() };
         let nr = unsafe { reader.read_u16_be_unchecked() };
+        span!(TRACE, "control_message", tunnel_id, session_id, ns, nr);
 
         const FIXED_LENGTH: usize = 12;
         if length as usize > reader.len() + FIXED_LENGTH {
@@ -69,16 +170,25 @@ impl ControlMessage {
 
         if let Some(first) = avp_and_err.first() {
             match first {
-                Ok(AVP::MessageType(_)) => (),
+                Ok(AVP::MessageType(_)) | Err(DecodeError::UnknownMessageType(_)) => (),
                 _ => return Err(vec![DecodeError::ControlMessageTypeNotFirst]),
             }
         }
 
-        if avp_and_err.iter().any(|x| {
-            println!("{x:?}");
-            x.is_err()
-        }) {
-            return Err(avp_and_err.into_iter().filter_map(|x| x.err()).collect());
+        #[cfg(feature = "tracing")]
+        for x in avp_and_err.iter() {
+            trace!(avp = ?x, "Read AVP");
+        }
+
+        if avp_and_err.iter().any(|x| x.is_err()) {
+            let errors: Vec<_> = avp_and_err.into_iter().filter_map(|x| x.err()).collect();
+            debug!(
+                tunnel_id,
+                session_id,
+                ?errors,
+                "Malformed AVPs in control message"
+            );
+            return Err(errors);
         }
 
         let avps = avp_and_err.into_iter().filter_map(|x| x.ok()).collect();
@@ -93,8 +203,52 @@ impl ControlMessage {
         })
     }
 
+    /// # Summary
+    /// Get the total encoded length of this `ControlMessage`, or an error if it or any of its AVPs is oversize.
+    #[inline]
+    pub(crate) fn get_checked_length(&self) -> EncodeResult<usize> {
+        const FIXED_LENGTH: usize = 12;
+
+        let mut length = FIXED_LENGTH;
+        for avp in self.avps.iter() {
+            length += avp.get_checked_length()?;
+        }
+
+        if length > u16::MAX as usize {
+            return Err(EncodeError::OversizeMessage(length));
+        }
+
+        Ok(length)
+    }
+
+    /// # Summary
+    /// Attempt to write a `ControlMessage` using a mutable `Writer`.
+    ///
+    /// Oversize messages and AVPs are reported without writing anything, whereas `Writer` overflow is reported after
+    /// writing.
+    #[inline]
+    pub fn try_write(&self, writer: &mut impl Writer) -> EncodeResult<()> {
+        self.write_checked(Message::<&[u8]>::PROTOCOL_VERSION, writer)?;
+
+        if writer.has_overflowed() {
+            return Err(EncodeError::WriterCapacityExhausted);
+        }
+
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn write(&self, protocol_version: u8, writer: &mut impl Writer) {
+        if let Err(e) = self.write_checked(protocol_version, writer) {
+            panic!("{e}");
+        }
+    }
+
+    #[inline]
+    fn write_checked(&self, protocol_version: u8, writer: &mut impl Writer) -> EncodeResult<()> {
+        // Note: Nothing may be written before all lengths are checked
+        self.get_checked_length()?;
+
         let start_position = writer.len();
         let flags = Flags::new(
             MessageFlagType::Control,
@@ -120,7 +274,7 @@ impl ControlMessage {
 
         // Write payload
         for avp in self.avps.iter() {
-            avp.write(writer);
+            avp.write_checked(writer)?;
         }
 
         // Get total length
@@ -128,7 +282,45 @@ impl ControlMessage {
         let length = end_position - start_position;
 
         // Overwrite dummy octets
-        assert!(length <= u16::MAX as usize);
         writer.write_bytes_at(&(length as u16).to_be_bytes(), length_position);
+
+        Ok(())
+    }
+}
+
+/// Hide `avps`, returning them preceded by a freshly generated `RandomVector` AVP.
+///
+/// Length padding of a random length shorter than `AVP::CRYPTO_CHUNK_SIZE` is used for every AVP.
+pub(crate) fn hide_avps(
+    avps: Vec<AVP>,
+    secret: &[u8],
+    rng: &mut dyn RngCore,
+) -> EncodeResult<Vec<AVP>> {
+    if avps.is_empty() {
+        return Ok(avps);
     }
+
+    let mut value = [0; 4];
+    rng.fill_bytes(&mut value);
+    let random_vector = types::RandomVector::from(value);
+
+    let mut result = Vec::with_capacity(avps.len() + 1);
+    result.push(AVP::RandomVector(random_vector));
+    for avp in avps.into_iter() {
+        let mut length_padding = [0; AVP::CRYPTO_CHUNK_SIZE];
+        rng.fill_bytes(&mut length_padding);
+        let length_padding_length = rng.next_u32() as usize % AVP::CRYPTO_CHUNK_SIZE;
+
+        let mut alignment_padding = [0; AVP::CRYPTO_CHUNK_SIZE];
+        rng.fill_bytes(&mut alignment_padding);
+
+        result.push(avp.hide(
+            secret,
+            &random_vector,
+            &length_padding[..length_padding_length],
+            &alignment_padding,
+        )?);
+    }
+
+    Ok(result)
 }
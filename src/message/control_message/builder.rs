@@ -0,0 +1,167 @@
+use crate::avp::{types, AVP};
+use crate::common::{BuildError, BuildResult, Writer};
+use crate::message::control_message::hide_avps;
+use crate::message::{ControlMessage, Message};
+use rand_core::RngCore;
+use std::collections::BTreeSet;
+
+/// # Summary
+/// A builder for `ControlMessage`s which takes care of the header fields and AVP ordering mandated by the protocol.
+///
+/// The `MessageType` AVP is always placed first and the length field is computed automatically.
+/// Every AVP except `RandomVector`, `ConnectSpeedUpdate` and `FailoverSessionState` may occur at most once.
+///
+/// # Lifetimes
+/// * `'a` - The lifetime of the borrowed shared secret and random number generator used for hiding AVPs.
+pub struct ControlMessageBuilder<'a> {
+    tunnel_id: u16,
+    session_id: u16,
+    ns: u16,
+    nr: u16,
+    avps: Vec<(AVP, bool)>,
+    hiding: Option<(&'a [u8], &'a mut dyn RngCore)>,
+}
+
+impl<'a> ControlMessageBuilder<'a> {
+    #[inline]
+    pub(crate) fn new(message_type: types::MessageType) -> Self {
+        Self {
+            tunnel_id: 0,
+            session_id: 0,
+            ns: 0,
+            nr: 0,
+            avps: vec![(AVP::MessageType(message_type), false)],
+            hiding: None,
+        }
+    }
+
+    /// # Summary
+    /// Set the tunnel identifier field.
+    #[inline]
+    pub fn tunnel(mut self, tunnel_id: u16) -> Self {
+        self.tunnel_id = tunnel_id;
+        self
+    }
+
+    /// # Summary
+    /// Set the session identifier field.
+    #[inline]
+    pub fn session(mut self, session_id: u16) -> Self {
+        self.session_id = session_id;
+        self
+    }
+
+    /// # Summary
+    /// Set the NS field.
+    #[inline]
+    pub fn ns(mut self, ns: u16) -> Self {
+        self.ns = ns;
+        self
+    }
+
+    /// # Summary
+    /// Set the NR field.
+    #[inline]
+    pub fn nr(mut self, nr: u16) -> Self {
+        self.nr = nr;
+        self
+    }
+
+    /// # Summary
+    /// Append an `AVP` to the message.
+    #[inline]
+    pub fn avp(mut self, avp: AVP) -> Self {
+        self.avps.push((avp, false));
+        self
+    }
+
+    /// # Summary
+    /// Append an `AVP` to the message, to be hidden using the secret supplied with `hide_with`.
+    #[inline]
+    pub fn hidden_avp(mut self, avp: AVP) -> Self {
+        self.avps.push((avp, true));
+        self
+    }
+
+    /// # Summary
+    /// Supply the shared secret and random number generator used to hide AVPs added with `hidden_avp`.
+    #[inline]
+    pub fn hide_with(mut self, secret: &'a [u8], rng: &'a mut dyn RngCore) -> Self {
+        self.hiding = Some((secret, rng));
+        self
+    }
+
+    fn validate(&self) -> BuildResult<()> {
+        let mut seen = BTreeSet::new();
+        for (avp, hide) in self.avps.iter() {
+            let attribute_type = avp.get_attribute_type();
+            // Vendor-specific attribute types are only unique within their vendor
+            let vendor_id = match avp {
+                AVP::Vendor(vendor) => vendor.vendor_id,
+                _ => 0,
+            };
+
+            if *hide
+                && matches!(
+                    avp,
+                    AVP::MessageType(_) | AVP::RandomVector(_) | AVP::Vendor(_)
+                )
+            {
+                return Err(BuildError::UnhideableAVP(attribute_type));
+            }
+
+            if !matches!(
+                avp,
+                AVP::RandomVector(_) | AVP::ConnectSpeedUpdate(_) | AVP::FailoverSessionState(_)
+            ) && !seen.insert((vendor_id, attribute_type))
+            {
+                return Err(BuildError::DuplicateAVP(attribute_type));
+            }
+        }
+
+        if self.hiding.is_none() && self.avps.iter().any(|(_, hide)| *hide) {
+            return Err(BuildError::MissingSecret);
+        }
+
+        Ok(())
+    }
+
+    /// # Summary
+    /// Build the `ControlMessage`.
+    ///
+    /// Fails if the message or any of its AVPs, hidden or not, exceeds the maximum encodable length.
+    ///
+    /// Hidden AVPs are placed last, preceded by a freshly generated `RandomVector` AVP.
+    pub fn build(self) -> BuildResult<ControlMessage> {
+        self.validate()?;
+
+        let (hidden, plain): (Vec<_>, Vec<_>) = self.avps.into_iter().partition(|(_, hide)| *hide);
+        let mut avps: Vec<AVP> = plain.into_iter().map(|(avp, _)| avp).collect();
+        if let Some((secret, rng)) = self.hiding {
+            avps.extend(hide_avps(
+                hidden.into_iter().map(|(avp, _)| avp).collect(),
+                secret,
+                rng,
+            )?);
+        }
+
+        let mut message = ControlMessage {
+            length: 0,
+            tunnel_id: self.tunnel_id,
+            session_id: self.session_id,
+            ns: self.ns,
+            nr: self.nr,
+            avps,
+        };
+        message.length = message.get_checked_length()? as u16;
+
+        Ok(message)
+    }
+
+    /// # Summary
+    /// Build the `ControlMessage` and write it using a `Writer`.
+    pub fn write(self, writer: &mut impl Writer) -> BuildResult<()> {
+        let message = Message::<Vec<u8>>::Control(self.build()?);
+        Ok(message.try_write(writer)?)
+    }
+}
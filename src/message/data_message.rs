@@ -1,5 +1,9 @@
-use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+use crate::common::{
+    DecodeError, DecodeResult, EncodeError, EncodeResult, Reader, SliceReader, Writer,
+};
 use crate::message::flags::{Flags, MessageFlagType};
+use crate::message::Message;
+use crate::ppp::Frame;
 use core::borrow::Borrow;
 
 /// # Summary
@@ -102,6 +106,55 @@ where
         })
     }
 
+    /// # Summary
+    /// Get the total encoded length of this `DataMessage`, or an error if it is oversize.
+    #[inline]
+    pub(crate) fn get_checked_length(&self) -> EncodeResult<usize> {
+        const FIXED_LENGTH: usize = 6;
+
+        let mut length = FIXED_LENGTH + self.data.borrow().len();
+        if self.length.is_some() {
+            length += 2;
+        }
+        if self.ns_nr.is_some() {
+            length += 4;
+        }
+        if self.offset.is_some() {
+            length += 2;
+        }
+
+        if length > u16::MAX as usize {
+            return Err(EncodeError::OversizeMessage(length));
+        }
+
+        Ok(length)
+    }
+
+    /// # Summary
+    /// Attempt to decode the payload of this `DataMessage` as a PPP `Frame` without copying it.
+    #[inline]
+    pub fn ppp_frame(&self) -> DecodeResult<Frame<&[u8]>> {
+        let mut reader = SliceReader::from(self.data.borrow());
+        Frame::try_read(&mut reader)
+    }
+
+    /// # Summary
+    /// Attempt to write a `DataMessage` using a mutable `Writer`.
+    ///
+    /// Oversize messages are reported without writing anything, whereas `Writer` overflow is reported after
+    /// writing.
+    #[inline]
+    pub fn try_write(&self, writer: &mut impl Writer) -> EncodeResult<()> {
+        self.get_checked_length()?;
+        self.write(Message::<&[u8]>::PROTOCOL_VERSION, writer);
+
+        if writer.has_overflowed() {
+            return Err(EncodeError::WriterCapacityExhausted);
+        }
+
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn write(&self, protocol_version: u8, writer: &mut impl Writer) {
         let flags = Flags::new(
@@ -1,6 +1,9 @@
 use crate::common::SliceReader;
 use crate::message::*;
 
+mod builder;
+mod hidden;
+mod rng;
 mod valid_avp;
 mod write_read;
 
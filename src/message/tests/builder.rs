@@ -0,0 +1,155 @@
+use crate::avp::{types, AVP};
+use crate::common::{BuildError, EncodeError, SliceReader, VecWriter};
+use crate::message::tests::rng::CounterRng;
+use crate::message::*;
+
+fn read_control(data: &[u8]) -> ControlMessage {
+    match Message::try_read(&mut SliceReader::from(data)) {
+        Ok(Message::Control(control)) => control,
+        x => panic!("{x:?}"),
+    }
+}
+
+#[test]
+fn build_write_read() {
+    let mut w = VecWriter::new();
+    ControlMessage::builder(types::MessageType::IncomingCallRequest)
+        .tunnel(1)
+        .session(2)
+        .ns(3)
+        .nr(4)
+        .avp(AVP::AssignedSessionId(0x1337.into()))
+        .avp(AVP::CallSerialNumber(0x1337.into()))
+        .write(&mut w)
+        .unwrap();
+
+    let expected = ControlMessage {
+        length: 12 + 8 + 8 + 10,
+        tunnel_id: 1,
+        session_id: 2,
+        ns: 3,
+        nr: 4,
+        avps: vec![
+            AVP::MessageType(types::MessageType::IncomingCallRequest),
+            AVP::AssignedSessionId(0x1337.into()),
+            AVP::CallSerialNumber(0x1337.into()),
+        ],
+    };
+    assert_eq!(read_control(&w.data), expected);
+}
+
+#[test]
+fn duplicate_avp() {
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .avp(AVP::AssignedSessionId(1.into()))
+        .avp(AVP::AssignedSessionId(2.into()))
+        .build();
+    assert_eq!(result, Err(BuildError::DuplicateAVP(14)));
+
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .avp(AVP::MessageType(types::MessageType::Hello))
+        .build();
+    assert_eq!(result, Err(BuildError::DuplicateAVP(0)));
+
+    let vendor = |vendor_id| {
+        AVP::Vendor(types::Vendor {
+            vendor_id,
+            attribute_type: 14,
+            is_mandatory: false,
+            is_hidden: false,
+            value: vec![0x00, 0x01],
+        })
+    };
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .avp(AVP::AssignedSessionId(1.into()))
+        .avp(vendor(9))
+        .avp(vendor(3561))
+        .build();
+    assert!(result.is_ok());
+
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .avp(vendor(9))
+        .avp(vendor(9))
+        .build();
+    assert_eq!(result, Err(BuildError::DuplicateAVP(14)));
+}
+
+#[test]
+fn oversize_avp() {
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .avp(AVP::HostName(vec![0x61; 1018].into()))
+        .build();
+    assert_eq!(
+        result,
+        Err(BuildError::Encode(EncodeError::OversizeAVP(7, 1024)))
+    );
+}
+
+#[test]
+fn oversize_hidden_avp() {
+    let mut rng = CounterRng { state: 0x1337 };
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .hidden_avp(AVP::HostName(vec![0x61; 2000].into()))
+        .hide_with(b"secret", &mut rng)
+        .build();
+    assert_eq!(
+        result,
+        Err(BuildError::Encode(EncodeError::OversizeAVP(7, 2006)))
+    );
+
+    // Fits when plain, but not once padded to the hiding chunk size
+    let mut rng = CounterRng { state: 0x1337 };
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .hidden_avp(AVP::HostName(vec![0x61; 1010].into()))
+        .hide_with(b"secret", &mut rng)
+        .build();
+    assert!(matches!(
+        result,
+        Err(BuildError::Encode(EncodeError::OversizeAVP(7, _)))
+    ));
+}
+
+#[test]
+fn duplicate_random_vector() {
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .avp(AVP::RandomVector([0x01, 0x02, 0x03, 0x04].into()))
+        .avp(AVP::RandomVector([0x05, 0x06, 0x07, 0x08].into()))
+        .build();
+    assert!(result.is_ok());
+}
+
+#[test]
+fn hidden_without_secret() {
+    let result = ControlMessage::builder(types::MessageType::Hello)
+        .hidden_avp(AVP::VendorName("test vendor".to_owned().into()))
+        .build();
+    assert_eq!(result, Err(BuildError::MissingSecret));
+}
+
+#[test]
+fn hidden_write_read() {
+    let secret = b"my_super_secret";
+    let mut rng = CounterRng { state: 0x1337 };
+    let vendor_name = AVP::VendorName("test vendor".to_owned().into());
+    let host_name = AVP::HostName("test-host.com".as_bytes().to_owned().into());
+
+    let mut w = VecWriter::new();
+    ControlMessage::builder(types::MessageType::StartControlConnectionRequest)
+        .avp(AVP::HostName("test-host.com".as_bytes().to_owned().into()))
+        .hidden_avp(vendor_name.clone())
+        .hide_with(secret, &mut rng)
+        .write(&mut w)
+        .unwrap();
+
+    let control = read_control(&w.data);
+    assert_eq!(control.length as usize, w.data.len());
+    assert_eq!(control.avps.len(), 4);
+    assert_eq!(control.avps[1], host_name);
+
+    let random_vector = match &control.avps[2] {
+        AVP::RandomVector(rv) => *rv,
+        x => panic!("{x:?}"),
+    };
+    let revealed = control.avps[3].clone().reveal(secret, &random_vector);
+    assert_eq!(revealed, Ok(vendor_name));
+}
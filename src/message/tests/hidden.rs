@@ -0,0 +1,122 @@
+use crate::avp::{types, AVP};
+use crate::common::{DecodeError, EncodeError, SliceReader, VecWriter};
+use crate::message::tests::rng::CounterRng;
+use crate::message::*;
+
+fn make_message(avps: Vec<AVP>) -> ControlMessage {
+    ControlMessage {
+        length: 0,
+        tunnel_id: 1,
+        session_id: 2,
+        ns: 3,
+        nr: 4,
+        avps,
+    }
+}
+
+#[test]
+fn hide_selected_reveal_all() {
+    let secret = b"my_super_secret";
+    let mut rng = CounterRng { state: 0x1337 };
+    let plain = vec![
+        AVP::MessageType(types::MessageType::IncomingCallConnected),
+        AVP::TxConnectSpeed(0xdeadbeef.into()),
+        AVP::ProxyAuthenName(vec![0xde, 0xad, 0xbe, 0xef].into()),
+        AVP::ProxyAuthenResponse(vec![0x01, 0x02, 0x03, 0x04].into()),
+    ];
+
+    let mut msg = make_message(plain.clone());
+    msg.hide_selected(secret, &mut rng, |avp| {
+        matches!(
+            avp,
+            AVP::ProxyAuthenName(_) | AVP::ProxyAuthenResponse(_) | AVP::MessageType(_)
+        )
+    })
+    .unwrap();
+
+    // MessageType is never hidden, selected AVPs are moved after a new RandomVector
+    assert_eq!(msg.avps.len(), 5);
+    assert_eq!(msg.avps[0], plain[0]);
+    assert_eq!(msg.avps[1], plain[1]);
+    assert!(matches!(msg.avps[2], AVP::RandomVector(_)));
+    assert!(matches!(msg.avps[3], AVP::Hidden(_)));
+    assert!(matches!(msg.avps[4], AVP::Hidden(_)));
+
+    // Round trip through the wire format
+    let mut w = VecWriter::new();
+    Message::<Vec<u8>>::Control(msg).write(&mut w);
+    let mut msg = match Message::try_read(&mut SliceReader::from(&w.data)) {
+        Ok(Message::Control(control)) => control,
+        x => panic!("{x:?}"),
+    };
+
+    assert_eq!(msg.reveal_all(secret), Ok(()));
+    assert_eq!(msg.avps[0], plain[0]);
+    assert_eq!(msg.avps[1], plain[1]);
+    assert_eq!(msg.avps[3], plain[2]);
+    assert_eq!(msg.avps[4], plain[3]);
+}
+
+#[test]
+fn hide_selected_keeps_previous_random_vector() {
+    let secret = b"my_super_secret";
+    let mut rng = CounterRng { state: 0x1337 };
+    let vendor_name = AVP::VendorName("test vendor".to_owned().into());
+    let host_name = AVP::HostName("test-host.com".as_bytes().to_owned().into());
+
+    let mut msg = make_message(vec![
+        AVP::MessageType(types::MessageType::StartControlConnectionRequest),
+        vendor_name.clone(),
+        host_name.clone(),
+    ]);
+    msg.hide_selected(secret, &mut rng, |avp| matches!(avp, AVP::VendorName(_)))
+        .unwrap();
+    msg.hide_selected(secret, &mut rng, |avp| matches!(avp, AVP::HostName(_)))
+        .unwrap();
+
+    assert_eq!(msg.reveal_all(secret), Ok(()));
+    assert_eq!(msg.avps[2], vendor_name);
+    assert_eq!(msg.avps[4], host_name);
+}
+
+#[test]
+fn hide_selected_oversize() {
+    let mut rng = CounterRng { state: 0x1337 };
+    let avps = vec![
+        AVP::MessageType(types::MessageType::Hello),
+        AVP::HostName(vec![0x61; 2000].into()),
+    ];
+
+    let mut msg = make_message(avps.clone());
+    assert_eq!(
+        msg.hide_selected(b"secret", &mut rng, |_| true),
+        Err(EncodeError::OversizeAVP(7, 2006))
+    );
+    assert_eq!(msg.avps, avps);
+}
+
+#[test]
+fn hide_selected_nothing() {
+    let mut rng = CounterRng { state: 0x1337 };
+    let avps = vec![AVP::MessageType(types::MessageType::Hello)];
+
+    let mut msg = make_message(avps.clone());
+    msg.hide_selected(b"secret", &mut rng, |_| true).unwrap();
+    assert_eq!(msg.avps, avps);
+}
+
+#[test]
+fn reveal_all_without_random_vector() {
+    let mut msg = make_message(vec![
+        AVP::MessageType(types::MessageType::Hello),
+        AVP::Hidden(types::Hidden {
+            attribute_type: 7,
+            value: vec![0x00; 16],
+        }),
+    ]);
+    assert_eq!(
+        msg.reveal_all(b"secret"),
+        Err(vec![DecodeError::HiddenAVPWithoutRandomVector(7)])
+    );
+    assert!(matches!(msg.avps[1], AVP::Hidden(_)));
+}
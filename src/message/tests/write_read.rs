@@ -1,4 +1,5 @@
-use crate::common::{SliceReader, VecWriter};
+use crate::avp::AVP;
+use crate::common::{EncodeError, SliceReader, SliceWriter, VecWriter, Writer};
 use crate::message::*;
 
 #[test]
@@ -31,3 +32,107 @@ fn empty_control() {
 
     assert_eq!(in_msg, out_msg);
 }
+
+#[test]
+fn oversize_control() {
+    let in_msg = Message::<Vec<u8>>::Control(ControlMessage {
+        length: 0,
+        tunnel_id: 0,
+        session_id: 1,
+        ns: 2,
+        nr: 3,
+        avps: vec![AVP::HostName(vec![0x61; 1017].into()); 65],
+    });
+
+    let mut w = VecWriter::new();
+    assert_eq!(
+        in_msg.try_write(&mut w),
+        Err(EncodeError::OversizeMessage(12 + 65 * 1023))
+    );
+    assert!(w.is_empty());
+}
+
+#[test]
+fn oversize_data() {
+    let data = vec![0xff; u16::MAX as usize];
+    let in_msg = Message::Data(DataMessage {
+        is_prioritized: false,
+        length: None,
+        tunnel_id: 0,
+        session_id: 1,
+        ns_nr: None,
+        offset: None,
+        data: &data[..],
+    });
+
+    let mut w = VecWriter::new();
+    assert_eq!(
+        in_msg.try_write(&mut w),
+        Err(EncodeError::OversizeMessage(6 + data.len()))
+    );
+    assert!(w.is_empty());
+}
+
+#[test]
+fn writer_capacity_exhausted() {
+    let in_msg = Message::<Vec<u8>>::Control(ControlMessage {
+        length: 0,
+        tunnel_id: 0,
+        session_id: 1,
+        ns: 2,
+        nr: 3,
+        avps: vec![AVP::HostName("test-host.com".as_bytes().to_owned().into())],
+    });
+
+    let mut buffer = [0x00; 16];
+    let mut w = SliceWriter::from(&mut buffer);
+    assert_eq!(
+        in_msg.try_write(&mut w),
+        Err(EncodeError::WriterCapacityExhausted)
+    );
+
+    let mut buffer = [0x00; 31];
+    let mut w = SliceWriter::from(&mut buffer);
+    assert_eq!(in_msg.try_write(&mut w), Ok(()));
+    assert_eq!(w.finish().map(|x| x.len()), Ok(31));
+}
+
+#[test]
+fn try_write_variants() {
+    let control = ControlMessage {
+        length: 0,
+        tunnel_id: 0,
+        session_id: 1,
+        ns: 2,
+        nr: 3,
+        avps: vec![AVP::HostName("test-host.com".as_bytes().to_owned().into())],
+    };
+    let data = DataMessage {
+        is_prioritized: false,
+        length: None,
+        tunnel_id: 0,
+        session_id: 1,
+        ns_nr: None,
+        offset: None,
+        data: &[0xff, 0x03][..],
+    };
+
+    let mut w = VecWriter::new();
+    assert_eq!(control.try_write(&mut w), Ok(()));
+    let mut expected = VecWriter::new();
+    Message::<&[u8]>::Control(control.clone()).write(&mut expected);
+    assert_eq!(w.data, expected.data);
+
+    let mut w = VecWriter::new();
+    assert_eq!(data.try_write(&mut w), Ok(()));
+    let mut expected = VecWriter::new();
+    Message::Data(data).write(&mut expected);
+    assert_eq!(w.data, expected.data);
+
+    let mut buffer = [0x00; 16];
+    let mut w = SliceWriter::from(&mut buffer);
+    assert_eq!(
+        control.try_write(&mut w),
+        Err(EncodeError::WriterCapacityExhausted)
+    );
+}
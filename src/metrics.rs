@@ -0,0 +1,13 @@
+//! Types and implementations related to exporting `Tunnel` statistics in the
+//! [OpenMetrics](https://openmetrics.io) text format, e.g. for scraping by Prometheus.
+//!
+//! Requires the `metrics` feature.
+
+#[cfg(test)]
+mod tests;
+
+mod openmetrics;
+pub use openmetrics::*;
+
+mod http;
+pub use http::*;
@@ -0,0 +1,116 @@
+use crate::metrics::{render, CONTENT_TYPE};
+use crate::tunnel::TunnelSnapshot;
+use std::io::{self, Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+/// The path at which metrics are served.
+pub const METRICS_PATH: &str = "/metrics";
+
+const MAXIMUM_REQUEST_LENGTH: usize = 8192;
+
+/// The read and write timeout of connections accepted by `serve`.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+const HEAD_TERMINATOR: &[u8] = b"\r\n\r\n";
+
+/// Read an HTTP request head, returning its request line.
+fn read_request_line(stream: &mut impl Read) -> io::Result<String> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    loop {
+        if head.len() > MAXIMUM_REQUEST_LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Oversize request",
+            ));
+        }
+        let count = stream.read(&mut buffer)?;
+        if count == 0 {
+            break;
+        }
+
+        // Only scan the newly read octets, plus those a terminator split across reads may start in
+        let start = head.len().saturating_sub(HEAD_TERMINATOR.len() - 1);
+        head.extend_from_slice(&buffer[..count]);
+        if head[start..]
+            .windows(HEAD_TERMINATOR.len())
+            .any(|x| x == HEAD_TERMINATOR)
+        {
+            break;
+        }
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    Ok(head.lines().next().unwrap_or_default().to_owned())
+}
+
+fn respond(
+    stream: &mut impl Write,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// # Summary
+/// Handle a single HTTP request on `stream`, serving the metrics of the snapshots returned by `snapshots` in
+/// response to `GET /metrics`.
+///
+/// The snapshots are only taken for requests of the metrics path.
+pub fn handle_connection(
+    stream: &mut (impl Read + Write),
+    snapshots: impl FnOnce() -> Vec<TunnelSnapshot>,
+) -> io::Result<()> {
+    let request_line = read_request_line(stream)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+
+    const TEXT: &str = "text/plain; charset=utf-8";
+    match (method, path) {
+        ("GET", METRICS_PATH) => respond(stream, "200 OK", CONTENT_TYPE, &render(&snapshots())),
+        (_, METRICS_PATH) => respond(
+            stream,
+            "405 Method Not Allowed",
+            TEXT,
+            "Method Not Allowed\n",
+        ),
+        _ => respond(stream, "404 Not Found", TEXT, "Not Found\n"),
+    }
+}
+
+/// # Summary
+/// Serve metrics to every connection accepted by `listener`, one connection at a time.
+///
+/// This function blocks for as long as `listener` accepts connections and is intended to run on a dedicated thread.
+/// Errors of individual connections, including failures to accept them, are ignored. Connections which stall for
+/// longer than `CONNECTION_TIMEOUT` while reading the request or writing the response are dropped.
+pub fn serve(
+    listener: &TcpListener,
+    snapshots: impl Fn() -> Vec<TunnelSnapshot>,
+) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(_error) => {
+                debug!(error = %_error, "Failed to accept metrics connection");
+                continue;
+            }
+        };
+        let result = stream
+            .set_read_timeout(Some(CONNECTION_TIMEOUT))
+            .and_then(|_| stream.set_write_timeout(Some(CONNECTION_TIMEOUT)))
+            .and_then(|_| handle_connection(&mut stream, &snapshots));
+        if let Err(_error) = result {
+            debug!(error = %_error, "Failed to serve metrics");
+        }
+    }
+    Ok(())
+}
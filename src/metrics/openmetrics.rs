@@ -0,0 +1,265 @@
+use crate::avp::types::result_code::CodeValue;
+use crate::avp::types::MessageType;
+use crate::session::SessionState;
+use crate::tunnel::{TunnelSnapshot, TunnelState};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// The media type of the OpenMetrics text format.
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+const TUNNEL_STATES: [TunnelState; 4] = [
+    TunnelState::Idle,
+    TunnelState::Establishing,
+    TunnelState::Established,
+    TunnelState::Closed,
+];
+
+const SESSION_STATES: [SessionState; 5] = [
+    SessionState::Idle,
+    SessionState::Establishing,
+    SessionState::Established,
+    SessionState::OnHold,
+    SessionState::Closed,
+];
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// A metric family being rendered.
+struct Family<'a> {
+    out: &'a mut String,
+    name: &'static str,
+    suffix: &'static str,
+}
+
+impl<'a> Family<'a> {
+    fn new(out: &'a mut String, name: &'static str, kind: &str, help: &str) -> Self {
+        let _ = writeln!(out, "# TYPE {name} {kind}");
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let suffix = if kind == "counter" { "_total" } else { "" };
+        Self { out, name, suffix }
+    }
+
+    fn sample(&mut self, labels: &[(&str, &str)], value: u64) {
+        let _ = write!(self.out, "{}{}", self.name, self.suffix);
+        if !labels.is_empty() {
+            self.out.push('{');
+            for (i, (label, value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.out.push(',');
+                }
+                let _ = write!(self.out, "{label}=\"{}\"", escape(value));
+            }
+            self.out.push('}');
+        }
+        let _ = writeln!(self.out, " {value}");
+    }
+}
+
+fn teardown_reason(message_type: MessageType, code: u16) -> String {
+    let code = CodeValue::from(code);
+    let reason = match message_type {
+        MessageType::StopControlConnectionNotification => {
+            code.as_stop_ccn().map(|x| format!("{x:?}"))
+        }
+        _ => code.as_cdn().map(|x| format!("{x:?}")),
+    };
+    reason.unwrap_or_else(|_| "Unknown".to_owned())
+}
+
+fn control_messages(family: &mut Family, tunnel_id: &str, counters: &BTreeMap<MessageType, u64>) {
+    for (message_type, count) in counters.iter() {
+        family.sample(
+            &[
+                ("tunnel_id", tunnel_id),
+                ("message_type", message_type.name()),
+                ("code", &message_type.get_code().to_string()),
+            ],
+            *count,
+        );
+    }
+}
+
+fn directional(family: &mut Family, tunnel_id: &str, sent: u64, received: u64) {
+    family.sample(&[("tunnel_id", tunnel_id), ("direction", "sent")], sent);
+    family.sample(
+        &[("tunnel_id", tunnel_id), ("direction", "received")],
+        received,
+    );
+}
+
+/// # Summary
+/// Render the given tunnel snapshots in the OpenMetrics text format.
+pub fn render(snapshots: &[TunnelSnapshot]) -> String {
+    let mut out = String::new();
+    let ids: Vec<String> = snapshots.iter().map(|x| x.tunnel_id.to_string()).collect();
+    let tunnels = || ids.iter().map(String::as_str).zip(snapshots.iter());
+
+    Family::new(&mut out, "l2tp_tunnels", "gauge", "Number of tunnels.")
+        .sample(&[], snapshots.len() as u64);
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_tunnel_state",
+        "gauge",
+        "Number of tunnels by state.",
+    );
+    for state in TUNNEL_STATES {
+        let count = snapshots.iter().filter(|x| x.state == state).count();
+        family.sample(&[("state", &format!("{state:?}"))], count as u64);
+    }
+
+    Family::new(&mut out, "l2tp_sessions", "gauge", "Number of sessions.")
+        .sample(&[], snapshots.iter().map(|x| x.sessions.len() as u64).sum());
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_session_state",
+        "gauge",
+        "Number of sessions by state.",
+    );
+    for state in SESSION_STATES {
+        let count = snapshots
+            .iter()
+            .flat_map(|x| x.sessions.values())
+            .filter(|x| x.state == state)
+            .count();
+        family.sample(&[("state", &format!("{state:?}"))], count as u64);
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_control_messages_sent",
+        "counter",
+        "Control messages sent, excluding retransmissions and ZLBs.",
+    );
+    for (id, snapshot) in tunnels() {
+        control_messages(&mut family, id, &snapshot.tunnel.messages.control_sent);
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_control_messages_received",
+        "counter",
+        "Control messages received, excluding ZLBs.",
+    );
+    for (id, snapshot) in tunnels() {
+        control_messages(&mut family, id, &snapshot.tunnel.messages.control_received);
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_retransmissions",
+        "counter",
+        "Control messages retransmitted.",
+    );
+    for (id, snapshot) in tunnels() {
+        family.sample(&[("tunnel_id", id)], snapshot.tunnel.retransmissions);
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_zlb",
+        "counter",
+        "ZLB acknowledgements sent and received.",
+    );
+    for (id, snapshot) in tunnels() {
+        directional(
+            &mut family,
+            id,
+            snapshot.tunnel.zlb_sent,
+            snapshot.tunnel.zlb_received,
+        );
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_data_packets",
+        "counter",
+        "Data messages sent and received.",
+    );
+    for (id, snapshot) in tunnels() {
+        let messages = &snapshot.tunnel.messages;
+        directional(
+            &mut family,
+            id,
+            messages.data_packets_sent,
+            messages.data_packets_received,
+        );
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_data_bytes",
+        "counter",
+        "Payload octets of data messages sent and received.",
+    );
+    for (id, snapshot) in tunnels() {
+        let messages = &snapshot.tunnel.messages;
+        directional(
+            &mut family,
+            id,
+            messages.data_bytes_sent,
+            messages.data_bytes_received,
+        );
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_control_sequence_errors",
+        "counter",
+        "Control messages received with an unexpected Ns.",
+    );
+    for (id, snapshot) in tunnels() {
+        family.sample(
+            &[("tunnel_id", id)],
+            snapshot.tunnel.control_sequence_errors,
+        );
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_decode_errors",
+        "counter",
+        "Decode errors by error.",
+    );
+    for (id, snapshot) in tunnels() {
+        for (error, count) in snapshot.tunnel.decode_errors.iter() {
+            family.sample(&[("tunnel_id", id), ("error", error)], *count);
+        }
+    }
+
+    let mut family = Family::new(
+        &mut out,
+        "l2tp_teardowns",
+        "counter",
+        "StopCCN and CDN messages by result code.",
+    );
+    for (id, snapshot) in tunnels() {
+        for (direction, teardowns) in [
+            ("sent", &snapshot.tunnel.teardowns_sent),
+            ("received", &snapshot.tunnel.teardowns_received),
+        ] {
+            for ((message_type, code), count) in teardowns.iter() {
+                family.sample(
+                    &[
+                        ("tunnel_id", id),
+                        ("direction", direction),
+                        ("message_type", message_type.name()),
+                        ("result_code", &code.to_string()),
+                        ("reason", &teardown_reason(*message_type, *code)),
+                    ],
+                    *count,
+                );
+            }
+        }
+    }
+
+    out.push_str("# EOF\n");
+    out
+}
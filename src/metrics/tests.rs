@@ -0,0 +1,159 @@
+use crate::avp::types::MessageType;
+use crate::metrics::*;
+use crate::session::SessionState;
+use crate::tunnel::{SessionSnapshot, TunnelSnapshot, TunnelState};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+fn snapshots() -> Vec<TunnelSnapshot> {
+    let mut snapshot = TunnelSnapshot {
+        tunnel_id: 7,
+        state: TunnelState::Established,
+        ..Default::default()
+    };
+    snapshot.tunnel.retransmissions = 3;
+    snapshot
+        .tunnel
+        .messages
+        .control_received
+        .insert(MessageType::Hello, 2);
+    snapshot
+        .tunnel
+        .messages
+        .control_received
+        .insert(MessageType::from_code(30), 1);
+    snapshot.tunnel.messages.data_bytes_sent = 1500;
+    snapshot.tunnel.decode_errors.insert("IncompleteFlags", 1);
+    snapshot
+        .tunnel
+        .teardowns_received
+        .insert((MessageType::CallDisconnectNotify, 3), 4);
+    snapshot.sessions.insert(
+        1,
+        SessionSnapshot {
+            state: SessionState::Established,
+            ..Default::default()
+        },
+    );
+
+    vec![
+        snapshot,
+        TunnelSnapshot {
+            tunnel_id: 8,
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+fn render_metrics() {
+    let text = render(&snapshots());
+    let lines: Vec<&str> = text.lines().collect();
+
+    for expected in [
+        "# TYPE l2tp_tunnels gauge",
+        "l2tp_tunnels 2",
+        "l2tp_tunnel_state{state=\"Established\"} 1",
+        "l2tp_tunnel_state{state=\"Idle\"} 1",
+        "l2tp_tunnel_state{state=\"Closed\"} 0",
+        "l2tp_sessions 1",
+        "l2tp_session_state{state=\"Established\"} 1",
+        "# TYPE l2tp_retransmissions counter",
+        "l2tp_retransmissions_total{tunnel_id=\"7\"} 3",
+        "l2tp_retransmissions_total{tunnel_id=\"8\"} 0",
+        "l2tp_control_messages_received_total{tunnel_id=\"7\",message_type=\"Hello\",code=\"6\"} 2",
+        "l2tp_control_messages_received_total{tunnel_id=\"7\",message_type=\"Unknown\",code=\"30\"} 1",
+        "l2tp_data_bytes_total{tunnel_id=\"7\",direction=\"sent\"} 1500",
+        "l2tp_decode_errors_total{tunnel_id=\"7\",error=\"IncompleteFlags\"} 1",
+        "l2tp_teardowns_total{tunnel_id=\"7\",direction=\"received\",message_type=\"Call-Disconnect-Notify\",result_code=\"3\",reason=\"CallDisconnectedAdministrative\"} 4",
+    ] {
+        assert!(lines.contains(&expected), "missing {expected:?} in\n{text}");
+    }
+    assert_eq!(lines.last(), Some(&"# EOF"));
+}
+
+fn get(address: std::net::SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn scrape_loopback() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = std::thread::spawn(move || {
+        for _ in 0..3 {
+            let (mut stream, _) = listener.accept().unwrap();
+            handle_connection(&mut stream, snapshots).unwrap();
+        }
+    });
+
+    let response = get(address, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    assert!(head.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(head.contains(&format!("Content-Type: {CONTENT_TYPE}")));
+    assert!(head.contains(&format!("Content-Length: {}", body.len())));
+    assert_eq!(body, render(&snapshots()));
+
+    let response = get(address, "GET / HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    let response = get(address, "POST /metrics HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+
+    server.join().unwrap();
+}
+
+/// A stream yielding its request in small chunks and discarding the response.
+struct ChunkedStream<'a> {
+    chunks: std::slice::Iter<'a, &'a [u8]>,
+    response: Vec<u8>,
+}
+
+impl Read for ChunkedStream<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let chunk = self.chunks.next().copied().unwrap_or_default();
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        Ok(chunk.len())
+    }
+}
+
+impl Write for ChunkedStream<'_> {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.response.extend_from_slice(buffer);
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn split_request_head() {
+    let chunks: [&[u8]; 4] = [b"GET /metrics HTTP/1.1\r", b"\n\r", b"\n", b"trailing"];
+    let mut stream = ChunkedStream {
+        chunks: chunks.iter(),
+        response: Vec::new(),
+    };
+    handle_connection(&mut stream, snapshots).unwrap();
+
+    assert!(stream.response.starts_with(b"HTTP/1.1 200 OK\r\n"));
+    // The head was complete before the trailing chunk
+    assert_eq!(stream.chunks.next(), Some(&&b"trailing"[..]));
+}
+
+#[test]
+fn serve_after_stalled_connection() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || serve(&listener, snapshots));
+
+    // A client which never sends its request only delays the next one until it times out
+    let _stalled = TcpStream::connect(address).unwrap();
+    let response = get(address, "GET /metrics HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+}
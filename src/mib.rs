@@ -0,0 +1,20 @@
+//! Types and implementations related to exposing `Tunnel` state through the L2TP MIB (RFC 3371).
+//!
+//! The tables are populated from `TunnelSnapshot`s and exposed as object instances ordered by object identifier, so
+//! that an SNMP agent can serve them without knowledge of L2TP. The assignment of `ifIndex` values to tunnel and
+//! session interfaces is left to the agent.
+
+#[cfg(test)]
+mod tests;
+
+mod oid;
+pub use oid::*;
+
+mod value;
+pub use value::*;
+
+mod tables;
+pub use tables::*;
+
+mod view;
+pub use view::*;
@@ -0,0 +1,143 @@
+use core::fmt;
+
+/// The object identifier of the L2TP MIB module (RFC 3371), `mib-2.transmission.139`.
+pub const L2TP_MIB: [u32; 8] = [1, 3, 6, 1, 2, 1, 10, 139];
+
+/// The arc of `l2tpObjects` below `L2TP_MIB`.
+const OBJECTS_ARC: u32 = 1;
+
+/// The arc of the conceptual row below each table.
+const ENTRY_ARC: u32 = 1;
+
+/// # Summary
+/// An SNMP object identifier.
+///
+/// Object identifiers are ordered lexicographically by their arcs, which is the order used by SNMP `GetNext`.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Oid(pub Vec<u32>);
+
+impl Oid {
+    /// # Summary
+    /// Get the arcs of this object identifier.
+    #[inline]
+    pub fn arcs(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// # Summary
+    /// Check whether `prefix` is a prefix of this object identifier.
+    #[inline]
+    pub fn starts_with(&self, prefix: &Oid) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    /// # Summary
+    /// Create a descendant of this object identifier by appending `arcs`.
+    #[inline]
+    pub fn child(&self, arcs: &[u32]) -> Oid {
+        let mut result = self.0.clone();
+        result.extend_from_slice(arcs);
+        Oid(result)
+    }
+}
+
+impl From<&[u32]> for Oid {
+    fn from(value: &[u32]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl fmt::Display for Oid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            write!(f, "{arc}")?;
+        }
+        Ok(())
+    }
+}
+
+/// # Summary
+/// The tables of the L2TP MIB modelled by this crate.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Table {
+    DomainConfig,
+    DomainStats,
+    TunnelConfig,
+    TunnelStats,
+    SessionStats,
+}
+
+impl Table {
+    const ALL: [Table; 5] = [
+        Table::DomainConfig,
+        Table::DomainStats,
+        Table::TunnelConfig,
+        Table::TunnelStats,
+        Table::SessionStats,
+    ];
+
+    #[inline]
+    fn arc(self) -> u32 {
+        match self {
+            Table::DomainConfig => 2,
+            Table::DomainStats => 3,
+            Table::TunnelConfig => 4,
+            Table::TunnelStats => 5,
+            Table::SessionStats => 6,
+        }
+    }
+
+    /// # Summary
+    /// Get the object identifier of this table.
+    #[inline]
+    pub fn oid(self) -> Oid {
+        Oid(L2TP_MIB.to_vec()).child(&[OBJECTS_ARC, self.arc()])
+    }
+
+    /// # Summary
+    /// Get the object identifier of the conceptual row of this table.
+    #[inline]
+    pub fn entry(self) -> Oid {
+        self.oid().child(&[ENTRY_ARC])
+    }
+
+    /// # Summary
+    /// Get the object identifier of a column instance of this table.
+    ///
+    /// # Parameters
+    /// * `column` - The column number.
+    /// * `index` - The encoded index of the row.
+    #[inline]
+    pub fn column(self, column: u32, index: &[u32]) -> Oid {
+        let mut oid = self.entry().child(&[column]);
+        oid.0.extend_from_slice(index);
+        oid
+    }
+
+    /// # Summary
+    /// Split the object identifier of a column instance into its table, column number and encoded row index.
+    pub fn parse(oid: &Oid) -> Option<(Table, u32, &[u32])> {
+        Self::ALL.into_iter().find_map(|table| {
+            let entry = table.entry();
+            if !oid.starts_with(&entry) {
+                return None;
+            }
+            match &oid.0[entry.0.len()..] {
+                [column, index @ ..] => Some((table, *column, index)),
+                [] => None,
+            }
+        })
+    }
+}
+
+/// # Summary
+/// Encode a variable length octet string as a row index, i.e. prefixed with its length.
+#[inline]
+pub fn string_index(value: &[u8]) -> Vec<u32> {
+    core::iter::once(value.len() as u32)
+        .chain(value.iter().map(|x| *x as u32))
+        .collect()
+}
@@ -0,0 +1,654 @@
+use crate::avp::types::{BearerCapabilities, CallErrors, FramingCapabilities};
+use crate::mib::{string_index, MibValue, Oid, Table};
+use crate::session::SessionState;
+use crate::tunnel::{PeerInfo, SessionSnapshot, TunnelSnapshot, TunnelState};
+
+/// # Summary
+/// A conceptual row of an L2TP MIB table.
+pub trait Row {
+    /// The table this row belongs to.
+    const TABLE: Table;
+
+    /// # Summary
+    /// Get the encoded index of this row.
+    fn index(&self) -> Vec<u32>;
+
+    /// # Summary
+    /// Get the column numbers and values of the accessible objects of this row which have a value.
+    fn columns(&self) -> Vec<(u32, MibValue)>;
+
+    /// # Summary
+    /// Get the object identifiers and values of the accessible objects of this row which have a value.
+    fn varbinds(&self) -> Vec<(Oid, MibValue)> {
+        let index = self.index();
+        self.columns()
+            .into_iter()
+            .map(|(column, value)| (Self::TABLE.column(column, &index), value))
+            .collect()
+    }
+}
+
+fn tunnel_state(state: TunnelState) -> i32 {
+    match state {
+        TunnelState::Idle => 1,
+        TunnelState::Establishing => 2,
+        TunnelState::Established => 3,
+        TunnelState::Closed => 4,
+    }
+}
+
+fn session_state(state: SessionState) -> i32 {
+    match state {
+        SessionState::Idle => 1,
+        SessionState::Establishing => 2,
+        SessionState::Established | SessionState::OnHold => 3,
+        SessionState::Closed => 4,
+    }
+}
+
+fn truth_value(value: bool) -> i32 {
+    if value {
+        1
+    } else {
+        2
+    }
+}
+
+fn bearer_capabilities(capabilities: BearerCapabilities) -> i32 {
+    match (
+        capabilities.is_digital_access_supported(),
+        capabilities.is_analog_access_supported(),
+    ) {
+        (false, false) => 1,
+        (true, false) => 2,
+        (false, true) => 3,
+        (true, true) => 4,
+    }
+}
+
+fn framing_capabilities(capabilities: FramingCapabilities) -> i32 {
+    match (
+        capabilities.is_sync_framing_supported(),
+        capabilities.is_async_framing_supported(),
+    ) {
+        (false, false) => 1,
+        (true, false) => 2,
+        (false, true) => 3,
+        (true, true) => 4,
+    }
+}
+
+/// # Summary
+/// A row of `l2tpDomainConfigTable`.
+///
+/// The library does not manage domains, so the values are supplied by the caller and defaults are the `DEFVAL`s of
+/// the MIB. The tunnel defaults of the domain are those of `TunnelConfigRow`, and `l2tpDomainConfigSecret` is not
+/// exposed.
+///
+/// # Data members
+/// * `id` - The `l2tpDomainConfigId` of the domain.
+/// * `is_enabled` - Whether the domain is administratively enabled.
+/// * `drain_tunnels` - Whether new tunnels and sessions are refused, to drain the domain.
+/// * `tunnel_hello_interval` - The default interval between `Hello` messages in seconds.
+/// * `tunnel_idle_timeout` - The default number of seconds a tunnel without sessions is kept, or zero to keep it
+///   indefinitely.
+/// * `control_rws` - The default control channel receive window size.
+/// * `control_max_retransmissions` - The default number of retransmissions before a tunnel is torn down.
+/// * `control_max_retransmit_timeout` - The default maximum retransmission timeout in seconds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainConfigRow {
+    pub id: String,
+    pub is_enabled: bool,
+    pub drain_tunnels: bool,
+    pub tunnel_hello_interval: i32,
+    pub tunnel_idle_timeout: i32,
+    pub control_rws: i32,
+    pub control_max_retransmissions: i32,
+    pub control_max_retransmit_timeout: i32,
+}
+
+impl DomainConfigRow {
+    pub const ADMIN_STATE: u32 = 2;
+    pub const DRAIN_TUNNELS: u32 = 3;
+    pub const TUNNEL_HELLO_INT: u32 = 7;
+    pub const TUNNEL_IDLE_TO: u32 = 8;
+    pub const CONTROL_RWS: u32 = 9;
+    pub const CONTROL_MAX_RETX: u32 = 10;
+    pub const CONTROL_MAX_RETX_TO: u32 = 11;
+
+    /// # Summary
+    /// Create a `DomainConfigRow` with default values.
+    #[inline]
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            is_enabled: true,
+            drain_tunnels: false,
+            tunnel_hello_interval: 60,
+            tunnel_idle_timeout: 0,
+            control_rws: 4,
+            control_max_retransmissions: 5,
+            control_max_retransmit_timeout: 16,
+        }
+    }
+}
+
+impl Row for DomainConfigRow {
+    const TABLE: Table = Table::DomainConfig;
+
+    #[inline]
+    fn index(&self) -> Vec<u32> {
+        string_index(self.id.as_bytes())
+    }
+
+    fn columns(&self) -> Vec<(u32, MibValue)> {
+        vec![
+            (
+                Self::ADMIN_STATE,
+                MibValue::Integer(if self.is_enabled { 1 } else { 2 }),
+            ),
+            (
+                Self::DRAIN_TUNNELS,
+                MibValue::Integer(truth_value(self.drain_tunnels)),
+            ),
+            (
+                Self::TUNNEL_HELLO_INT,
+                MibValue::Integer(self.tunnel_hello_interval),
+            ),
+            (
+                Self::TUNNEL_IDLE_TO,
+                MibValue::Integer(self.tunnel_idle_timeout),
+            ),
+            (Self::CONTROL_RWS, MibValue::Integer(self.control_rws)),
+            (
+                Self::CONTROL_MAX_RETX,
+                MibValue::Integer(self.control_max_retransmissions),
+            ),
+            (
+                Self::CONTROL_MAX_RETX_TO,
+                MibValue::Integer(self.control_max_retransmit_timeout),
+            ),
+        ]
+    }
+}
+
+/// # Summary
+/// A row of `l2tpDomainStatsTable`, aggregated over the tunnels of an L2TP domain.
+///
+/// # Data members
+/// * `id` - The `l2tpDomainConfigId` of the domain.
+/// * `active_tunnels` - The number of established tunnels.
+/// * `active_sessions` - The number of established sessions.
+/// * `control_rx_packets` - The number of control messages received, including ZLBs.
+/// * `control_tx_packets` - The number of control messages sent, including retransmissions and ZLBs.
+/// * `payload_rx_octets` - The number of payload octets of data messages received.
+/// * `payload_rx_packets` - The number of data messages received.
+/// * `payload_tx_octets` - The number of payload octets of data messages sent.
+/// * `payload_tx_packets` - The number of data messages sent.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DomainRow {
+    pub id: String,
+    pub active_tunnels: usize,
+    pub active_sessions: usize,
+    pub control_rx_packets: u64,
+    pub control_tx_packets: u64,
+    pub payload_rx_octets: u64,
+    pub payload_rx_packets: u64,
+    pub payload_tx_octets: u64,
+    pub payload_tx_packets: u64,
+}
+
+impl DomainRow {
+    pub const ACTIVE_TUNNELS: u32 = 4;
+    pub const ACTIVE_SESSIONS: u32 = 7;
+    pub const CONTROL_RX_PKTS: u32 = 10;
+    pub const CONTROL_TX_PKTS: u32 = 12;
+    pub const PAYLOAD_RX_OCTETS: u32 = 13;
+    pub const PAYLOAD_RX_PKTS: u32 = 14;
+    pub const PAYLOAD_TX_OCTETS: u32 = 16;
+    pub const PAYLOAD_TX_PKTS: u32 = 17;
+
+    /// # Summary
+    /// Create a `DomainRow` from the snapshots of the tunnels of a domain.
+    pub fn new(id: &str, snapshots: &[TunnelSnapshot]) -> Self {
+        let mut row = Self {
+            id: id.to_owned(),
+            ..Default::default()
+        };
+        for snapshot in snapshots.iter() {
+            let statistics = &snapshot.tunnel;
+            if snapshot.state == TunnelState::Established {
+                row.active_tunnels += 1;
+            }
+            row.active_sessions += snapshot
+                .sessions
+                .values()
+                .filter(|x| matches!(x.state, SessionState::Established | SessionState::OnHold))
+                .count();
+            row.control_rx_packets +=
+                statistics.messages.control_received_total() + statistics.zlb_received;
+            row.control_tx_packets += statistics.messages.control_sent_total()
+                + statistics.zlb_sent
+                + statistics.retransmissions;
+            row.payload_rx_octets += statistics.messages.data_bytes_received;
+            row.payload_rx_packets += statistics.messages.data_packets_received;
+            row.payload_tx_octets += statistics.messages.data_bytes_sent;
+            row.payload_tx_packets += statistics.messages.data_packets_sent;
+        }
+        row
+    }
+}
+
+impl Row for DomainRow {
+    const TABLE: Table = Table::DomainStats;
+
+    #[inline]
+    fn index(&self) -> Vec<u32> {
+        string_index(self.id.as_bytes())
+    }
+
+    fn columns(&self) -> Vec<(u32, MibValue)> {
+        vec![
+            (Self::ACTIVE_TUNNELS, MibValue::gauge(self.active_tunnels)),
+            (Self::ACTIVE_SESSIONS, MibValue::gauge(self.active_sessions)),
+            (
+                Self::CONTROL_RX_PKTS,
+                MibValue::counter(self.control_rx_packets),
+            ),
+            (
+                Self::CONTROL_TX_PKTS,
+                MibValue::counter(self.control_tx_packets),
+            ),
+            (
+                Self::PAYLOAD_RX_OCTETS,
+                MibValue::counter(self.payload_rx_octets),
+            ),
+            (
+                Self::PAYLOAD_RX_PKTS,
+                MibValue::counter(self.payload_rx_packets),
+            ),
+            (
+                Self::PAYLOAD_TX_OCTETS,
+                MibValue::counter(self.payload_tx_octets),
+            ),
+            (
+                Self::PAYLOAD_TX_PKTS,
+                MibValue::counter(self.payload_tx_packets),
+            ),
+        ]
+    }
+}
+
+/// # Summary
+/// A row of `l2tpTunnelConfigTable`.
+///
+/// The library does not manage timers or windows, so apart from the domain the values are supplied by the caller.
+/// Defaults are the `DEFVAL`s of the MIB.
+///
+/// # Data members
+/// * `if_index` - The `ifIndex` of the tunnel interface.
+/// * `domain_id` - The `l2tpDomainConfigId` of the domain the tunnel belongs to.
+/// * `hello_interval` - The interval between `Hello` messages in seconds.
+/// * `idle_timeout` - The number of seconds a tunnel without sessions is kept, or zero to keep it indefinitely.
+/// * `control_rws` - The control channel receive window size.
+/// * `control_max_retransmissions` - The number of retransmissions before the tunnel is torn down.
+/// * `control_max_retransmit_timeout` - The maximum retransmission timeout in seconds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TunnelConfigRow {
+    pub if_index: u32,
+    pub domain_id: String,
+    pub hello_interval: i32,
+    pub idle_timeout: i32,
+    pub control_rws: i32,
+    pub control_max_retransmissions: i32,
+    pub control_max_retransmit_timeout: i32,
+}
+
+impl TunnelConfigRow {
+    pub const DOMAIN_ID: u32 = 1;
+    pub const HELLO_INTERVAL: u32 = 5;
+    pub const IDLE_TIMEOUT: u32 = 6;
+    pub const CONTROL_RWS: u32 = 7;
+    pub const CONTROL_MAX_RETX: u32 = 8;
+    pub const CONTROL_MAX_RETX_TO: u32 = 9;
+
+    /// # Summary
+    /// Create a `TunnelConfigRow` with default values.
+    #[inline]
+    pub fn new(if_index: u32, domain_id: &str) -> Self {
+        Self {
+            if_index,
+            domain_id: domain_id.to_owned(),
+            hello_interval: 60,
+            idle_timeout: 0,
+            control_rws: 4,
+            control_max_retransmissions: 5,
+            control_max_retransmit_timeout: 16,
+        }
+    }
+}
+
+impl Row for TunnelConfigRow {
+    const TABLE: Table = Table::TunnelConfig;
+
+    #[inline]
+    fn index(&self) -> Vec<u32> {
+        vec![self.if_index]
+    }
+
+    fn columns(&self) -> Vec<(u32, MibValue)> {
+        vec![
+            (
+                Self::DOMAIN_ID,
+                MibValue::OctetString(self.domain_id.as_bytes().to_vec()),
+            ),
+            (Self::HELLO_INTERVAL, MibValue::Integer(self.hello_interval)),
+            (Self::IDLE_TIMEOUT, MibValue::Integer(self.idle_timeout)),
+            (Self::CONTROL_RWS, MibValue::Integer(self.control_rws)),
+            (
+                Self::CONTROL_MAX_RETX,
+                MibValue::Integer(self.control_max_retransmissions),
+            ),
+            (
+                Self::CONTROL_MAX_RETX_TO,
+                MibValue::Integer(self.control_max_retransmit_timeout),
+            ),
+        ]
+    }
+}
+
+/// # Summary
+/// A row of `l2tpTunnelStatsTable`.
+///
+/// # Data members
+/// * `if_index` - The `ifIndex` of the tunnel interface.
+/// * `local_tunnel_id` - The tunnel identifier assigned locally.
+/// * `remote_tunnel_id` - The tunnel identifier assigned by the peer.
+/// * `state` - The state of the tunnel.
+/// * `peer` - The information announced by the peer.
+/// * `control_rx_packets` - The number of control messages received, excluding ZLBs.
+/// * `control_rx_zlb` - The number of ZLBs received.
+/// * `control_out_of_sequence` - The number of control messages received with an unexpected Ns.
+/// * `control_tx_packets` - The number of control messages sent, including retransmissions but excluding ZLBs.
+/// * `control_tx_zlb` - The number of ZLBs sent.
+/// * `active_sessions` - The number of established sessions.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TunnelStatsRow {
+    pub if_index: u32,
+    pub local_tunnel_id: u16,
+    pub remote_tunnel_id: u16,
+    pub state: TunnelState,
+    pub peer: PeerInfo,
+    pub control_rx_packets: u64,
+    pub control_rx_zlb: u64,
+    pub control_out_of_sequence: u64,
+    pub control_tx_packets: u64,
+    pub control_tx_zlb: u64,
+    pub active_sessions: usize,
+}
+
+impl TunnelStatsRow {
+    pub const LOCAL_TID: u32 = 1;
+    pub const REMOTE_TID: u32 = 2;
+    pub const STATE: u32 = 3;
+    pub const REMOTE_HOST_NAME: u32 = 5;
+    pub const REMOTE_VENDOR_NAME: u32 = 6;
+    pub const REMOTE_FIRMWARE_REVISION: u32 = 7;
+    pub const REMOTE_PROTOCOL_VERS: u32 = 8;
+    pub const BEARER_CAPS: u32 = 10;
+    pub const FRAMING_CAPS: u32 = 11;
+    pub const CONTROL_RX_PKTS: u32 = 12;
+    pub const CONTROL_RX_ZLB: u32 = 13;
+    pub const CONTROL_OUT_OF_SEQ: u32 = 14;
+    pub const CONTROL_TX_PKTS: u32 = 16;
+    pub const CONTROL_TX_ZLB: u32 = 17;
+    pub const ACTIVE_SESSIONS: u32 = 26;
+
+    /// # Summary
+    /// Create a `TunnelStatsRow` from a snapshot of a tunnel.
+    pub fn new(if_index: u32, snapshot: &TunnelSnapshot) -> Self {
+        let statistics = &snapshot.tunnel;
+        Self {
+            if_index,
+            local_tunnel_id: snapshot.tunnel_id,
+            remote_tunnel_id: snapshot.remote_tunnel_id,
+            state: snapshot.state,
+            peer: snapshot.peer.clone(),
+            control_rx_packets: statistics.messages.control_received_total(),
+            control_rx_zlb: statistics.zlb_received,
+            control_out_of_sequence: statistics.control_sequence_errors,
+            control_tx_packets: statistics.messages.control_sent_total()
+                + statistics.retransmissions,
+            control_tx_zlb: statistics.zlb_sent,
+            active_sessions: snapshot
+                .sessions
+                .values()
+                .filter(|x| matches!(x.state, SessionState::Established | SessionState::OnHold))
+                .count(),
+        }
+    }
+}
+
+impl Row for TunnelStatsRow {
+    const TABLE: Table = Table::TunnelStats;
+
+    #[inline]
+    fn index(&self) -> Vec<u32> {
+        vec![self.if_index]
+    }
+
+    fn columns(&self) -> Vec<(u32, MibValue)> {
+        let peer = &self.peer;
+        let mut columns = vec![
+            (
+                Self::LOCAL_TID,
+                MibValue::Integer(self.local_tunnel_id as i32),
+            ),
+            (
+                Self::REMOTE_TID,
+                MibValue::Integer(self.remote_tunnel_id as i32),
+            ),
+            (Self::STATE, MibValue::Integer(tunnel_state(self.state))),
+        ];
+        if let Some(host_name) = &peer.host_name {
+            columns.push((
+                Self::REMOTE_HOST_NAME,
+                MibValue::OctetString(host_name.clone()),
+            ));
+        }
+        if let Some(vendor_name) = &peer.vendor_name {
+            columns.push((
+                Self::REMOTE_VENDOR_NAME,
+                MibValue::OctetString(vendor_name.as_bytes().to_vec()),
+            ));
+        }
+        if let Some(firmware_revision) = peer.firmware_revision {
+            columns.push((
+                Self::REMOTE_FIRMWARE_REVISION,
+                MibValue::Integer(firmware_revision as i32),
+            ));
+        }
+        if let Some(version) = peer.protocol_version {
+            columns.push((
+                Self::REMOTE_PROTOCOL_VERS,
+                MibValue::OctetString(vec![version.version, version.revision]),
+            ));
+        }
+        if let Some(capabilities) = peer.bearer_capabilities {
+            columns.push((
+                Self::BEARER_CAPS,
+                MibValue::Integer(bearer_capabilities(capabilities)),
+            ));
+        }
+        if let Some(capabilities) = peer.framing_capabilities {
+            columns.push((
+                Self::FRAMING_CAPS,
+                MibValue::Integer(framing_capabilities(capabilities)),
+            ));
+        }
+        columns.extend([
+            (
+                Self::CONTROL_RX_PKTS,
+                MibValue::counter(self.control_rx_packets),
+            ),
+            (Self::CONTROL_RX_ZLB, MibValue::counter(self.control_rx_zlb)),
+            (
+                Self::CONTROL_OUT_OF_SEQ,
+                MibValue::counter(self.control_out_of_sequence),
+            ),
+            (
+                Self::CONTROL_TX_PKTS,
+                MibValue::counter(self.control_tx_packets),
+            ),
+            (Self::CONTROL_TX_ZLB, MibValue::counter(self.control_tx_zlb)),
+            (Self::ACTIVE_SESSIONS, MibValue::gauge(self.active_sessions)),
+        ]);
+        columns
+    }
+}
+
+/// # Summary
+/// A row of `l2tpSessionStatsTable`.
+///
+/// # Data members
+/// * `if_index` - The `ifIndex` of the session interface.
+/// * `tunnel_if_index` - The `ifIndex` of the interface of the tunnel carrying the session.
+/// * `local_session_id` - The session identifier assigned locally.
+/// * `remote_session_id` - The session identifier assigned by the peer.
+/// * `state` - The call state of the session.
+/// * `tx_connect_speed` - The most recently announced transmit connect speed in bits per second.
+/// * `rx_connect_speed` - The most recently announced receive connect speed in bits per second.
+/// * `data_tx_packets` - The number of data messages sent.
+/// * `data_tx_octets` - The number of payload octets of data messages sent.
+/// * `data_rx_packets` - The number of data messages received.
+/// * `data_rx_octets` - The number of payload octets of data messages received.
+/// * `out_of_sequence` - The number of data messages received out of sequence.
+/// * `call_errors` - The most recently reported error counters of the call.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SessionStatsRow {
+    pub if_index: u32,
+    pub tunnel_if_index: u32,
+    pub local_session_id: u16,
+    pub remote_session_id: u16,
+    pub state: SessionState,
+    pub tx_connect_speed: Option<u64>,
+    pub rx_connect_speed: Option<u64>,
+    pub data_tx_packets: u64,
+    pub data_tx_octets: u64,
+    pub data_rx_packets: u64,
+    pub data_rx_octets: u64,
+    pub out_of_sequence: u64,
+    pub call_errors: CallErrors,
+}
+
+impl SessionStatsRow {
+    pub const TUNNEL_IF_INDEX: u32 = 1;
+    pub const LOCAL_SID: u32 = 2;
+    pub const REMOTE_SID: u32 = 3;
+    pub const STATE: u32 = 5;
+    pub const TX_CONNECT_SPEED: u32 = 8;
+    pub const RX_CONNECT_SPEED: u32 = 9;
+    pub const DATA_TX_PKTS: u32 = 22;
+    pub const DATA_TX_OCTETS: u32 = 23;
+    pub const DATA_RX_PKTS: u32 = 24;
+    pub const DATA_RX_OCTETS: u32 = 25;
+    pub const OUT_OF_SEQUENCE: u32 = 26;
+    pub const CRC_ERRORS: u32 = 27;
+    pub const FRAMING_ERRORS: u32 = 28;
+    pub const HARDWARE_OVERRUNS: u32 = 29;
+    pub const BUFFER_OVERRUNS: u32 = 30;
+    pub const TIMEOUT_ERRORS: u32 = 31;
+    pub const ALIGNMENT_ERRORS: u32 = 32;
+
+    /// # Summary
+    /// Create a `SessionStatsRow` from a snapshot of a session.
+    pub fn new(
+        if_index: u32,
+        tunnel_if_index: u32,
+        local_session_id: u16,
+        snapshot: &SessionSnapshot,
+    ) -> Self {
+        let messages = &snapshot.statistics.messages;
+        Self {
+            if_index,
+            tunnel_if_index,
+            local_session_id,
+            remote_session_id: snapshot.remote_session_id,
+            state: snapshot.state,
+            tx_connect_speed: snapshot.tx_connect_speed,
+            rx_connect_speed: snapshot.rx_connect_speed,
+            data_tx_packets: messages.data_packets_sent,
+            data_tx_octets: messages.data_bytes_sent,
+            data_rx_packets: messages.data_packets_received,
+            data_rx_octets: messages.data_bytes_received,
+            out_of_sequence: snapshot.statistics.data_sequence_errors,
+            call_errors: snapshot.statistics.call_errors.clone(),
+        }
+    }
+}
+
+impl Row for SessionStatsRow {
+    const TABLE: Table = Table::SessionStats;
+
+    #[inline]
+    fn index(&self) -> Vec<u32> {
+        vec![self.if_index]
+    }
+
+    fn columns(&self) -> Vec<(u32, MibValue)> {
+        let errors = &self.call_errors;
+        let mut columns = vec![
+            (
+                Self::TUNNEL_IF_INDEX,
+                MibValue::Integer(self.tunnel_if_index as i32),
+            ),
+            (
+                Self::LOCAL_SID,
+                MibValue::Integer(self.local_session_id as i32),
+            ),
+            (
+                Self::REMOTE_SID,
+                MibValue::Integer(self.remote_session_id as i32),
+            ),
+            (Self::STATE, MibValue::Integer(session_state(self.state))),
+        ];
+        if let Some(speed) = self.tx_connect_speed {
+            columns.push((Self::TX_CONNECT_SPEED, MibValue::gauge(speed)));
+        }
+        if let Some(speed) = self.rx_connect_speed {
+            columns.push((Self::RX_CONNECT_SPEED, MibValue::gauge(speed)));
+        }
+        columns.extend([
+            (Self::DATA_TX_PKTS, MibValue::counter(self.data_tx_packets)),
+            (Self::DATA_TX_OCTETS, MibValue::counter(self.data_tx_octets)),
+            (Self::DATA_RX_PKTS, MibValue::counter(self.data_rx_packets)),
+            (Self::DATA_RX_OCTETS, MibValue::counter(self.data_rx_octets)),
+            (
+                Self::OUT_OF_SEQUENCE,
+                MibValue::counter(self.out_of_sequence),
+            ),
+            (Self::CRC_ERRORS, MibValue::Counter32(errors.crc_errors)),
+            (
+                Self::FRAMING_ERRORS,
+                MibValue::Counter32(errors.framing_errors),
+            ),
+            (
+                Self::HARDWARE_OVERRUNS,
+                MibValue::Counter32(errors.hardware_overruns),
+            ),
+            (
+                Self::BUFFER_OVERRUNS,
+                MibValue::Counter32(errors.buffer_overruns),
+            ),
+            (
+                Self::TIMEOUT_ERRORS,
+                MibValue::Counter32(errors.timeout_errors),
+            ),
+            (
+                Self::ALIGNMENT_ERRORS,
+                MibValue::Counter32(errors.alignment_errors),
+            ),
+        ]);
+        columns
+    }
+}
@@ -0,0 +1,282 @@
+use crate::avp::types::{
+    BearerCapabilities, CallErrors, FirmwareRevision, FramingCapabilities, HostName, MessageType,
+    ProtocolVersion, TxConnectSpeed, VendorName,
+};
+use crate::avp::AVP;
+use crate::common::{SliceReader, VecWriter};
+use crate::mib::*;
+use crate::tunnel::Tunnel;
+use crate::{ControlMessage, Message};
+use std::time::Instant;
+
+fn receive(tunnel: &mut Tunnel, session_id: u16, ns: u16, avps: Vec<AVP>) -> ControlMessage {
+    let mut message = ControlMessage {
+        length: 0,
+        tunnel_id: 1,
+        session_id,
+        ns,
+        nr: 0,
+        avps,
+    };
+    message.length = message.get_checked_length().unwrap() as u16;
+    let mut w = VecWriter::new();
+    Message::<Vec<u8>>::Control(message.clone())
+        .try_write(&mut w)
+        .unwrap();
+    tunnel.read(&mut SliceReader::from(&w.data)).unwrap();
+    message
+}
+
+fn tunnel() -> Tunnel {
+    let mut tunnel = Tunnel::new(1, 2);
+    tunnel.add_session(10, 20);
+    receive(
+        &mut tunnel,
+        0,
+        0,
+        vec![
+            AVP::MessageType(MessageType::StartControlConnectionRequest),
+            AVP::ProtocolVersion(ProtocolVersion {
+                version: 1,
+                revision: 0,
+            }),
+            AVP::HostName(HostName {
+                value: b"lac".to_vec(),
+            }),
+            AVP::VendorName(VendorName {
+                value: "Vendor".to_owned(),
+            }),
+            AVP::FirmwareRevision(FirmwareRevision { value: 0x0102 }),
+            AVP::FramingCapabilities(FramingCapabilities::new(true, true)),
+            AVP::BearerCapabilities(BearerCapabilities::new(true, true)),
+        ],
+    );
+    receive(
+        &mut tunnel,
+        0,
+        1,
+        vec![AVP::MessageType(
+            MessageType::StartControlConnectionConnected,
+        )],
+    );
+    receive(
+        &mut tunnel,
+        10,
+        2,
+        vec![
+            AVP::MessageType(MessageType::IncomingCallConnected),
+            AVP::TxConnectSpeed(TxConnectSpeed { value: 1_000_000 }),
+        ],
+    );
+    let message = receive(
+        &mut tunnel,
+        10,
+        3,
+        vec![
+            AVP::MessageType(MessageType::WanErrorNotify),
+            AVP::CallErrors(CallErrors {
+                crc_errors: 5,
+                ..Default::default()
+            }),
+        ],
+    );
+    tunnel
+        .session_mut(10)
+        .unwrap()
+        .handle(&message, Instant::now())
+        .unwrap();
+    tunnel
+}
+
+fn if_index(interface: Interface) -> u32 {
+    match interface {
+        Interface::Tunnel(tunnel_id) => 100 + tunnel_id as u32,
+        Interface::Session(_, session_id) => 1000 + session_id as u32,
+    }
+}
+
+#[test]
+fn oid() {
+    assert_eq!(
+        Table::TunnelStats.column(TunnelStatsRow::STATE, &[101]),
+        Oid(vec![1, 3, 6, 1, 2, 1, 10, 139, 1, 5, 1, 3, 101])
+    );
+    assert_eq!(
+        Table::DomainStats.oid().to_string(),
+        "1.3.6.1.2.1.10.139.1.3"
+    );
+    assert_eq!(string_index(b"ab"), vec![2, 97, 98]);
+
+    let oid = Table::SessionStats.column(SessionStatsRow::CRC_ERRORS, &[1010]);
+    assert_eq!(
+        Table::parse(&oid),
+        Some((
+            Table::SessionStats,
+            SessionStatsRow::CRC_ERRORS,
+            &[1010][..]
+        ))
+    );
+    assert_eq!(Table::parse(&Table::SessionStats.entry()), None);
+    assert_eq!(Table::parse(&Oid(L2TP_MIB.to_vec())), None);
+}
+
+#[test]
+fn peer_info() {
+    let tunnel = tunnel();
+    let peer = tunnel.peer();
+    assert_eq!(peer.host_name.as_deref(), Some(&b"lac"[..]));
+    assert_eq!(peer.vendor_name.as_deref(), Some("Vendor"));
+    assert_eq!(peer.firmware_revision, Some(0x0102));
+    assert_eq!(
+        tunnel.session(10).unwrap().tx_connect_speed(),
+        Some(1_000_000)
+    );
+    assert_eq!(tunnel.session(10).unwrap().rx_connect_speed(), None);
+}
+
+#[test]
+fn tunnel_stats() {
+    let row = TunnelStatsRow::new(101, &tunnel().snapshot());
+    let varbinds = row.varbinds();
+    let get = |column| {
+        let oid = Table::TunnelStats.column(column, &[101]);
+        varbinds
+            .iter()
+            .find(|(x, _)| *x == oid)
+            .map(|(_, value)| value.clone())
+    };
+
+    assert_eq!(get(TunnelStatsRow::LOCAL_TID), Some(MibValue::Integer(1)));
+    assert_eq!(get(TunnelStatsRow::REMOTE_TID), Some(MibValue::Integer(2)));
+    assert_eq!(get(TunnelStatsRow::STATE), Some(MibValue::Integer(3)));
+    assert_eq!(
+        get(TunnelStatsRow::REMOTE_HOST_NAME),
+        Some(MibValue::OctetString(b"lac".to_vec()))
+    );
+    assert_eq!(
+        get(TunnelStatsRow::REMOTE_PROTOCOL_VERS),
+        Some(MibValue::OctetString(vec![1, 0]))
+    );
+    assert_eq!(get(TunnelStatsRow::BEARER_CAPS), Some(MibValue::Integer(4)));
+    assert_eq!(
+        get(TunnelStatsRow::FRAMING_CAPS),
+        Some(MibValue::Integer(4))
+    );
+    assert_eq!(
+        get(TunnelStatsRow::CONTROL_RX_PKTS),
+        Some(MibValue::Counter32(4))
+    );
+    assert_eq!(
+        get(TunnelStatsRow::ACTIVE_SESSIONS),
+        Some(MibValue::Gauge32(1))
+    );
+
+    // Absent peer information is omitted
+    let row = TunnelStatsRow::new(101, &Tunnel::new(1, 2).snapshot());
+    assert!(row
+        .columns()
+        .iter()
+        .all(|(column, _)| *column != TunnelStatsRow::REMOTE_HOST_NAME));
+}
+
+#[test]
+fn view() {
+    let snapshots = [tunnel().snapshot(), Tunnel::new(3, 4).snapshot()];
+    let view = MibView::new("default", &snapshots, if_index);
+
+    assert_eq!(view.domain.active_tunnels, 1);
+    assert_eq!(view.domain.active_sessions, 1);
+    assert_eq!(view.domain.control_rx_packets, 4);
+    assert_eq!(view.tunnel_stats.len(), 2);
+    assert_eq!(view.session_stats.len(), 1);
+
+    let session_index = [1010];
+    assert_eq!(
+        view.get(&Table::SessionStats.column(SessionStatsRow::TUNNEL_IF_INDEX, &session_index)),
+        Some(&MibValue::Integer(101))
+    );
+    assert_eq!(
+        view.get(&Table::SessionStats.column(SessionStatsRow::TX_CONNECT_SPEED, &session_index)),
+        Some(&MibValue::Gauge32(1_000_000))
+    );
+    assert_eq!(
+        view.get(&Table::SessionStats.column(SessionStatsRow::CRC_ERRORS, &session_index)),
+        Some(&MibValue::Counter32(5))
+    );
+    assert_eq!(
+        view.get(&Table::SessionStats.column(SessionStatsRow::RX_CONNECT_SPEED, &session_index)),
+        None
+    );
+
+    let mut index = string_index(b"default");
+    index.insert(0, DomainRow::ACTIVE_TUNNELS);
+    assert_eq!(
+        view.get(&Table::DomainStats.entry().child(&index)),
+        Some(&MibValue::Gauge32(1))
+    );
+
+    // Walking with GetNext visits every instance in order
+    let mut oid = Oid(L2TP_MIB.to_vec());
+    let mut walked = Vec::new();
+    while let Some((next, _)) = view.get_next(&oid) {
+        assert!(*next > oid);
+        oid = next.clone();
+        walked.push(oid.clone());
+    }
+    assert_eq!(walked.len(), view.walk().count());
+    let mut config_index = string_index(b"default");
+    config_index.insert(0, DomainConfigRow::ADMIN_STATE);
+    assert_eq!(
+        walked.first(),
+        Some(&Table::DomainConfig.entry().child(&config_index))
+    );
+    assert!(walked.contains(&Table::DomainStats.entry().child(&index)));
+    assert!(walked
+        .iter()
+        .all(|x| x.starts_with(&Oid(L2TP_MIB.to_vec()))));
+}
+
+#[test]
+fn tunnel_config() {
+    let snapshots = [tunnel().snapshot()];
+    let mut view = MibView::new("default", &snapshots, if_index);
+    let oid = Table::TunnelConfig.column(TunnelConfigRow::HELLO_INTERVAL, &[101]);
+    assert_eq!(view.get(&oid), Some(&MibValue::Integer(60)));
+
+    view.tunnel_configs[0].hello_interval = 30;
+    view.update();
+    assert_eq!(view.get(&oid), Some(&MibValue::Integer(30)));
+    assert_eq!(
+        view.get(&Table::TunnelConfig.column(TunnelConfigRow::DOMAIN_ID, &[101])),
+        Some(&MibValue::OctetString(b"default".to_vec()))
+    );
+}
+
+#[test]
+fn domain_config() {
+    let mut view = MibView::new("default", &[], if_index);
+    let index = string_index(b"default");
+    let oid = Table::DomainConfig.column(DomainConfigRow::DRAIN_TUNNELS, &index);
+    assert_eq!(view.get(&oid), Some(&MibValue::Integer(2)));
+    assert_eq!(
+        view.get(&Table::DomainConfig.column(DomainConfigRow::ADMIN_STATE, &index)),
+        Some(&MibValue::Integer(1))
+    );
+    assert_eq!(
+        view.get(&Table::DomainConfig.column(DomainConfigRow::CONTROL_RWS, &index)),
+        Some(&MibValue::Integer(4))
+    );
+
+    view.domain_config.drain_tunnels = true;
+    view.update();
+    assert_eq!(view.get(&oid), Some(&MibValue::Integer(1)));
+}
+
+#[test]
+fn counter_wrap() {
+    assert_eq!(MibValue::counter(5), MibValue::Counter32(5));
+    assert_eq!(
+        MibValue::counter(u32::MAX as u64 + 6),
+        MibValue::Counter32(5)
+    );
+}
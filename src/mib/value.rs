@@ -0,0 +1,31 @@
+/// # Summary
+/// The value of a MIB object, tagged with its SMIv2 type.
+///
+/// Counters are `Counter32` values and wrap around as mandated by SMIv2.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MibValue {
+    Integer(i32),
+    OctetString(Vec<u8>),
+    Gauge32(u32),
+    Counter32(u32),
+}
+
+impl MibValue {
+    /// # Summary
+    /// Create a `Counter32` value from a 64-bit counter.
+    ///
+    /// Only the low 32 bits are kept, so the value wraps around to zero every 2^32 counts as a `Counter32` does.
+    /// Managers detect the wrap by the value decreasing, which requires polling the fast-moving octet counters more
+    /// often than they can wrap.
+    #[inline]
+    pub fn counter(value: u64) -> Self {
+        MibValue::Counter32(value as u32)
+    }
+
+    /// # Summary
+    /// Create a `Gauge32` value, saturating at the maximum value.
+    #[inline]
+    pub fn gauge(value: impl TryInto<u32>) -> Self {
+        MibValue::Gauge32(value.try_into().unwrap_or(u32::MAX))
+    }
+}
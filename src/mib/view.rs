@@ -0,0 +1,124 @@
+use crate::mib::{
+    DomainConfigRow, DomainRow, MibValue, Oid, Row, SessionStatsRow, TunnelConfigRow,
+    TunnelStatsRow,
+};
+use crate::tunnel::TunnelSnapshot;
+
+/// # Summary
+/// The interface an L2TP tunnel or session is exposed as, for assigning `ifIndex` values.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Interface {
+    /// A tunnel by its locally assigned identifier.
+    Tunnel(u16),
+    /// A session by the locally assigned identifiers of its tunnel and itself.
+    Session(u16, u16),
+}
+
+/// # Summary
+/// A point-in-time view of the L2TP MIB tables of a single domain, as served by an SNMP agent.
+///
+/// The object instances are kept sorted by object identifier, so `get` and `get_next` map directly to the SNMP
+/// `Get` and `GetNext` operations.
+///
+/// # Data members
+/// * `domain_config` - The `l2tpDomainConfigTable` row of the domain.
+/// * `domain` - The `l2tpDomainStatsTable` row of the domain.
+/// * `tunnel_configs` - The `l2tpTunnelConfigTable` rows.
+/// * `tunnel_stats` - The `l2tpTunnelStatsTable` rows.
+/// * `session_stats` - The `l2tpSessionStatsTable` rows.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MibView {
+    pub domain_config: DomainConfigRow,
+    pub domain: DomainRow,
+    pub tunnel_configs: Vec<TunnelConfigRow>,
+    pub tunnel_stats: Vec<TunnelStatsRow>,
+    pub session_stats: Vec<SessionStatsRow>,
+    varbinds: Vec<(Oid, MibValue)>,
+}
+
+impl MibView {
+    /// # Summary
+    /// Create a `MibView` from the snapshots of the tunnels of a domain.
+    ///
+    /// # Parameters
+    /// * `domain_id` - The `l2tpDomainConfigId` of the domain.
+    /// * `snapshots` - The snapshots of the tunnels of the domain.
+    /// * `if_index` - Assigns the `ifIndex` of each tunnel and session interface.
+    pub fn new(
+        domain_id: &str,
+        snapshots: &[TunnelSnapshot],
+        mut if_index: impl FnMut(Interface) -> u32,
+    ) -> Self {
+        let mut tunnel_configs = Vec::with_capacity(snapshots.len());
+        let mut tunnel_stats = Vec::with_capacity(snapshots.len());
+        let mut session_stats = Vec::new();
+        for snapshot in snapshots.iter() {
+            let tunnel_if_index = if_index(Interface::Tunnel(snapshot.tunnel_id));
+            tunnel_configs.push(TunnelConfigRow::new(tunnel_if_index, domain_id));
+            tunnel_stats.push(TunnelStatsRow::new(tunnel_if_index, snapshot));
+            for (session_id, session) in snapshot.sessions.iter() {
+                session_stats.push(SessionStatsRow::new(
+                    if_index(Interface::Session(snapshot.tunnel_id, *session_id)),
+                    tunnel_if_index,
+                    *session_id,
+                    session,
+                ));
+            }
+        }
+
+        let mut view = Self {
+            domain_config: DomainConfigRow::new(domain_id),
+            domain: DomainRow::new(domain_id, snapshots),
+            tunnel_configs,
+            tunnel_stats,
+            session_stats,
+            varbinds: Vec::new(),
+        };
+        view.update();
+        view
+    }
+
+    /// # Summary
+    /// Rebuild the object instances after modifying the rows of this view, e.g. to set domain or tunnel
+    /// configuration.
+    pub fn update(&mut self) {
+        let mut varbinds = self.domain_config.varbinds();
+        varbinds.extend(self.domain.varbinds());
+        self.tunnel_configs
+            .iter()
+            .for_each(|row| varbinds.extend(row.varbinds()));
+        self.tunnel_stats
+            .iter()
+            .for_each(|row| varbinds.extend(row.varbinds()));
+        self.session_stats
+            .iter()
+            .for_each(|row| varbinds.extend(row.varbinds()));
+        varbinds.sort_by(|a, b| a.0.cmp(&b.0));
+        self.varbinds = varbinds;
+    }
+
+    /// # Summary
+    /// Get the value of an object instance.
+    #[inline]
+    pub fn get(&self, oid: &Oid) -> Option<&MibValue> {
+        self.varbinds
+            .binary_search_by(|(x, _)| x.cmp(oid))
+            .ok()
+            .map(|i| &self.varbinds[i].1)
+    }
+
+    /// # Summary
+    /// Get the first object instance following `oid` in lexicographic order.
+    #[inline]
+    pub fn get_next(&self, oid: &Oid) -> Option<(&Oid, &MibValue)> {
+        let i = self.varbinds.partition_point(|(x, _)| x <= oid);
+        self.varbinds.get(i).map(|(x, value)| (x, value))
+    }
+
+    /// # Summary
+    /// Get an iterator over all object instances in lexicographic order.
+    #[inline]
+    pub fn walk(&self) -> impl Iterator<Item = (&Oid, &MibValue)> {
+        self.varbinds.iter().map(|(x, value)| (x, value))
+    }
+}
@@ -0,0 +1,31 @@
+//! Types and implementations related to PPP frames carried in L2TP data messages.
+
+#[cfg(test)]
+mod tests;
+
+mod protocol;
+pub use protocol::*;
+
+mod frame;
+pub use frame::*;
+
+mod control_packet;
+pub use control_packet::*;
+
+mod packet;
+pub use packet::*;
+
+mod lcp_option;
+pub use lcp_option::*;
+
+mod proxy_lcp;
+pub use proxy_lcp::*;
+
+mod multilink;
+pub use multilink::*;
+
+mod fcs;
+pub use fcs::*;
+
+mod hdlc;
+pub use hdlc::*;
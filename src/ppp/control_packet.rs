@@ -0,0 +1,81 @@
+use crate::common::{DecodeError, DecodeResult, Reader, Writer};
+use core::borrow::Borrow;
+
+/// # Summary
+/// A `ControlPacket` is a representation of the packet format shared by PPP control protocols such as LCP, IPCP,
+/// IPV6CP, PAP and CHAP.
+///
+/// # Data members
+/// * `code` - The code field, whose meaning depends on the control protocol.
+/// * `identifier` - The identifier field used for matching requests and replies.
+/// * `data` - The data field, excluding any padding following the packet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ControlPacket<T> {
+    pub code: u8,
+    pub identifier: u8,
+    pub data: T,
+}
+
+impl<T> ControlPacket<T>
+where
+    T: Borrow<[u8]>,
+{
+    pub const HEADER_LENGTH: usize = 4;
+
+    /// # Summary
+    /// Attempt to read a `ControlPacket` using a `Reader`.
+    ///
+    /// Any padding following the packet as indicated by its length field is skipped.
+    #[inline]
+    pub fn try_read(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        // Note: Subsequent unsafe code depends on this check
+        if reader.len() < Self::HEADER_LENGTH {
+            return Err(DecodeError::IncompletePppControlPacket);
+        }
+
+        let code = unsafe { reader.read_u8_unchecked() };
+        let identifier = unsafe { reader.read_u8_unchecked() };
+        let length = unsafe { reader.read_u16_be_unchecked() };
+
+        if (length as usize) < Self::HEADER_LENGTH {
+            return Err(DecodeError::InvalidPppControlPacketLength(length));
+        }
+        let data_length = length as usize - Self::HEADER_LENGTH;
+        if data_length > reader.len() {
+            return Err(DecodeError::IncompletePppControlPacket);
+        }
+
+        let data = reader
+            .bytes(data_length)
+            .ok_or(DecodeError::PppFrameReadError)?;
+
+        // Skip padding
+        reader.skip_bytes(reader.len());
+
+        Ok(Self {
+            code,
+            identifier,
+            data,
+        })
+    }
+
+    /// # Summary
+    /// Get the encoded length of this `ControlPacket`.
+    #[inline]
+    pub fn get_length(&self) -> usize {
+        Self::HEADER_LENGTH + self.data.borrow().len()
+    }
+
+    /// # Summary
+    /// Write a `ControlPacket` using a `Writer`.
+    #[inline]
+    pub fn write(&self, writer: &mut impl Writer) {
+        let length = self.get_length();
+        assert!(length <= u16::MAX as usize);
+
+        writer.write_u8(self.code);
+        writer.write_u8(self.identifier);
+        writer.write_u16_be(length as u16);
+        writer.write_bytes(self.data.borrow());
+    }
+}
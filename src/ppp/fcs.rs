@@ -0,0 +1,104 @@
+/// # Summary
+/// The frame check sequence algorithms of RFC 1662.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Fcs {
+    #[default]
+    Fcs16,
+    Fcs32,
+}
+
+const FCS16_INITIAL: u16 = 0xffff;
+const FCS16_GOOD: u16 = 0xf0b8;
+const FCS16_POLYNOMIAL: u16 = 0x8408;
+
+const FCS32_INITIAL: u32 = 0xffffffff;
+const FCS32_GOOD: u32 = 0xdebb20e3;
+const FCS32_POLYNOMIAL: u32 = 0xedb88320;
+
+const fn fcs16_table() -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u16;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 != 0 {
+                (value >> 1) ^ FCS16_POLYNOMIAL
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+}
+
+const fn fcs32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 != 0 {
+                (value >> 1) ^ FCS32_POLYNOMIAL
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+}
+
+static FCS16_TABLE: [u16; 256] = fcs16_table();
+static FCS32_TABLE: [u32; 256] = fcs32_table();
+
+fn fcs16_update(fcs: u16, data: &[u8]) -> u16 {
+    data.iter().fold(fcs, |fcs, x| {
+        (fcs >> 8) ^ FCS16_TABLE[((fcs ^ *x as u16) & 0xff) as usize]
+    })
+}
+
+fn fcs32_update(fcs: u32, data: &[u8]) -> u32 {
+    data.iter().fold(fcs, |fcs, x| {
+        (fcs >> 8) ^ FCS32_TABLE[((fcs ^ *x as u32) & 0xff) as usize]
+    })
+}
+
+impl Fcs {
+    /// # Summary
+    /// Get the length of the frame check sequence in octets.
+    #[inline]
+    pub fn get_length(&self) -> usize {
+        match self {
+            Fcs::Fcs16 => 2,
+            Fcs::Fcs32 => 4,
+        }
+    }
+
+    /// # Summary
+    /// Compute the frame check sequence of `data` in transmission order.
+    pub fn compute(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Fcs::Fcs16 => (!fcs16_update(FCS16_INITIAL, data)).to_le_bytes().to_vec(),
+            Fcs::Fcs32 => (!fcs32_update(FCS32_INITIAL, data)).to_le_bytes().to_vec(),
+        }
+    }
+
+    /// # Summary
+    /// Check whether `data` ends with a valid frame check sequence.
+    pub fn is_valid(&self, data: &[u8]) -> bool {
+        if data.len() < self.get_length() {
+            return false;
+        }
+        match self {
+            Fcs::Fcs16 => fcs16_update(FCS16_INITIAL, data) == FCS16_GOOD,
+            Fcs::Fcs32 => fcs32_update(FCS32_INITIAL, data) == FCS32_GOOD,
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::common::{DecodeError, DecodeResult, Reader, SliceReader, Writer};
+use crate::ppp::{Packet, Protocol};
+use core::borrow::Borrow;
+
+/// # Summary
+/// A `Frame` is a representation of a PPP frame as carried in the payload of an L2TP `DataMessage`.
+///
+/// # Data members
+/// * `protocol` - The protocol field.
+/// * `payload` - The information field, including any padding.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame<T> {
+    pub protocol: Protocol,
+    pub payload: T,
+}
+
+/// # Summary
+/// The header field compression options negotiated by LCP, used when writing a `Frame`.
+///
+/// # Data members
+/// * `address_control` - Omit the address and control fields (ACFC).
+/// * `protocol` - Use a single octet protocol field where possible (PFC).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Compression {
+    pub address_control: bool,
+    pub protocol: bool,
+}
+
+impl<T> Frame<T>
+where
+    T: Borrow<[u8]>,
+{
+    const ADDRESS: u8 = 0xff;
+    const CONTROL: u8 = 0x03;
+
+    /// # Summary
+    /// Attempt to read a `Frame` using a `Reader`, consuming all remaining data.
+    ///
+    /// Both compressed and uncompressed address, control and protocol fields are accepted.
+    #[inline]
+    pub fn try_read(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        // Note: Subsequent unsafe code depends on these checks
+        if reader.is_empty() {
+            return Err(DecodeError::IncompletePppFrame);
+        }
+        let mut octet = unsafe { reader.read_u8_unchecked() };
+
+        // The address field can't be mistaken for a protocol field since the latter must start with an even octet
+        if octet == Self::ADDRESS {
+            if reader.len() < 2 {
+                return Err(DecodeError::IncompletePppFrame);
+            }
+            let control = unsafe { reader.read_u8_unchecked() };
+            if control != Self::CONTROL {
+                return Err(DecodeError::InvalidPppControlField(control));
+            }
+            octet = unsafe { reader.read_u8_unchecked() };
+        }
+
+        // A protocol field with an odd first octet has been compressed to a single octet
+        let protocol_value = if octet & 0x1 != 0 {
+            octet as u16
+        } else {
+            if reader.is_empty() {
+                return Err(DecodeError::IncompletePppFrame);
+            }
+            let lsb = unsafe { reader.read_u8_unchecked() };
+            ((octet as u16) << 8) | lsb as u16
+        };
+
+        let protocol = Protocol::from(protocol_value);
+        if !protocol.is_valid() {
+            return Err(DecodeError::InvalidPppProtocol(protocol_value));
+        }
+
+        let payload = reader
+            .bytes(reader.len())
+            .ok_or(DecodeError::PppFrameReadError)?;
+
+        Ok(Self { protocol, payload })
+    }
+
+    /// # Summary
+    /// Decode the payload of this `Frame` according to its protocol without copying it.
+    #[inline]
+    pub fn packet(&self) -> DecodeResult<Packet<&[u8]>> {
+        let mut reader = SliceReader::from(self.payload.borrow());
+        Packet::try_read(self.protocol, &mut reader)
+    }
+
+    /// # Summary
+    /// Write a `Frame` using a `Writer`, applying the given header field `Compression`.
+    ///
+    /// Frames of LCP are never compressed, as mandated by RFC 1661.
+    #[inline]
+    pub fn write(&self, compression: Compression, writer: &mut impl Writer) {
+        let is_lcp = self.protocol == Protocol::Lcp;
+
+        if !compression.address_control || is_lcp {
+            writer.write_u8(Self::ADDRESS);
+            writer.write_u8(Self::CONTROL);
+        }
+
+        let protocol_value = u16::from(self.protocol);
+        if compression.protocol && !is_lcp && protocol_value < 0x100 {
+            writer.write_u8(protocol_value as u8);
+        } else {
+            writer.write_u16_be(protocol_value);
+        }
+
+        writer.write_bytes(self.payload.borrow());
+    }
+}
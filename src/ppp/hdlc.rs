@@ -0,0 +1,195 @@
+use crate::avp::types::Accm;
+use crate::common::{DecodeError, DecodeResult, Writer};
+use crate::ppp::Fcs;
+
+const FLAG: u8 = 0x7e;
+const ESCAPE: u8 = 0x7d;
+const ESCAPE_MASK: u8 = 0x20;
+
+/// The default ACCM, which escapes all control characters.
+pub const DEFAULT_ACCM: u32 = 0xffffffff;
+
+/// The default maximum length of a deframed frame, corresponding to the default MRU of 1500 with the largest header.
+pub const DEFAULT_MAXIMUM_FRAME_LENGTH: usize = 1500 + 8;
+
+#[inline]
+fn is_mapped(accm: u32, octet: u8) -> bool {
+    octet < 0x20 && accm & (1 << octet) != 0
+}
+
+/// # Summary
+/// An `HdlcEncoder` converts PPP frames as carried in a `DataMessage` to an async HDLC-like framed octet stream
+/// (RFC 1662 section 4).
+///
+/// # Data members
+/// * `accm` - The async control character map of control characters to escape.
+/// * `fcs` - The frame check sequence to append.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HdlcEncoder {
+    pub accm: u32,
+    pub fcs: Fcs,
+}
+
+impl Default for HdlcEncoder {
+    fn default() -> Self {
+        Self {
+            accm: DEFAULT_ACCM,
+            fcs: Fcs::default(),
+        }
+    }
+}
+
+impl HdlcEncoder {
+    /// # Summary
+    /// Create an `HdlcEncoder` using the send ACCM of an `Accm` AVP.
+    #[inline]
+    pub fn from_accm(accm: &Accm, fcs: Fcs) -> Self {
+        Self {
+            accm: u32::from_be_bytes(accm.send_accm),
+            fcs,
+        }
+    }
+
+    fn write_stuffed(&self, data: &[u8], writer: &mut impl Writer) {
+        for octet in data.iter().copied() {
+            if octet == FLAG || octet == ESCAPE || is_mapped(self.accm, octet) {
+                writer.write_u8(ESCAPE);
+                writer.write_u8(octet ^ ESCAPE_MASK);
+            } else {
+                writer.write_u8(octet);
+            }
+        }
+    }
+
+    /// # Summary
+    /// Write a PPP `frame` delimited by flag sequences and followed by its frame check sequence.
+    pub fn encode(&self, frame: &[u8], writer: &mut impl Writer) {
+        writer.write_u8(FLAG);
+        self.write_stuffed(frame, writer);
+        self.write_stuffed(&self.fcs.compute(frame), writer);
+        writer.write_u8(FLAG);
+    }
+}
+
+/// # Summary
+/// An `HdlcDecoder` converts an async HDLC-like framed octet stream (RFC 1662 section 4) to PPP frames as carried in a
+/// `DataMessage`.
+///
+/// Data may be supplied in arbitrarily sized chunks; partial frames are retained between calls to `decode`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HdlcDecoder {
+    accm: u32,
+    fcs: Fcs,
+    maximum_frame_length: usize,
+    buffer: Vec<u8>,
+    is_escaped: bool,
+    is_oversize: bool,
+}
+
+impl Default for HdlcDecoder {
+    fn default() -> Self {
+        Self::new(DEFAULT_ACCM, Fcs::default())
+    }
+}
+
+impl HdlcDecoder {
+    /// # Summary
+    /// Create an `HdlcDecoder` using a receive ACCM and frame check sequence.
+    ///
+    /// Unescaped control characters present in the receive ACCM are discarded, as they may have been inserted by
+    /// intermediate equipment.
+    #[inline]
+    pub fn new(accm: u32, fcs: Fcs) -> Self {
+        Self {
+            accm,
+            fcs,
+            maximum_frame_length: DEFAULT_MAXIMUM_FRAME_LENGTH,
+            buffer: Vec::new(),
+            is_escaped: false,
+            is_oversize: false,
+        }
+    }
+
+    /// # Summary
+    /// Create an `HdlcDecoder` using the receive ACCM of an `Accm` AVP.
+    #[inline]
+    pub fn from_accm(accm: &Accm, fcs: Fcs) -> Self {
+        Self::new(u32::from_be_bytes(accm.receive_accm), fcs)
+    }
+
+    /// # Summary
+    /// Set the maximum length of a frame, excluding its frame check sequence.
+    #[inline]
+    pub fn with_maximum_frame_length(mut self, length: usize) -> Self {
+        self.maximum_frame_length = length;
+        self
+    }
+
+    /// # Summary
+    /// Update the receive ACCM, e.g. after receiving a `SetLinkInfo` message.
+    #[inline]
+    pub fn set_accm(&mut self, accm: u32) {
+        self.accm = accm;
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.is_escaped = false;
+        self.is_oversize = false;
+    }
+
+    fn finish_frame(&mut self) -> Option<DecodeResult<Vec<u8>>> {
+        if self.is_escaped {
+            self.reset();
+            return Some(Err(DecodeError::HdlcFrameAborted));
+        }
+        if self.is_oversize {
+            self.reset();
+            return Some(Err(DecodeError::OversizeHdlcFrame(
+                self.maximum_frame_length,
+            )));
+        }
+        // Consecutive flags delimit empty frames, which are ignored
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        let mut frame = core::mem::take(&mut self.buffer);
+        self.reset();
+
+        if frame.len() <= self.fcs.get_length() {
+            return Some(Err(DecodeError::IncompleteHdlcFrame));
+        }
+        if !self.fcs.is_valid(&frame) {
+            return Some(Err(DecodeError::InvalidHdlcFcs));
+        }
+        frame.truncate(frame.len() - self.fcs.get_length());
+
+        Some(Ok(frame))
+    }
+
+    /// # Summary
+    /// Decode a chunk of the octet stream, returning every frame completed by it.
+    pub fn decode(&mut self, data: &[u8]) -> Vec<DecodeResult<Vec<u8>>> {
+        let mut frames = Vec::new();
+        for octet in data.iter().copied() {
+            match octet {
+                FLAG => frames.extend(self.finish_frame()),
+                ESCAPE => self.is_escaped = true,
+                x if is_mapped(self.accm, x) => (),
+                mut x => {
+                    if self.is_escaped {
+                        x ^= ESCAPE_MASK;
+                        self.is_escaped = false;
+                    }
+                    if self.buffer.len() < self.maximum_frame_length + self.fcs.get_length() {
+                        self.buffer.push(x);
+                    } else {
+                        self.is_oversize = true;
+                    }
+                }
+            }
+        }
+        frames
+    }
+}
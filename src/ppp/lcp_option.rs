@@ -0,0 +1,246 @@
+use crate::common::{DecodeError, DecodeResult, Reader, SliceReader, Writer};
+use crate::ppp::Protocol;
+use core::borrow::Borrow;
+
+/// # Summary
+/// The endpoint discriminator of a multilink PPP peer as described in RFC 1990, identifying the system links
+/// belong to.
+///
+/// # Data members
+/// * `class` - The class of the address, e.g. 1 for a locally assigned address or 3 for an IEEE 802.1 MAC address.
+/// * `address` - The address of the system.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EndpointDiscriminator {
+    pub class: u8,
+    pub address: Vec<u8>,
+}
+
+/// # Summary
+/// An `LcpOption` is a representation of a single LCP Configure-Request option as described in RFC 1661.
+///
+/// Options that aren't explicitly supported are preserved as `Other`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LcpOption {
+    MaximumReceiveUnit(u16),
+    AsyncControlCharacterMap(u32),
+    AuthenticationProtocol { protocol: Protocol, data: Vec<u8> },
+    QualityProtocol { protocol: u16, data: Vec<u8> },
+    MagicNumber(u32),
+    ProtocolFieldCompression,
+    AddressAndControlFieldCompression,
+    MaximumReceiveReconstructedUnit(u16),
+    ShortSequenceNumberHeaderFormat,
+    EndpointDiscriminator(EndpointDiscriminator),
+    Other { option_type: u8, data: Vec<u8> },
+}
+
+use LcpOption::*;
+
+impl LcpOption {
+    const MAXIMUM_RECEIVE_UNIT: u8 = 1;
+    const ASYNC_CONTROL_CHARACTER_MAP: u8 = 2;
+    const AUTHENTICATION_PROTOCOL: u8 = 3;
+    const QUALITY_PROTOCOL: u8 = 4;
+    const MAGIC_NUMBER: u8 = 5;
+    const PROTOCOL_FIELD_COMPRESSION: u8 = 7;
+    const ADDRESS_AND_CONTROL_FIELD_COMPRESSION: u8 = 8;
+    const MAXIMUM_RECEIVE_RECONSTRUCTED_UNIT: u8 = 17;
+    const SHORT_SEQUENCE_NUMBER_HEADER_FORMAT: u8 = 18;
+    const ENDPOINT_DISCRIMINATOR: u8 = 19;
+
+    const HEADER_LENGTH: usize = 2;
+
+    /// # Summary
+    /// Attempt to read a single `LcpOption` using a `Reader`.
+    pub fn try_read<T: Borrow<[u8]>>(reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        // Note: Subsequent unsafe code depends on these checks
+        if reader.len() < Self::HEADER_LENGTH {
+            return Err(DecodeError::IncompleteLcpOption);
+        }
+        let option_type = unsafe { reader.read_u8_unchecked() };
+        let length = unsafe { reader.read_u8_unchecked() };
+
+        if (length as usize) < Self::HEADER_LENGTH {
+            return Err(DecodeError::InvalidLcpOptionLength(option_type, length));
+        }
+        let data_length = length as usize - Self::HEADER_LENGTH;
+        if data_length > reader.len() {
+            return Err(DecodeError::IncompleteLcpOption);
+        }
+
+        let expect_length = |expected: usize| {
+            if data_length == expected {
+                Ok(())
+            } else {
+                Err(DecodeError::InvalidLcpOptionLength(option_type, length))
+            }
+        };
+        let expect_minimum_length = |minimum: usize| {
+            if data_length >= minimum {
+                Ok(())
+            } else {
+                Err(DecodeError::InvalidLcpOptionLength(option_type, length))
+            }
+        };
+
+        let option = match option_type {
+            Self::MAXIMUM_RECEIVE_UNIT => {
+                expect_length(2)?;
+                MaximumReceiveUnit(unsafe { reader.read_u16_be_unchecked() })
+            }
+            Self::ASYNC_CONTROL_CHARACTER_MAP => {
+                expect_length(4)?;
+                AsyncControlCharacterMap(unsafe { reader.read_u32_be_unchecked() })
+            }
+            Self::AUTHENTICATION_PROTOCOL => {
+                expect_minimum_length(2)?;
+                let protocol = unsafe { reader.read_u16_be_unchecked() }.into();
+                AuthenticationProtocol {
+                    protocol,
+                    data: Self::read_data(reader, data_length - 2)?,
+                }
+            }
+            Self::QUALITY_PROTOCOL => {
+                expect_minimum_length(2)?;
+                let protocol = unsafe { reader.read_u16_be_unchecked() };
+                QualityProtocol {
+                    protocol,
+                    data: Self::read_data(reader, data_length - 2)?,
+                }
+            }
+            Self::MAGIC_NUMBER => {
+                expect_length(4)?;
+                MagicNumber(unsafe { reader.read_u32_be_unchecked() })
+            }
+            Self::PROTOCOL_FIELD_COMPRESSION => {
+                expect_length(0)?;
+                ProtocolFieldCompression
+            }
+            Self::ADDRESS_AND_CONTROL_FIELD_COMPRESSION => {
+                expect_length(0)?;
+                AddressAndControlFieldCompression
+            }
+            Self::MAXIMUM_RECEIVE_RECONSTRUCTED_UNIT => {
+                expect_length(2)?;
+                MaximumReceiveReconstructedUnit(unsafe { reader.read_u16_be_unchecked() })
+            }
+            Self::SHORT_SEQUENCE_NUMBER_HEADER_FORMAT => {
+                expect_length(0)?;
+                ShortSequenceNumberHeaderFormat
+            }
+            Self::ENDPOINT_DISCRIMINATOR => {
+                expect_minimum_length(1)?;
+                let class = unsafe { reader.read_u8_unchecked() };
+                EndpointDiscriminator(self::EndpointDiscriminator {
+                    class,
+                    address: Self::read_data(reader, data_length - 1)?,
+                })
+            }
+            option_type => Other {
+                option_type,
+                data: Self::read_data(reader, data_length)?,
+            },
+        };
+
+        Ok(option)
+    }
+
+    fn read_data<T: Borrow<[u8]>>(
+        reader: &mut impl Reader<T>,
+        length: usize,
+    ) -> DecodeResult<Vec<u8>> {
+        Ok(reader
+            .bytes(length)
+            .ok_or(DecodeError::IncompleteLcpOption)?
+            .borrow()
+            .to_owned())
+    }
+
+    /// # Summary
+    /// Attempt to read a sequence of `LcpOption`s, such as the contents of a proxy LCP AVP.
+    pub fn try_read_all(data: &[u8]) -> DecodeResult<Vec<Self>> {
+        let mut reader = SliceReader::from(data);
+        let mut options = Vec::new();
+        while !reader.is_empty() {
+            options.push(Self::try_read(&mut reader)?);
+        }
+        Ok(options)
+    }
+
+    /// # Summary
+    /// Get the option type of this `LcpOption`.
+    pub fn get_option_type(&self) -> u8 {
+        match self {
+            MaximumReceiveUnit(_) => Self::MAXIMUM_RECEIVE_UNIT,
+            AsyncControlCharacterMap(_) => Self::ASYNC_CONTROL_CHARACTER_MAP,
+            AuthenticationProtocol { .. } => Self::AUTHENTICATION_PROTOCOL,
+            QualityProtocol { .. } => Self::QUALITY_PROTOCOL,
+            MagicNumber(_) => Self::MAGIC_NUMBER,
+            ProtocolFieldCompression => Self::PROTOCOL_FIELD_COMPRESSION,
+            AddressAndControlFieldCompression => Self::ADDRESS_AND_CONTROL_FIELD_COMPRESSION,
+            MaximumReceiveReconstructedUnit(_) => Self::MAXIMUM_RECEIVE_RECONSTRUCTED_UNIT,
+            ShortSequenceNumberHeaderFormat => Self::SHORT_SEQUENCE_NUMBER_HEADER_FORMAT,
+            EndpointDiscriminator(_) => Self::ENDPOINT_DISCRIMINATOR,
+            Other { option_type, .. } => *option_type,
+        }
+    }
+
+    /// # Summary
+    /// Get the encoded length of this `LcpOption`, including its type and length fields.
+    pub fn get_length(&self) -> usize {
+        Self::HEADER_LENGTH
+            + match self {
+                MaximumReceiveUnit(_) | MaximumReceiveReconstructedUnit(_) => 2,
+                AsyncControlCharacterMap(_) | MagicNumber(_) => 4,
+                AuthenticationProtocol { data, .. } | QualityProtocol { data, .. } => {
+                    2 + data.len()
+                }
+                ProtocolFieldCompression
+                | AddressAndControlFieldCompression
+                | ShortSequenceNumberHeaderFormat => 0,
+                EndpointDiscriminator(x) => 1 + x.address.len(),
+                Other { data, .. } => data.len(),
+            }
+    }
+
+    /// # Summary
+    /// Write an `LcpOption` using a `Writer`.
+    ///
+    /// # Panics
+    /// Panics if the encoded length of the option exceeds 255 octets.
+    pub fn write(&self, writer: &mut impl Writer) {
+        let length = self.get_length();
+        assert!(length <= u8::MAX as usize);
+
+        writer.write_u8(self.get_option_type());
+        writer.write_u8(length as u8);
+        match self {
+            MaximumReceiveUnit(x) | MaximumReceiveReconstructedUnit(x) => writer.write_u16_be(*x),
+            AsyncControlCharacterMap(x) | MagicNumber(x) => writer.write_u32_be(*x),
+            AuthenticationProtocol { protocol, data } => {
+                writer.write_u16_be((*protocol).into());
+                writer.write_bytes(data);
+            }
+            QualityProtocol { protocol, data } => {
+                writer.write_u16_be(*protocol);
+                writer.write_bytes(data);
+            }
+            ProtocolFieldCompression
+            | AddressAndControlFieldCompression
+            | ShortSequenceNumberHeaderFormat => (),
+            EndpointDiscriminator(x) => {
+                writer.write_u8(x.class);
+                writer.write_bytes(&x.address);
+            }
+            Other { data, .. } => writer.write_bytes(data),
+        }
+    }
+
+    /// # Summary
+    /// Write a sequence of `LcpOption`s using a `Writer`.
+    pub fn write_all(options: &[Self], writer: &mut impl Writer) {
+        for option in options.iter() {
+            option.write(writer);
+        }
+    }
+}
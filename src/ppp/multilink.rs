@@ -0,0 +1,150 @@
+use crate::common::{BundleError, BundleResult};
+use crate::ppp::{EndpointDiscriminator, ProxyLcp};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The authenticated name and endpoint discriminator identifying a bundle (RFC 1990 section 5.1.3).
+type BundleKey = (Vec<u8>, EndpointDiscriminator);
+
+/// # Summary
+/// A multilink PPP bundle (RFC 1990) made up of sessions of the same client.
+///
+/// Members are identified by the locally assigned identifiers of their tunnel and session.
+///
+/// # Data members
+/// * `authenticated_name` - The name the client authenticated with.
+/// * `endpoint_discriminator` - The endpoint discriminator of the client.
+/// * `mrru` - The maximum receive reconstructed unit requested by the client, the same on every link.
+/// * `short_sequence_numbers` - Whether the client requested the short sequence number header format.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bundle {
+    pub authenticated_name: Vec<u8>,
+    pub endpoint_discriminator: EndpointDiscriminator,
+    pub mrru: u16,
+    pub short_sequence_numbers: bool,
+    members: BTreeSet<(u16, u16)>,
+}
+
+impl Bundle {
+    /// # Summary
+    /// Get the tunnel and session identifiers of the links of this bundle.
+    #[inline]
+    pub fn members(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        self.members.iter().copied()
+    }
+}
+
+/// # Summary
+/// A `BundleRegistry` groups the sessions of an LNS into multilink PPP bundles, using the LCP state proxied by the
+/// LACs.
+///
+/// As in RFC 1990 section 5.1.3, links belong to the same bundle if their clients authenticated with the same name
+/// and sent the same endpoint discriminator. Links without an endpoint discriminator, or with the null class,
+/// can't be told apart from other links of the same user and are never bundled.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BundleRegistry {
+    bundles: BTreeMap<BundleKey, Bundle>,
+    links: BTreeMap<(u16, u16), BundleKey>,
+}
+
+impl BundleRegistry {
+    const NULL_CLASS: u8 = 0;
+
+    /// # Summary
+    /// Add a session to the bundle of its client, creating the bundle for the first link.
+    ///
+    /// Fails if the client didn't request multilink PPP, didn't identify itself with an endpoint discriminator, or
+    /// requested an MRRU or sequence number header format different from that of its bundle.
+    ///
+    /// # Parameters
+    /// * `tunnel_id` - The locally assigned identifier of the tunnel of the session.
+    /// * `session_id` - The locally assigned identifier of the session.
+    /// * `authenticated_name` - The name the client authenticated with, e.g. from its `ProxyAuthenName` AVP.
+    /// * `proxy_lcp` - The LCP state proxied by the LAC for the session.
+    pub fn join(
+        &mut self,
+        tunnel_id: u16,
+        session_id: u16,
+        authenticated_name: &[u8],
+        proxy_lcp: &ProxyLcp,
+    ) -> BundleResult<&Bundle> {
+        let client = &proxy_lcp.peer;
+        let mrru = client.mrru.ok_or(BundleError::NotMultilink)?;
+        let endpoint_discriminator = client
+            .endpoint_discriminator
+            .as_ref()
+            .filter(|x| x.class != Self::NULL_CLASS)
+            .ok_or(BundleError::MissingEndpointDiscriminator)?;
+
+        // Validate before leaving the current bundle, so a rejected join leaves the membership intact. A bundle
+        // whose only link is this session is replaced rather than joined.
+        let link = (tunnel_id, session_id);
+        let key = (authenticated_name.to_vec(), endpoint_discriminator.clone());
+        if let Some(bundle) = self
+            .bundles
+            .get(&key)
+            .filter(|x| x.members.iter().any(|member| *member != link))
+        {
+            if bundle.mrru != mrru {
+                return Err(BundleError::MrruMismatch(bundle.mrru, mrru));
+            }
+            if bundle.short_sequence_numbers != client.short_sequence_numbers {
+                return Err(BundleError::SequenceNumberFormatMismatch);
+            }
+        }
+
+        self.leave(tunnel_id, session_id);
+        self.links.insert(link, key.clone());
+        let bundle = self.bundles.entry(key).or_insert_with(|| Bundle {
+            authenticated_name: authenticated_name.to_vec(),
+            endpoint_discriminator: endpoint_discriminator.clone(),
+            mrru,
+            short_sequence_numbers: client.short_sequence_numbers,
+            members: BTreeSet::new(),
+        });
+        bundle.members.insert(link);
+        Ok(bundle)
+    }
+
+    /// # Summary
+    /// Remove a session from its bundle, removing the bundle along with its last link.
+    ///
+    /// Returns whether the session was a member of a bundle.
+    pub fn leave(&mut self, tunnel_id: u16, session_id: u16) -> bool {
+        let Some(key) = self.links.remove(&(tunnel_id, session_id)) else {
+            return false;
+        };
+        if let Some(bundle) = self.bundles.get_mut(&key) {
+            bundle.members.remove(&(tunnel_id, session_id));
+            if bundle.members.is_empty() {
+                self.bundles.remove(&key);
+            }
+        }
+        true
+    }
+
+    /// # Summary
+    /// Get the bundle of the client with the given authenticated name and endpoint discriminator.
+    #[inline]
+    pub fn bundle(
+        &self,
+        authenticated_name: &[u8],
+        endpoint_discriminator: &EndpointDiscriminator,
+    ) -> Option<&Bundle> {
+        self.bundles
+            .get(&(authenticated_name.to_vec(), endpoint_discriminator.clone()))
+    }
+
+    /// # Summary
+    /// Get the bundle a session is a link of.
+    #[inline]
+    pub fn bundle_of(&self, tunnel_id: u16, session_id: u16) -> Option<&Bundle> {
+        self.bundles.get(self.links.get(&(tunnel_id, session_id))?)
+    }
+
+    /// # Summary
+    /// Get an iterator over all bundles.
+    #[inline]
+    pub fn bundles(&self) -> impl Iterator<Item = &Bundle> {
+        self.bundles.values()
+    }
+}
@@ -0,0 +1,62 @@
+use crate::common::{DecodeError, DecodeResult, Reader};
+use crate::ppp::{ControlPacket, Protocol};
+use core::borrow::Borrow;
+
+/// # Summary
+/// A `Packet` is the decoded payload of a PPP `Frame`, dispatched on its `Protocol`.
+///
+/// Network layer payloads and payloads of unrecognized protocols are left undecoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Packet<T> {
+    Lcp(ControlPacket<T>),
+    Pap(ControlPacket<T>),
+    Chap(ControlPacket<T>),
+    Ipcp(ControlPacket<T>),
+    Ipv6cp(ControlPacket<T>),
+    Ipv4(T),
+    Ipv6(T),
+    Other(u16, T),
+}
+
+impl<T> Packet<T>
+where
+    T: Borrow<[u8]>,
+{
+    /// # Summary
+    /// Attempt to read a `Packet` of the given `Protocol` using a `Reader`, consuming all remaining data.
+    #[inline]
+    pub fn try_read(protocol: Protocol, reader: &mut impl Reader<T>) -> DecodeResult<Self> {
+        let mut payload = || {
+            reader
+                .bytes(reader.len())
+                .ok_or(DecodeError::PppFrameReadError)
+        };
+
+        Ok(match protocol {
+            Protocol::Lcp => Packet::Lcp(ControlPacket::try_read(reader)?),
+            Protocol::Pap => Packet::Pap(ControlPacket::try_read(reader)?),
+            Protocol::Chap => Packet::Chap(ControlPacket::try_read(reader)?),
+            Protocol::Ipcp => Packet::Ipcp(ControlPacket::try_read(reader)?),
+            Protocol::Ipv6cp => Packet::Ipv6cp(ControlPacket::try_read(reader)?),
+            Protocol::Ipv4 => Packet::Ipv4(payload()?),
+            Protocol::Ipv6 => Packet::Ipv6(payload()?),
+            Protocol::Other(x) => Packet::Other(x, payload()?),
+        })
+    }
+
+    /// # Summary
+    /// Get the `Protocol` of this `Packet`.
+    #[inline]
+    pub fn protocol(&self) -> Protocol {
+        match self {
+            Packet::Lcp(_) => Protocol::Lcp,
+            Packet::Pap(_) => Protocol::Pap,
+            Packet::Chap(_) => Protocol::Chap,
+            Packet::Ipcp(_) => Protocol::Ipcp,
+            Packet::Ipv6cp(_) => Protocol::Ipv6cp,
+            Packet::Ipv4(_) => Protocol::Ipv4,
+            Packet::Ipv6(_) => Protocol::Ipv6,
+            Packet::Other(x, _) => Protocol::Other(*x),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+/// # Summary
+/// A `Protocol` is a representation of the PPP protocol field which identifies the payload of a PPP frame.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Protocol {
+    Ipv4,
+    Ipv6,
+    Lcp,
+    Pap,
+    Chap,
+    Ipcp,
+    Ipv6cp,
+    Other(u16),
+}
+
+use Protocol::*;
+
+impl Protocol {
+    /// # Summary
+    /// Indicate whether this is a valid protocol number, i.e. whether the most significant octet is even and the
+    /// least significant octet is odd.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        let value = u16::from(*self);
+        value & 0x0100 == 0 && value & 0x0001 != 0
+    }
+
+    /// # Summary
+    /// Indicate whether this protocol is a PPP control protocol, whose frames carry a `ControlPacket`.
+    #[inline]
+    pub fn is_control(&self) -> bool {
+        u16::from(*self) >= 0x8000
+    }
+}
+
+impl From<u16> for Protocol {
+    #[inline]
+    fn from(value: u16) -> Self {
+        match value {
+            0x0021 => Ipv4,
+            0x0057 => Ipv6,
+            0xc021 => Lcp,
+            0xc023 => Pap,
+            0xc223 => Chap,
+            0x8021 => Ipcp,
+            0x8057 => Ipv6cp,
+            x => Other(x),
+        }
+    }
+}
+
+impl From<Protocol> for u16 {
+    #[inline]
+    fn from(value: Protocol) -> Self {
+        match value {
+            Ipv4 => 0x0021,
+            Ipv6 => 0x0057,
+            Lcp => 0xc021,
+            Pap => 0xc023,
+            Chap => 0xc223,
+            Ipcp => 0x8021,
+            Ipv6cp => 0x8057,
+            Other(x) => x,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::avp::AVP;
+use crate::common::DecodeError;
+use crate::ppp::{EndpointDiscriminator, LcpOption, Protocol};
+use core::ops::RangeInclusive;
+
+/// # Summary
+/// An `LcpConfiguration` summarizes the values requested by one side of an LCP negotiation, with the RFC 1661
+/// defaults applied to options that weren't requested.
+///
+/// # Data members
+/// * `mru` - The maximum receive unit.
+/// * `accm` - The async control character map.
+/// * `authentication` - The authentication protocol and its data, if any.
+/// * `magic_number` - The magic number, if any.
+/// * `protocol_field_compression` - Whether protocol field compression was requested.
+/// * `address_and_control_field_compression` - Whether address and control field compression was requested.
+/// * `mrru` - The maximum receive reconstructed unit, if multilink PPP was requested.
+/// * `short_sequence_numbers` - Whether the short sequence number header format of multilink PPP was requested.
+/// * `endpoint_discriminator` - The endpoint discriminator, if any.
+/// * `unknown_options` - The types of any options not covered above.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LcpConfiguration {
+    pub mru: u16,
+    pub accm: u32,
+    pub authentication: Option<(Protocol, Vec<u8>)>,
+    pub magic_number: Option<u32>,
+    pub protocol_field_compression: bool,
+    pub address_and_control_field_compression: bool,
+    pub mrru: Option<u16>,
+    pub short_sequence_numbers: bool,
+    pub endpoint_discriminator: Option<EndpointDiscriminator>,
+    pub unknown_options: Vec<u8>,
+}
+
+impl LcpConfiguration {
+    pub const DEFAULT_MRU: u16 = 1500;
+    pub const DEFAULT_ACCM: u32 = 0xffffffff;
+}
+
+impl Default for LcpConfiguration {
+    fn default() -> Self {
+        Self {
+            mru: Self::DEFAULT_MRU,
+            accm: Self::DEFAULT_ACCM,
+            authentication: None,
+            magic_number: None,
+            protocol_field_compression: false,
+            address_and_control_field_compression: false,
+            mrru: None,
+            short_sequence_numbers: false,
+            endpoint_discriminator: None,
+            unknown_options: Vec::new(),
+        }
+    }
+}
+
+impl From<&[LcpOption]> for LcpConfiguration {
+    fn from(options: &[LcpOption]) -> Self {
+        let mut configuration = Self::default();
+        for option in options.iter() {
+            match option {
+                LcpOption::MaximumReceiveUnit(mru) => configuration.mru = *mru,
+                LcpOption::AsyncControlCharacterMap(accm) => configuration.accm = *accm,
+                LcpOption::AuthenticationProtocol { protocol, data } => {
+                    configuration.authentication = Some((*protocol, data.clone()))
+                }
+                LcpOption::MagicNumber(magic_number) => {
+                    configuration.magic_number = Some(*magic_number)
+                }
+                LcpOption::ProtocolFieldCompression => {
+                    configuration.protocol_field_compression = true
+                }
+                LcpOption::AddressAndControlFieldCompression => {
+                    configuration.address_and_control_field_compression = true
+                }
+                LcpOption::MaximumReceiveReconstructedUnit(mrru) => {
+                    configuration.mrru = Some(*mrru)
+                }
+                LcpOption::ShortSequenceNumberHeaderFormat => {
+                    configuration.short_sequence_numbers = true
+                }
+                LcpOption::EndpointDiscriminator(x) => {
+                    configuration.endpoint_discriminator = Some(x.clone())
+                }
+                LcpOption::QualityProtocol { .. } | LcpOption::Other { .. } => {
+                    configuration.unknown_options.push(option.get_option_type())
+                }
+            }
+        }
+        configuration
+    }
+}
+
+/// # Summary
+/// The LCP state negotiated by a LAC on behalf of an LNS, as carried in proxy LCP AVPs (RFC 2661 section 4.4.5).
+///
+/// # Data members
+/// * `local` - The options the LAC requested from the client on behalf of the LNS (Last Sent LCP CONFREQ).
+/// * `peer` - The options requested by the client (Last Received LCP CONFREQ).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyLcp {
+    pub local: LcpConfiguration,
+    pub peer: LcpConfiguration,
+}
+
+/// # Summary
+/// The reason why proxied LCP state was found unacceptable.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RenegotiationReason {
+    MissingProxyLcp,
+    MalformedProxyLcp(DecodeError),
+    MruOutOfRange(u16),
+    UnacceptableAuthentication(Option<Protocol>),
+    UnsupportedOption(u8),
+    MultilinkNotSupported,
+    LoopedBack,
+}
+
+/// # Summary
+/// The outcome of evaluating proxied LCP state against a `ProxyLcpPolicy`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProxyLcpDecision {
+    Accept(Box<ProxyLcp>),
+    Renegotiate(RenegotiationReason),
+}
+
+/// # Summary
+/// A `ProxyLcpPolicy` decides whether an LNS can adopt the LCP state negotiated by a LAC, or must renegotiate LCP
+/// with the client.
+///
+/// # Data members
+/// * `mru` - The range of MRUs acceptable in either direction.
+/// * `authentication` - The acceptable authentication protocols and their data. If empty, no authentication may
+///   have been requested on behalf of the LNS.
+/// * `multilink` - Whether multilink PPP (RFC 1990) may have been negotiated, for LNSs bundling sessions with a
+///   `BundleRegistry`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyLcpPolicy {
+    pub mru: RangeInclusive<u16>,
+    pub authentication: Vec<(Protocol, Vec<u8>)>,
+    pub multilink: bool,
+}
+
+impl Default for ProxyLcpPolicy {
+    /// Accept MRUs from 576 to 1500 octets with either CHAP-MD5 or PAP authentication, without multilink PPP.
+    fn default() -> Self {
+        const CHAP_MD5: u8 = 5;
+
+        Self {
+            mru: 576..=1500,
+            authentication: vec![(Protocol::Chap, vec![CHAP_MD5]), (Protocol::Pap, vec![])],
+            multilink: false,
+        }
+    }
+}
+
+impl ProxyLcpPolicy {
+    /// # Summary
+    /// Evaluate the proxy LCP AVPs found among `avps`.
+    ///
+    /// Renegotiation is required if the Last Sent or Last Received LCP CONFREQ AVPs are missing or malformed, if
+    /// either MRU is outside of the acceptable range, if the requested authentication is unacceptable, if an
+    /// unsupported option was requested, if multilink PPP was requested but isn't allowed or if both sides use the
+    /// same magic number.
+    pub fn evaluate(&self, avps: &[AVP]) -> ProxyLcpDecision {
+        match self.try_evaluate(avps) {
+            Ok(proxy_lcp) => ProxyLcpDecision::Accept(Box::new(proxy_lcp)),
+            Err(reason) => ProxyLcpDecision::Renegotiate(reason),
+        }
+    }
+
+    fn try_evaluate(&self, avps: &[AVP]) -> Result<ProxyLcp, RenegotiationReason> {
+        let mut last_sent = None;
+        let mut last_received = None;
+        for avp in avps.iter() {
+            match avp {
+                AVP::LastSentLcpConfReq(x) => last_sent = Some(x.options()),
+                AVP::LastReceivedLcpConfReq(x) => last_received = Some(x.options()),
+                _ => (),
+            }
+        }
+
+        let (Some(last_sent), Some(last_received)) = (last_sent, last_received) else {
+            return Err(RenegotiationReason::MissingProxyLcp);
+        };
+        let local =
+            LcpConfiguration::from(&last_sent.map_err(RenegotiationReason::MalformedProxyLcp)?[..]);
+        let peer = LcpConfiguration::from(
+            &last_received.map_err(RenegotiationReason::MalformedProxyLcp)?[..],
+        );
+
+        for configuration in [&local, &peer] {
+            if !self.mru.contains(&configuration.mru) {
+                return Err(RenegotiationReason::MruOutOfRange(configuration.mru));
+            }
+            if let Some(option_type) = configuration.unknown_options.first() {
+                return Err(RenegotiationReason::UnsupportedOption(*option_type));
+            }
+            if !self.multilink && configuration.mrru.is_some() {
+                return Err(RenegotiationReason::MultilinkNotSupported);
+            }
+        }
+
+        // The LNS authenticates the client, so only the authentication requested on its behalf matters
+        let authentication_ok = match &local.authentication {
+            Some(authentication) => self.authentication.contains(authentication),
+            None => self.authentication.is_empty(),
+        };
+        if !authentication_ok {
+            return Err(RenegotiationReason::UnacceptableAuthentication(
+                local.authentication.as_ref().map(|(protocol, _)| *protocol),
+            ));
+        }
+
+        if local.magic_number.is_some() && local.magic_number == peer.magic_number {
+            return Err(RenegotiationReason::LoopedBack);
+        }
+
+        Ok(ProxyLcp { local, peer })
+    }
+}
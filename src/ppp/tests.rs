@@ -0,0 +1,4 @@
+mod frame;
+mod hdlc;
+mod lcp;
+mod multilink;
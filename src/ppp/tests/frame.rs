@@ -0,0 +1,215 @@
+use crate::common::{DecodeError, SliceReader, VecWriter};
+use crate::ppp::{Compression, ControlPacket, Frame, Packet, Protocol};
+
+#[test]
+fn read_uncompressed_lcp() {
+    let input = [
+        0xff, 0x03, // Address and control
+        0xc0, 0x21, // Protocol (LCP)
+        0x01, 0x02, 0x00, 0x08, // Code, identifier and length
+        0x01, 0x04, 0x05, 0xdc, // MRU option
+        0x00, 0x00, // Padding
+    ];
+    let mut r = SliceReader::from(&input);
+    let frame = Frame::try_read(&mut r).unwrap();
+
+    assert_eq!(frame.protocol, Protocol::Lcp);
+    assert_eq!(
+        frame.packet(),
+        Ok(Packet::Lcp(ControlPacket {
+            code: 0x01,
+            identifier: 0x02,
+            data: &[0x01, 0x04, 0x05, 0xdc][..],
+        }))
+    );
+}
+
+#[test]
+fn read_compressed_ipv4() {
+    let input = [
+        0x21, // Protocol (IPv4), compressed
+        0x45, 0x00,
+    ];
+    let mut r = SliceReader::from(&input);
+    let frame = Frame::try_read(&mut r).unwrap();
+
+    assert_eq!(frame.protocol, Protocol::Ipv4);
+    assert_eq!(frame.payload, &[0x45, 0x00][..]);
+    assert_eq!(frame.packet(), Ok(Packet::Ipv4(&[0x45, 0x00][..])));
+}
+
+#[test]
+fn read_address_control_compressed() {
+    let input = [
+        0x80, 0x21, // Protocol (IPCP)
+        0x03, 0x01, 0x00, 0x04, // Code, identifier and length
+    ];
+    let mut r = SliceReader::from(&input);
+    let frame = Frame::try_read(&mut r).unwrap();
+
+    assert_eq!(frame.protocol, Protocol::Ipcp);
+    assert_eq!(
+        frame.packet(),
+        Ok(Packet::Ipcp(ControlPacket {
+            code: 0x03,
+            identifier: 0x01,
+            data: &[][..],
+        }))
+    );
+}
+
+#[test]
+fn read_other_protocol() {
+    let input = [0xff, 0x03, 0x00, 0x3d, 0xaa];
+    let mut r = SliceReader::from(&input);
+    let frame = Frame::try_read(&mut r).unwrap();
+
+    assert_eq!(frame.protocol, Protocol::Other(0x003d));
+    assert!(!frame.protocol.is_control());
+    assert_eq!(frame.packet(), Ok(Packet::Other(0x003d, &[0xaa][..])));
+}
+
+#[test]
+fn read_errors() {
+    let cases: [(&[u8], DecodeError); 6] = [
+        (&[], DecodeError::IncompletePppFrame),
+        (&[0xff], DecodeError::IncompletePppFrame),
+        (
+            &[0xff, 0x02, 0x00, 0x21],
+            DecodeError::InvalidPppControlField(0x02),
+        ),
+        (&[0xff, 0x03, 0xc0], DecodeError::IncompletePppFrame),
+        (&[0x02, 0x02], DecodeError::InvalidPppProtocol(0x0202)),
+        (&[0xc0, 0x20], DecodeError::InvalidPppProtocol(0xc020)),
+    ];
+
+    for (input, error) in cases {
+        let mut r = SliceReader::from(input);
+        assert_eq!(Frame::try_read(&mut r), Err(error));
+    }
+}
+
+#[test]
+fn read_control_packet_errors() {
+    let cases: [(&[u8], DecodeError); 3] = [
+        (&[0x01, 0x02, 0x00], DecodeError::IncompletePppControlPacket),
+        (
+            &[0x01, 0x02, 0x00, 0x03],
+            DecodeError::InvalidPppControlPacketLength(3),
+        ),
+        (
+            &[0x01, 0x02, 0x00, 0x06, 0x00],
+            DecodeError::IncompletePppControlPacket,
+        ),
+    ];
+
+    for (input, error) in cases {
+        let mut r = SliceReader::from(input);
+        assert_eq!(
+            ControlPacket::<&[u8]>::try_read(&mut r),
+            Err(error),
+            "input: {input:?}"
+        );
+    }
+}
+
+#[test]
+fn write_read() {
+    let frames = [
+        Frame {
+            protocol: Protocol::Lcp,
+            payload: vec![0x09, 0x01, 0x00, 0x08, 0xde, 0xad, 0xbe, 0xef],
+        },
+        Frame {
+            protocol: Protocol::Ipv6,
+            payload: vec![0x60, 0x00],
+        },
+        Frame {
+            protocol: Protocol::Chap,
+            payload: vec![0x03, 0x01, 0x00, 0x04],
+        },
+    ];
+    let compressions = [
+        Compression::default(),
+        Compression {
+            address_control: true,
+            protocol: false,
+        },
+        Compression {
+            address_control: true,
+            protocol: true,
+        },
+    ];
+
+    for frame in frames.iter() {
+        for compression in compressions {
+            let mut w = VecWriter::new();
+            frame.write(compression, &mut w);
+
+            let mut r = SliceReader::from(&w.data);
+            let read = Frame::try_read(&mut r).unwrap();
+            assert_eq!(read.protocol, frame.protocol);
+            assert_eq!(read.payload, &frame.payload[..]);
+        }
+    }
+}
+
+#[test]
+fn write_compression() {
+    let frame = Frame {
+        protocol: Protocol::Ipv4,
+        payload: &[0x45][..],
+    };
+    let mut w = VecWriter::new();
+    frame.write(
+        Compression {
+            address_control: true,
+            protocol: true,
+        },
+        &mut w,
+    );
+    assert_eq!(w.data, [0x21, 0x45]);
+
+    // LCP is never compressed
+    let frame = Frame {
+        protocol: Protocol::Lcp,
+        payload: &[0x05, 0x01, 0x00, 0x04][..],
+    };
+    let mut w = VecWriter::new();
+    frame.write(
+        Compression {
+            address_control: true,
+            protocol: true,
+        },
+        &mut w,
+    );
+    assert_eq!(w.data, [0xff, 0x03, 0xc0, 0x21, 0x05, 0x01, 0x00, 0x04]);
+}
+
+#[test]
+fn control_packet_write() {
+    let packet = ControlPacket {
+        code: 0x05,
+        identifier: 0x07,
+        data: &[0x01, 0x02][..],
+    };
+    let mut w = VecWriter::new();
+    packet.write(&mut w);
+    assert_eq!(w.data, [0x05, 0x07, 0x00, 0x06, 0x01, 0x02]);
+}
+
+#[test]
+fn data_message_ppp_frame() {
+    let message = crate::DataMessage {
+        is_prioritized: false,
+        length: None,
+        tunnel_id: 1,
+        session_id: 2,
+        ns_nr: None,
+        offset: None,
+        data: &[0xff, 0x03, 0x00, 0x57, 0x60][..],
+    };
+    let frame = message.ppp_frame().unwrap();
+    assert_eq!(frame.protocol, Protocol::Ipv6);
+    assert_eq!(frame.payload, &[0x60][..]);
+}
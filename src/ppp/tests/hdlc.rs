@@ -0,0 +1,134 @@
+use crate::avp::types::Accm;
+use crate::common::{DecodeError, VecWriter};
+use crate::ppp::*;
+
+const LCP_FRAME: [u8; 8] = [0xff, 0x03, 0xc0, 0x21, 0x05, 0x01, 0x00, 0x04];
+
+#[test]
+fn fcs_check_values() {
+    assert_eq!(Fcs::Fcs16.compute(b"123456789"), 0x906eu16.to_le_bytes());
+    assert_eq!(
+        Fcs::Fcs32.compute(b"123456789"),
+        0xcbf43926u32.to_le_bytes()
+    );
+
+    for fcs in [Fcs::Fcs16, Fcs::Fcs32] {
+        let mut data = b"123456789".to_vec();
+        data.extend(fcs.compute(&data));
+        assert!(fcs.is_valid(&data));
+
+        data[0] ^= 0x01;
+        assert!(!fcs.is_valid(&data));
+    }
+}
+
+#[test]
+fn encode_escapes() {
+    let encoder = HdlcEncoder {
+        accm: 0x000a0000,
+        fcs: Fcs::Fcs16,
+    };
+    let mut w = VecWriter::new();
+    encoder.encode(&[0x7e, 0x7d, 0x11, 0x13, 0x01], &mut w);
+
+    let fcs = Fcs::Fcs16.compute(&[0x7e, 0x7d, 0x11, 0x13, 0x01]);
+    assert_eq!(
+        w.data[..10],
+        [0x7e, 0x7d, 0x5e, 0x7d, 0x5d, 0x7d, 0x31, 0x7d, 0x33, 0x01]
+    );
+    assert_eq!(w.data[10..w.data.len() - 1].len(), fcs.len());
+    assert_eq!(w.data.last(), Some(&0x7e));
+}
+
+#[test]
+fn encode_decode() {
+    let accm = Accm {
+        send_accm: [0x00, 0x00, 0x00, 0x00],
+        receive_accm: [0x00, 0x00, 0x00, 0x00],
+    };
+    let payload: Vec<u8> = (0..=255).collect();
+
+    for fcs in [Fcs::Fcs16, Fcs::Fcs32] {
+        for encoder in [HdlcEncoder::default(), HdlcEncoder::from_accm(&accm, fcs)] {
+            let encoder = HdlcEncoder { fcs, ..encoder };
+            let mut w = VecWriter::new();
+            encoder.encode(&LCP_FRAME, &mut w);
+            encoder.encode(&payload, &mut w);
+
+            let mut decoder = HdlcDecoder::new(encoder.accm, fcs);
+            assert_eq!(
+                decoder.decode(&w.data),
+                vec![Ok(LCP_FRAME.to_vec()), Ok(payload.clone())]
+            );
+        }
+    }
+}
+
+#[test]
+fn decode_chunked() {
+    let mut w = VecWriter::new();
+    HdlcEncoder::default().encode(&LCP_FRAME, &mut w);
+
+    let mut decoder = HdlcDecoder::default();
+    let mut frames = Vec::new();
+    for chunk in w.data.chunks(3) {
+        frames.extend(decoder.decode(chunk));
+    }
+    assert_eq!(frames, vec![Ok(LCP_FRAME.to_vec())]);
+}
+
+#[test]
+fn decode_discards_mapped_characters() {
+    let mut w = VecWriter::new();
+    HdlcEncoder::default().encode(&LCP_FRAME, &mut w);
+    // Insert an unescaped XON as if added by a modem
+    w.data.insert(3, 0x11);
+
+    let mut decoder = HdlcDecoder::from_accm(
+        &Accm {
+            send_accm: [0xff; 4],
+            receive_accm: [0x00, 0x0a, 0x00, 0x00],
+        },
+        Fcs::Fcs16,
+    );
+    assert_eq!(decoder.decode(&w.data), vec![Ok(LCP_FRAME.to_vec())]);
+}
+
+#[test]
+fn decode_errors() {
+    let mut decoder = HdlcDecoder::default().with_maximum_frame_length(4);
+
+    // Bad FCS
+    let mut w = VecWriter::new();
+    HdlcEncoder::default().encode(&[0xff, 0x03, 0x00, 0x21], &mut w);
+    w.data[1] = 0xfe;
+    assert_eq!(
+        decoder.decode(&w.data),
+        vec![Err(DecodeError::InvalidHdlcFcs)]
+    );
+
+    // Abort sequence, too short and oversize frames
+    assert_eq!(
+        decoder.decode(&[0x7e, 0x41, 0x42, 0x7d, 0x7e, 0x41, 0x7e]),
+        vec![
+            Err(DecodeError::HdlcFrameAborted),
+            Err(DecodeError::IncompleteHdlcFrame)
+        ]
+    );
+    assert_eq!(decoder.decode(&[0x41; 7]), vec![]);
+    assert_eq!(
+        decoder.decode(&[0x7e, 0x7e]),
+        vec![Err(DecodeError::OversizeHdlcFrame(4))]
+    );
+}
+
+#[test]
+fn decode_ppp_frame() {
+    let mut w = VecWriter::new();
+    HdlcEncoder::default().encode(&LCP_FRAME, &mut w);
+
+    let frames = HdlcDecoder::default().decode(&w.data);
+    let data = frames[0].as_ref().unwrap();
+    let frame = Frame::try_read(&mut crate::common::SliceReader::from(data)).unwrap();
+    assert_eq!(frame.protocol, Protocol::Lcp);
+}
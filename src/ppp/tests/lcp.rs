@@ -0,0 +1,273 @@
+use crate::avp::{types, AVP};
+use crate::common::{DecodeError, VecWriter};
+use crate::ppp::*;
+
+fn confreq_options() -> Vec<LcpOption> {
+    vec![
+        LcpOption::MaximumReceiveUnit(1492),
+        LcpOption::AsyncControlCharacterMap(0),
+        LcpOption::AuthenticationProtocol {
+            protocol: Protocol::Chap,
+            data: vec![0x05],
+        },
+        LcpOption::MagicNumber(0x01020304),
+        LcpOption::ProtocolFieldCompression,
+        LcpOption::AddressAndControlFieldCompression,
+    ]
+}
+
+#[test]
+fn read_options() {
+    let input = [
+        0x01, 0x04, 0x05, 0xd4, // MRU
+        0x02, 0x06, 0x00, 0x00, 0x00, 0x00, // ACCM
+        0x03, 0x05, 0xc2, 0x23, 0x05, // CHAP-MD5
+        0x05, 0x06, 0x01, 0x02, 0x03, 0x04, // Magic number
+        0x07, 0x02, // PFC
+        0x08, 0x02, // ACFC
+    ];
+    assert_eq!(LcpOption::try_read_all(&input), Ok(confreq_options()));
+}
+
+#[test]
+fn read_other_options() {
+    let input = [
+        0x04, 0x08, 0xc0, 0x25, 0x00, 0x00, 0x00, 0x0a, // LQR
+        0x0d, 0x03, 0x06, // Callback
+    ];
+    assert_eq!(
+        LcpOption::try_read_all(&input),
+        Ok(vec![
+            LcpOption::QualityProtocol {
+                protocol: 0xc025,
+                data: vec![0x00, 0x00, 0x00, 0x0a],
+            },
+            LcpOption::Other {
+                option_type: 0x0d,
+                data: vec![0x06],
+            },
+        ])
+    );
+}
+
+#[test]
+fn read_multilink_options() {
+    let input = [
+        0x11, 0x04, 0x05, 0xd4, // MRRU
+        0x12, 0x02, // Short sequence numbers
+        0x13, 0x06, 0x01, 0xca, 0xfe, 0x01, // Endpoint discriminator
+    ];
+    let options = vec![
+        LcpOption::MaximumReceiveReconstructedUnit(1492),
+        LcpOption::ShortSequenceNumberHeaderFormat,
+        LcpOption::EndpointDiscriminator(EndpointDiscriminator {
+            class: 1,
+            address: vec![0xca, 0xfe, 0x01],
+        }),
+    ];
+    assert_eq!(LcpOption::try_read_all(&input), Ok(options.clone()));
+
+    let mut w = VecWriter::new();
+    LcpOption::write_all(&options, &mut w);
+    assert_eq!(w.data, input);
+
+    let configuration = LcpConfiguration::from(&options[..]);
+    assert_eq!(configuration.mrru, Some(1492));
+    assert!(configuration.short_sequence_numbers);
+    assert_eq!(configuration.endpoint_discriminator.unwrap().class, 1);
+}
+
+#[test]
+fn read_option_errors() {
+    let cases: [(&[u8], DecodeError); 6] = [
+        (&[0x01], DecodeError::IncompleteLcpOption),
+        (&[0x01, 0x04, 0x05], DecodeError::IncompleteLcpOption),
+        (&[0x01, 0x01], DecodeError::InvalidLcpOptionLength(1, 1)),
+        (
+            &[0x01, 0x03, 0x05],
+            DecodeError::InvalidLcpOptionLength(1, 3),
+        ),
+        (
+            &[0x07, 0x03, 0x00],
+            DecodeError::InvalidLcpOptionLength(7, 3),
+        ),
+        (&[0x13, 0x02], DecodeError::InvalidLcpOptionLength(19, 2)),
+    ];
+
+    for (input, error) in cases {
+        assert_eq!(
+            LcpOption::try_read_all(input),
+            Err(error),
+            "input: {input:?}"
+        );
+    }
+}
+
+#[test]
+fn write_read_options() {
+    let options = confreq_options();
+    let mut w = VecWriter::new();
+    LcpOption::write_all(&options, &mut w);
+
+    assert_eq!(
+        w.data.len(),
+        options.iter().map(|x| x.get_length()).sum::<usize>()
+    );
+    assert_eq!(LcpOption::try_read_all(&w.data), Ok(options));
+}
+
+#[test]
+fn avp_options() {
+    let avp = types::LastSentLcpConfReq::from_options(&confreq_options());
+    assert_eq!(avp.options(), Ok(confreq_options()));
+
+    let avp = types::InitialReceivedLcpConfReq::from(vec![0x01]);
+    assert_eq!(avp.options(), Err(DecodeError::IncompleteLcpOption));
+}
+
+#[test]
+fn configuration_defaults() {
+    let configuration = LcpConfiguration::from(&[LcpOption::MagicNumber(7)][..]);
+    assert_eq!(configuration.mru, 1500);
+    assert_eq!(configuration.accm, 0xffffffff);
+    assert_eq!(configuration.authentication, None);
+    assert_eq!(configuration.magic_number, Some(7));
+    assert!(!configuration.protocol_field_compression);
+}
+
+fn proxy_avps(local: &[LcpOption], peer: &[LcpOption]) -> Vec<AVP> {
+    vec![
+        AVP::InitialReceivedLcpConfReq(types::InitialReceivedLcpConfReq::from_options(peer)),
+        AVP::LastSentLcpConfReq(types::LastSentLcpConfReq::from_options(local)),
+        AVP::LastReceivedLcpConfReq(types::LastReceivedLcpConfReq::from_options(peer)),
+    ]
+}
+
+#[test]
+fn proxy_lcp_accept() {
+    let local = confreq_options();
+    let peer = [
+        LcpOption::MaximumReceiveUnit(1400),
+        LcpOption::MagicNumber(0xcafe),
+    ];
+    let decision = ProxyLcpPolicy::default().evaluate(&proxy_avps(&local, &peer));
+
+    let ProxyLcpDecision::Accept(proxy_lcp) = decision else {
+        panic!("Unexpected decision {decision:?}");
+    };
+    assert_eq!(proxy_lcp.local.mru, 1492);
+    assert_eq!(
+        proxy_lcp.local.authentication,
+        Some((Protocol::Chap, vec![0x05]))
+    );
+    assert!(proxy_lcp.local.address_and_control_field_compression);
+    assert_eq!(proxy_lcp.peer.mru, 1400);
+    assert_eq!(proxy_lcp.peer.magic_number, Some(0xcafe));
+}
+
+#[test]
+fn proxy_lcp_renegotiate() {
+    let policy = ProxyLcpPolicy::default();
+    let pap = LcpOption::AuthenticationProtocol {
+        protocol: Protocol::Pap,
+        data: vec![],
+    };
+    let ms_chap = LcpOption::AuthenticationProtocol {
+        protocol: Protocol::Chap,
+        data: vec![0x80],
+    };
+
+    let cases = [
+        (
+            proxy_avps(&[pap.clone(), LcpOption::MaximumReceiveUnit(9000)], &[]),
+            RenegotiationReason::MruOutOfRange(9000),
+        ),
+        (
+            proxy_avps(
+                core::slice::from_ref(&pap),
+                &[LcpOption::MaximumReceiveUnit(200)],
+            ),
+            RenegotiationReason::MruOutOfRange(200),
+        ),
+        (
+            proxy_avps(&[ms_chap], &[]),
+            RenegotiationReason::UnacceptableAuthentication(Some(Protocol::Chap)),
+        ),
+        (
+            proxy_avps(&[], &[]),
+            RenegotiationReason::UnacceptableAuthentication(None),
+        ),
+        (
+            proxy_avps(
+                core::slice::from_ref(&pap),
+                &[LcpOption::Other {
+                    option_type: 0x0d,
+                    data: vec![0x06],
+                }],
+            ),
+            RenegotiationReason::UnsupportedOption(0x0d),
+        ),
+        (
+            proxy_avps(
+                &[pap.clone(), LcpOption::MagicNumber(1)],
+                &[LcpOption::MagicNumber(1)],
+            ),
+            RenegotiationReason::LoopedBack,
+        ),
+        (
+            vec![AVP::LastSentLcpConfReq(
+                types::LastSentLcpConfReq::from_options(&[pap]),
+            )],
+            RenegotiationReason::MissingProxyLcp,
+        ),
+        (
+            vec![
+                AVP::LastSentLcpConfReq(vec![0x01, 0x04].into()),
+                AVP::LastReceivedLcpConfReq(vec![].into()),
+            ],
+            RenegotiationReason::MalformedProxyLcp(DecodeError::IncompleteLcpOption),
+        ),
+    ];
+
+    for (avps, reason) in cases {
+        assert_eq!(
+            policy.evaluate(&avps),
+            ProxyLcpDecision::Renegotiate(reason)
+        );
+    }
+}
+
+#[test]
+fn proxy_lcp_multilink() {
+    let pap = LcpOption::AuthenticationProtocol {
+        protocol: Protocol::Pap,
+        data: vec![],
+    };
+    let avps = proxy_avps(&[pap], &[LcpOption::MaximumReceiveReconstructedUnit(1500)]);
+    assert_eq!(
+        ProxyLcpPolicy::default().evaluate(&avps),
+        ProxyLcpDecision::Renegotiate(RenegotiationReason::MultilinkNotSupported)
+    );
+
+    let policy = ProxyLcpPolicy {
+        multilink: true,
+        ..Default::default()
+    };
+    assert!(matches!(
+        policy.evaluate(&avps),
+        ProxyLcpDecision::Accept(_)
+    ));
+}
+
+#[test]
+fn proxy_lcp_without_authentication() {
+    let policy = ProxyLcpPolicy {
+        mru: 1280..=1500,
+        authentication: vec![],
+        multilink: false,
+    };
+    assert!(matches!(
+        policy.evaluate(&proxy_avps(&[], &[])),
+        ProxyLcpDecision::Accept(_)
+    ));
+}
@@ -0,0 +1,155 @@
+use crate::common::BundleError;
+use crate::ppp::*;
+
+fn proxy_lcp(options: &[LcpOption]) -> ProxyLcp {
+    ProxyLcp {
+        local: LcpConfiguration::default(),
+        peer: LcpConfiguration::from(options),
+    }
+}
+
+fn discriminator(class: u8, address: &[u8]) -> LcpOption {
+    LcpOption::EndpointDiscriminator(EndpointDiscriminator {
+        class,
+        address: address.to_vec(),
+    })
+}
+
+#[test]
+fn bundles() {
+    let mut registry = BundleRegistry::default();
+    let client_a = proxy_lcp(&[
+        LcpOption::MaximumReceiveReconstructedUnit(1524),
+        discriminator(3, &[0x02, 0x00, 0x00, 0x00, 0x00, 0x01]),
+    ]);
+    let client_b = proxy_lcp(&[
+        LcpOption::MaximumReceiveReconstructedUnit(1500),
+        LcpOption::ShortSequenceNumberHeaderFormat,
+        discriminator(1, &[0xca, 0xfe]),
+    ]);
+
+    assert_eq!(
+        registry.join(1, 10, b"alice", &client_a).unwrap().mrru,
+        1524
+    );
+    registry.join(2, 20, b"alice", &client_a).unwrap();
+    let bundle = registry.join(1, 11, b"bob", &client_b).unwrap();
+    assert!(bundle.short_sequence_numbers);
+
+    assert_eq!(registry.bundles().count(), 2);
+    let bundle = registry.bundle_of(2, 20).unwrap();
+    assert_eq!(bundle.members().collect::<Vec<_>>(), [(1, 10), (2, 20)]);
+    assert_eq!(
+        registry.bundle(b"alice", &bundle.endpoint_discriminator.clone()),
+        Some(bundle)
+    );
+
+    assert!(registry.leave(1, 10));
+    assert!(!registry.leave(1, 10));
+    assert!(registry.leave(1, 11));
+    assert!(registry.bundle_of(1, 11).is_none());
+    assert_eq!(registry.bundles().count(), 1);
+}
+
+#[test]
+fn same_discriminator_different_users() {
+    let mut registry = BundleRegistry::default();
+    let client = proxy_lcp(&[
+        LcpOption::MaximumReceiveReconstructedUnit(1500),
+        discriminator(1, &[0x01]),
+    ]);
+
+    registry.join(1, 10, b"alice", &client).unwrap();
+    registry.join(1, 11, b"bob", &client).unwrap();
+
+    assert_eq!(registry.bundles().count(), 2);
+    assert_ne!(registry.bundle_of(1, 10), registry.bundle_of(1, 11));
+}
+
+#[test]
+fn mismatched_links() {
+    let mut registry = BundleRegistry::default();
+    let ed = discriminator(1, &[0xca, 0xfe]);
+    registry
+        .join(
+            1,
+            10,
+            b"alice",
+            &proxy_lcp(&[LcpOption::MaximumReceiveReconstructedUnit(1500), ed.clone()]),
+        )
+        .unwrap();
+
+    let cases = [
+        (
+            proxy_lcp(&[LcpOption::MaximumReceiveReconstructedUnit(1600), ed.clone()]),
+            BundleError::MrruMismatch(1500, 1600),
+        ),
+        (
+            proxy_lcp(&[
+                LcpOption::MaximumReceiveReconstructedUnit(1500),
+                LcpOption::ShortSequenceNumberHeaderFormat,
+                ed,
+            ]),
+            BundleError::SequenceNumberFormatMismatch,
+        ),
+    ];
+    for (proxy_lcp, error) in cases.iter() {
+        assert_eq!(
+            registry.join(1, 11, b"alice", proxy_lcp),
+            Err(error.clone())
+        );
+        assert!(registry.bundle_of(1, 11).is_none());
+    }
+    assert_eq!(registry.bundle_of(1, 10).unwrap().members().count(), 1);
+}
+
+#[test]
+fn rejected_join_keeps_membership() {
+    let mut registry = BundleRegistry::default();
+    let ed = discriminator(1, &[0xca, 0xfe]);
+    let mrru = |mrru| proxy_lcp(&[LcpOption::MaximumReceiveReconstructedUnit(mrru), ed.clone()]);
+    registry.join(1, 10, b"alice", &mrru(1500)).unwrap();
+    registry.join(1, 11, b"bob", &mrru(1500)).unwrap();
+
+    assert_eq!(
+        registry.join(1, 11, b"alice", &mrru(1600)),
+        Err(BundleError::MrruMismatch(1500, 1600))
+    );
+    let bundle = registry.bundle_of(1, 11).unwrap();
+    assert_eq!(bundle.authenticated_name, b"bob");
+    assert_eq!(bundle.members().collect::<Vec<_>>(), [(1, 11)]);
+    assert_eq!(registry.bundle_of(1, 10).unwrap().members().count(), 1);
+
+    // The only link of a bundle may renegotiate it
+    let bundle = registry.join(1, 11, b"bob", &mrru(1600)).unwrap();
+    assert_eq!(bundle.mrru, 1600);
+}
+
+#[test]
+fn unbundled_links() {
+    let mut registry = BundleRegistry::default();
+    let cases = [
+        (
+            proxy_lcp(&[discriminator(1, &[0xca, 0xfe])]),
+            BundleError::NotMultilink,
+        ),
+        (
+            proxy_lcp(&[LcpOption::MaximumReceiveReconstructedUnit(1500)]),
+            BundleError::MissingEndpointDiscriminator,
+        ),
+        (
+            proxy_lcp(&[
+                LcpOption::MaximumReceiveReconstructedUnit(1500),
+                discriminator(0, &[]),
+            ]),
+            BundleError::MissingEndpointDiscriminator,
+        ),
+    ];
+    for (proxy_lcp, error) in cases.iter() {
+        assert_eq!(
+            registry.join(1, 10, b"alice", proxy_lcp),
+            Err(error.clone())
+        );
+    }
+    assert_eq!(registry.bundles().count(), 0);
+}
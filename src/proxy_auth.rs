@@ -0,0 +1,18 @@
+//! Types and implementations related to verifying proxy authentication performed by a LAC (RFC 2661 section 4.4.5).
+//!
+//! Support for MS-CHAPv1 requires the `ms-chap` feature.
+
+#[cfg(test)]
+mod tests;
+
+mod credential_store;
+pub use credential_store::*;
+
+mod record;
+pub use record::*;
+
+mod verification;
+pub use verification::*;
+
+#[cfg(feature = "ms-chap")]
+mod ms_chap;
@@ -0,0 +1,23 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// # Summary
+/// A `CredentialStore` provides the clear-text secrets used to verify proxy authentication.
+pub trait CredentialStore {
+    /// # Summary
+    /// Look up the secret of the user with the given name, if known.
+    fn secret(&self, name: &[u8]) -> Option<Vec<u8>>;
+}
+
+impl CredentialStore for HashMap<Vec<u8>, Vec<u8>> {
+    #[inline]
+    fn secret(&self, name: &[u8]) -> Option<Vec<u8>> {
+        self.get(name).cloned()
+    }
+}
+
+impl CredentialStore for BTreeMap<Vec<u8>, Vec<u8>> {
+    #[inline]
+    fn secret(&self, name: &[u8]) -> Option<Vec<u8>> {
+        self.get(name).cloned()
+    }
+}
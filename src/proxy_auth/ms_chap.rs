@@ -0,0 +1,55 @@
+use des::cipher::generic_array::GenericArray;
+use des::cipher::{BlockEncrypt, KeyInit};
+use des::Des;
+use md4::{Digest, Md4};
+
+const NT_RESPONSE_OFFSET: usize = 24;
+const NT_RESPONSE_LENGTH: usize = 24;
+const FLAGS_OFFSET: usize = 48;
+
+/// Compute the NT password hash (RFC 2433 section A.2) of a UTF-8 password.
+pub(crate) fn nt_password_hash(password: &[u8]) -> [u8; 16] {
+    let unicode: Vec<u8> = String::from_utf8_lossy(password)
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    Md4::digest(&unicode).into()
+}
+
+/// Expand a 56-bit key into a 64-bit DES key, leaving the parity bits unset.
+fn des_key(key: &[u8]) -> [u8; 8] {
+    let mut expanded = [0u8; 8];
+    expanded[0] = key[0] >> 1;
+    for i in 1..7 {
+        expanded[i] = ((key[i - 1] << (7 - i)) | (key[i] >> (i + 1))) & 0x7f;
+    }
+    expanded[7] = key[6] & 0x7f;
+    expanded.map(|x| x << 1)
+}
+
+/// Compute the challenge response (RFC 2433 section A.5) for a password hash.
+pub(crate) fn challenge_response(challenge: &[u8; 8], password_hash: &[u8; 16]) -> [u8; 24] {
+    let mut padded = [0u8; 21];
+    padded[..16].copy_from_slice(password_hash);
+
+    let mut response = [0u8; 24];
+    for (key, output) in padded.chunks(7).zip(response.chunks_mut(8)) {
+        let cipher = Des::new(GenericArray::from_slice(&des_key(key)));
+        let mut block = GenericArray::clone_from_slice(challenge);
+        cipher.encrypt_block(&mut block);
+        output.copy_from_slice(&block);
+    }
+    response
+}
+
+/// Verify an MS-CHAPv1 response, returning `None` if only the LAN Manager response is present.
+pub(crate) fn verify_v1(secret: &[u8], challenge: &[u8], response: &[u8]) -> Option<bool> {
+    let challenge: &[u8; 8] = challenge.try_into().ok()?;
+    if response.len() <= FLAGS_OFFSET || response[FLAGS_OFFSET] != 1 {
+        return None;
+    }
+
+    let expected = challenge_response(challenge, &nt_password_hash(secret));
+    let actual = &response[NT_RESPONSE_OFFSET..NT_RESPONSE_OFFSET + NT_RESPONSE_LENGTH];
+    Some(super::verification::constant_time_eq(&expected, actual))
+}
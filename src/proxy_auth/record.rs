@@ -0,0 +1,155 @@
+use crate::avp::types::{MessageType, ProxyAuthenType};
+use crate::avp::AVP;
+use crate::common::ProxyAuthError;
+use crate::ControlMessage;
+
+/// # Summary
+/// A `ProxyAuth` is a typed representation of the proxy authentication AVPs of an `IncomingCallConnected` message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProxyAuth {
+    NoAuthentication,
+    Textual {
+        name: Vec<u8>,
+        password: Vec<u8>,
+    },
+    Pap {
+        name: Vec<u8>,
+        password: Vec<u8>,
+    },
+    Chap {
+        name: Vec<u8>,
+        id: u8,
+        challenge: Vec<u8>,
+        response: Vec<u8>,
+    },
+    MsChapV1 {
+        name: Vec<u8>,
+        id: u8,
+        challenge: Vec<u8>,
+        response: Vec<u8>,
+    },
+}
+
+const TYPE: u16 = 29;
+const NAME: u16 = 30;
+const CHALLENGE: u16 = 31;
+const ID: u16 = 32;
+const RESPONSE: u16 = 33;
+
+const CHAP_RESPONSE_LENGTH: usize = 16;
+const MS_CHAP_CHALLENGE_LENGTH: usize = 8;
+const MS_CHAP_RESPONSE_LENGTH: usize = 49;
+
+#[derive(Default)]
+struct Fields<'a> {
+    authen_type: Option<ProxyAuthenType>,
+    name: Option<&'a [u8]>,
+    challenge: Option<&'a [u8]>,
+    id: Option<u8>,
+    response: Option<&'a [u8]>,
+}
+
+fn require<T>(value: Option<T>, attribute_type: u16) -> Result<T, ProxyAuthError> {
+    value.ok_or(ProxyAuthError::MissingAVP(attribute_type))
+}
+
+fn require_length(value: &[u8], length: usize, attribute_type: u16) -> Result<(), ProxyAuthError> {
+    if value.len() == length {
+        Ok(())
+    } else {
+        Err(ProxyAuthError::InvalidLength(attribute_type, value.len()))
+    }
+}
+
+impl ProxyAuth {
+    /// # Summary
+    /// Attempt to extract a `ProxyAuth` from an `IncomingCallConnected` message.
+    ///
+    /// Returns `Ok(None)` if the message carries no `ProxyAuthenType` AVP. Hidden AVPs must be revealed first.
+    pub fn try_from_message(message: &ControlMessage) -> Result<Option<Self>, ProxyAuthError> {
+        match message.avps.first() {
+            Some(AVP::MessageType(MessageType::IncomingCallConnected)) => {
+                Self::try_from_avps(&message.avps)
+            }
+            _ => Err(ProxyAuthError::UnexpectedMessageType),
+        }
+    }
+
+    /// # Summary
+    /// Attempt to extract a `ProxyAuth` from a set of AVPs.
+    ///
+    /// Returns `Ok(None)` if there is no `ProxyAuthenType` AVP. Hidden AVPs must be revealed first.
+    pub fn try_from_avps(avps: &[AVP]) -> Result<Option<Self>, ProxyAuthError> {
+        let mut fields = Fields::default();
+        for avp in avps.iter() {
+            match avp {
+                AVP::ProxyAuthenType(x) => fields.authen_type = Some(*x),
+                AVP::ProxyAuthenName(x) => fields.name = Some(&x.value),
+                AVP::ProxyAuthenChallenge(x) => fields.challenge = Some(&x.value),
+                AVP::ProxyAuthenId(x) => fields.id = Some(x.value),
+                AVP::ProxyAuthenResponse(x) => fields.response = Some(&x.value),
+                AVP::Hidden(x) if (TYPE..=RESPONSE).contains(&x.attribute_type) => {
+                    return Err(ProxyAuthError::HiddenAVP(x.attribute_type))
+                }
+                _ => (),
+            }
+        }
+
+        let Some(authen_type) = fields.authen_type else {
+            return Ok(None);
+        };
+
+        let record = match authen_type {
+            ProxyAuthenType::NoAuthentication => ProxyAuth::NoAuthentication,
+            ProxyAuthenType::TextualUserNamePasswordExchange => ProxyAuth::Textual {
+                name: require(fields.name, NAME)?.to_owned(),
+                password: require(fields.response, RESPONSE)?.to_owned(),
+            },
+            ProxyAuthenType::PppPap => ProxyAuth::Pap {
+                name: require(fields.name, NAME)?.to_owned(),
+                password: require(fields.response, RESPONSE)?.to_owned(),
+            },
+            ProxyAuthenType::PppChap => {
+                let response = require(fields.response, RESPONSE)?;
+                require_length(response, CHAP_RESPONSE_LENGTH, RESPONSE)?;
+
+                ProxyAuth::Chap {
+                    name: require(fields.name, NAME)?.to_owned(),
+                    id: require(fields.id, ID)?,
+                    challenge: require(fields.challenge, CHALLENGE)?.to_owned(),
+                    response: response.to_owned(),
+                }
+            }
+            ProxyAuthenType::MicrosoftChapVersion1 => {
+                let challenge = require(fields.challenge, CHALLENGE)?;
+                require_length(challenge, MS_CHAP_CHALLENGE_LENGTH, CHALLENGE)?;
+                let response = require(fields.response, RESPONSE)?;
+                require_length(response, MS_CHAP_RESPONSE_LENGTH, RESPONSE)?;
+
+                ProxyAuth::MsChapV1 {
+                    name: require(fields.name, NAME)?.to_owned(),
+                    id: require(fields.id, ID)?,
+                    challenge: challenge.to_owned(),
+                    response: response.to_owned(),
+                }
+            }
+            ProxyAuthenType::Reserved => {
+                return Err(ProxyAuthError::UnsupportedType(authen_type));
+            }
+        };
+
+        Ok(Some(record))
+    }
+
+    /// # Summary
+    /// Get the user name of this `ProxyAuth`, if any.
+    pub fn name(&self) -> Option<&[u8]> {
+        match self {
+            ProxyAuth::NoAuthentication => None,
+            ProxyAuth::Textual { name, .. }
+            | ProxyAuth::Pap { name, .. }
+            | ProxyAuth::Chap { name, .. }
+            | ProxyAuth::MsChapV1 { name, .. } => Some(name),
+        }
+    }
+}
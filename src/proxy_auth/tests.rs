@@ -0,0 +1,197 @@
+use crate::avp::{types, AVP};
+use crate::common::ProxyAuthError;
+use crate::proxy_auth::*;
+use crate::ControlMessage;
+use std::collections::HashMap;
+
+fn store() -> HashMap<Vec<u8>, Vec<u8>> {
+    HashMap::from([
+        (b"alice".to_vec(), b"secret".to_vec()),
+        (b"User".to_vec(), b"clientPass".to_vec()),
+    ])
+}
+
+fn iccn(avps: Vec<AVP>) -> ControlMessage {
+    let mut message = ControlMessage {
+        length: 0,
+        tunnel_id: 1,
+        session_id: 2,
+        ns: 0,
+        nr: 0,
+        avps: vec![AVP::MessageType(types::MessageType::IncomingCallConnected)],
+    };
+    message.avps.extend(avps);
+    message
+}
+
+fn chap_avps(name: &[u8], response: Vec<u8>) -> Vec<AVP> {
+    vec![
+        AVP::ProxyAuthenType(types::ProxyAuthenType::PppChap),
+        AVP::ProxyAuthenName(name.to_vec().into()),
+        AVP::ProxyAuthenChallenge(vec![0x01, 0x02, 0x03, 0x04].into()),
+        AVP::ProxyAuthenId(7.into()),
+        AVP::ProxyAuthenResponse(response.into()),
+    ]
+}
+
+fn chap_response(id: u8, secret: &[u8], challenge: &[u8]) -> Vec<u8> {
+    let mut buffer = vec![id];
+    buffer.extend_from_slice(secret);
+    buffer.extend_from_slice(challenge);
+    md5::compute(&buffer).0.to_vec()
+}
+
+#[test]
+fn chap() {
+    let response = chap_response(7, b"secret", &[0x01, 0x02, 0x03, 0x04]);
+    let record = ProxyAuth::try_from_message(&iccn(chap_avps(b"alice", response)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.name(), Some(&b"alice"[..]));
+    assert_eq!(record.verify(&store()), Verification::Accepted);
+
+    let response = chap_response(7, b"wrong", &[0x01, 0x02, 0x03, 0x04]);
+    let record = ProxyAuth::try_from_avps(&chap_avps(b"alice", response))
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.verify(&store()), Verification::Rejected);
+
+    let response = chap_response(7, b"secret", &[0x01, 0x02, 0x03, 0x04]);
+    let record = ProxyAuth::try_from_avps(&chap_avps(b"mallory", response))
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.verify(&store()), Verification::UnknownUser);
+}
+
+#[test]
+fn pap() {
+    let avps = |password: &[u8]| {
+        vec![
+            AVP::ProxyAuthenType(types::ProxyAuthenType::PppPap),
+            AVP::ProxyAuthenName(b"alice".to_vec().into()),
+            AVP::ProxyAuthenResponse(password.to_vec().into()),
+        ]
+    };
+
+    let record = ProxyAuth::try_from_avps(&avps(b"secret")).unwrap().unwrap();
+    assert_eq!(
+        record,
+        ProxyAuth::Pap {
+            name: b"alice".to_vec(),
+            password: b"secret".to_vec(),
+        }
+    );
+    assert_eq!(record.verify(&store()), Verification::Accepted);
+
+    let record = ProxyAuth::try_from_avps(&avps(b"secret!"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.verify(&store()), Verification::Rejected);
+}
+
+#[test]
+fn no_authentication() {
+    let avps = [AVP::ProxyAuthenType(
+        types::ProxyAuthenType::NoAuthentication,
+    )];
+    let record = ProxyAuth::try_from_avps(&avps).unwrap().unwrap();
+    assert_eq!(record.verify(&store()), Verification::NotAuthenticated);
+
+    assert_eq!(ProxyAuth::try_from_message(&iccn(vec![])), Ok(None));
+}
+
+#[test]
+fn extraction_errors() {
+    let mut message = iccn(vec![]);
+    message.avps[0] = AVP::MessageType(types::MessageType::IncomingCallRequest);
+    assert_eq!(
+        ProxyAuth::try_from_message(&message),
+        Err(ProxyAuthError::UnexpectedMessageType)
+    );
+
+    let mut avps = chap_avps(b"alice", vec![0; 16]);
+    avps.remove(3);
+    assert_eq!(
+        ProxyAuth::try_from_avps(&avps),
+        Err(ProxyAuthError::MissingAVP(32))
+    );
+
+    let avps = chap_avps(b"alice", vec![0; 15]);
+    assert_eq!(
+        ProxyAuth::try_from_avps(&avps),
+        Err(ProxyAuthError::InvalidLength(33, 15))
+    );
+
+    let avps = [
+        AVP::ProxyAuthenType(types::ProxyAuthenType::PppPap),
+        AVP::Hidden(types::Hidden {
+            attribute_type: 30,
+            value: vec![0; 16],
+        }),
+    ];
+    assert_eq!(
+        ProxyAuth::try_from_avps(&avps),
+        Err(ProxyAuthError::HiddenAVP(30))
+    );
+
+    let avps = [AVP::ProxyAuthenType(types::ProxyAuthenType::Reserved)];
+    assert_eq!(
+        ProxyAuth::try_from_avps(&avps),
+        Err(ProxyAuthError::UnsupportedType(
+            types::ProxyAuthenType::Reserved
+        ))
+    );
+}
+
+// Test vector from RFC 2759 section 9.2, whose NT-Response is computed exactly as in MS-CHAPv1
+const MS_CHAP_CHALLENGE: [u8; 8] = [0xd0, 0x2e, 0x43, 0x86, 0xbc, 0xe9, 0x12, 0x26];
+const MS_CHAP_NT_RESPONSE: [u8; 24] = [
+    0x82, 0x30, 0x9e, 0xcd, 0x8d, 0x70, 0x8b, 0x5e, 0xa0, 0x8f, 0xaa, 0x39, 0x81, 0xcd, 0x83, 0x54,
+    0x42, 0x33, 0x11, 0x4a, 0x3d, 0x85, 0xd6, 0xdf,
+];
+
+fn ms_chap_record(nt_response: &[u8; 24], flags: u8) -> ProxyAuth {
+    let mut response = vec![0; 24];
+    response.extend_from_slice(nt_response);
+    response.push(flags);
+
+    let avps = [
+        AVP::ProxyAuthenType(types::ProxyAuthenType::MicrosoftChapVersion1),
+        AVP::ProxyAuthenName(b"User".to_vec().into()),
+        AVP::ProxyAuthenChallenge(MS_CHAP_CHALLENGE.to_vec().into()),
+        AVP::ProxyAuthenId(1.into()),
+        AVP::ProxyAuthenResponse(response.into()),
+    ];
+    ProxyAuth::try_from_avps(&avps).unwrap().unwrap()
+}
+
+#[cfg(feature = "ms-chap")]
+#[test]
+fn ms_chap_v1() {
+    assert_eq!(
+        super::ms_chap::nt_password_hash(b"clientPass"),
+        [
+            0x44, 0xeb, 0xba, 0x8d, 0x53, 0x12, 0xb8, 0xd6, 0x11, 0x47, 0x44, 0x11, 0xf5, 0x69,
+            0x89, 0xae
+        ]
+    );
+
+    let record = ms_chap_record(&MS_CHAP_NT_RESPONSE, 1);
+    assert_eq!(record.verify(&store()), Verification::Accepted);
+
+    let mut nt_response = MS_CHAP_NT_RESPONSE;
+    nt_response[0] ^= 0x01;
+    let record = ms_chap_record(&nt_response, 1);
+    assert_eq!(record.verify(&store()), Verification::Rejected);
+
+    // LAN Manager responses aren't supported
+    let record = ms_chap_record(&MS_CHAP_NT_RESPONSE, 0);
+    assert_eq!(record.verify(&store()), Verification::Unsupported);
+}
+
+#[cfg(not(feature = "ms-chap"))]
+#[test]
+fn ms_chap_v1_unsupported() {
+    let record = ms_chap_record(&MS_CHAP_NT_RESPONSE, 1);
+    assert_eq!(record.verify(&store()), Verification::Unsupported);
+}
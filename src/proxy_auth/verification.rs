@@ -0,0 +1,70 @@
+use crate::proxy_auth::{CredentialStore, ProxyAuth};
+
+/// # Summary
+/// The outcome of verifying a `ProxyAuth` against a `CredentialStore`.
+///
+/// Only `Accepted` allows the LNS to skip authenticating the user itself.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verification {
+    Accepted,
+    Rejected,
+    UnknownUser,
+    NotAuthenticated,
+    Unsupported,
+}
+
+/// Compare two byte strings in time independent of their contents.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn verdict(is_valid: bool) -> Verification {
+    if is_valid {
+        Verification::Accepted
+    } else {
+        Verification::Rejected
+    }
+}
+
+impl ProxyAuth {
+    /// # Summary
+    /// Verify this `ProxyAuth` against the secrets of a `CredentialStore`.
+    pub fn verify(&self, store: &impl CredentialStore) -> Verification {
+        let Some(name) = self.name() else {
+            return Verification::NotAuthenticated;
+        };
+        let Some(secret) = store.secret(name) else {
+            return Verification::UnknownUser;
+        };
+
+        match self {
+            ProxyAuth::NoAuthentication => Verification::NotAuthenticated,
+            ProxyAuth::Textual { password, .. } | ProxyAuth::Pap { password, .. } => {
+                verdict(constant_time_eq(password, &secret))
+            }
+            ProxyAuth::Chap {
+                id,
+                challenge,
+                response,
+                ..
+            } => {
+                let mut buffer = Vec::with_capacity(1 + secret.len() + challenge.len());
+                buffer.push(*id);
+                buffer.extend_from_slice(&secret);
+                buffer.extend_from_slice(challenge);
+                verdict(constant_time_eq(&md5::compute(&buffer).0, response))
+            }
+            #[cfg(feature = "ms-chap")]
+            ProxyAuth::MsChapV1 {
+                challenge,
+                response,
+                ..
+            } => match crate::proxy_auth::ms_chap::verify_v1(&secret, challenge, response) {
+                Some(is_valid) => verdict(is_valid),
+                None => Verification::Unsupported,
+            },
+            #[cfg(not(feature = "ms-chap"))]
+            ProxyAuth::MsChapV1 { .. } => Verification::Unsupported,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Types and implementations related to the session-level semantics of L2TP control messages.
+//!
+//! A `Session` performs no I/O of its own. Incoming control messages are passed to `Session::handle`, which yields
+//! typed `SessionEvent`s, and outgoing control messages are returned to the caller for transmission on the tunnel.
+
+#[cfg(test)]
+mod tests;
+
+mod event;
+pub use event::*;
+
+mod statistics;
+pub use statistics::*;
+
+mod sequencer;
+pub use sequencer::*;
+
+mod state;
+pub use state::*;
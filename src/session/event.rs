@@ -0,0 +1,67 @@
+use crate::avp::types::{
+    Accm, CallErrors, ModemOnHoldStatus, PppDisconnectCauseCode, Q931CauseCode, ResultCode,
+};
+use crate::avp::AVP;
+
+/// # Summary
+/// The reason a call was disconnected, as carried in a `CallDisconnectNotify` message.
+///
+/// # Data members
+/// * `result_code` - The result code of the disconnect.
+/// * `q931_cause_code` - The optional Q.931 cause code of the disconnect.
+/// * `ppp_disconnect_cause_code` - The optional PPP disconnect cause code of the disconnect.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisconnectCause {
+    pub result_code: ResultCode,
+    pub q931_cause_code: Option<Q931CauseCode>,
+    pub ppp_disconnect_cause_code: Option<PppDisconnectCauseCode>,
+}
+
+impl DisconnectCause {
+    /// Extract a `DisconnectCause` from the AVPs of a `CallDisconnectNotify` message, if it contains a result code.
+    pub(crate) fn from_avps(avps: &[AVP]) -> Option<Self> {
+        let mut result_code = None;
+        let mut q931_cause_code = None;
+        let mut ppp_disconnect_cause_code = None;
+        for avp in avps.iter() {
+            match avp {
+                AVP::ResultCode(x) => result_code = Some(x.clone()),
+                AVP::Q931CauseCode(x) => q931_cause_code = Some(x.clone()),
+                AVP::PppDisconnectCauseCode(x) => ppp_disconnect_cause_code = Some(x.clone()),
+                _ => (),
+            }
+        }
+
+        Some(Self {
+            result_code: result_code?,
+            q931_cause_code,
+            ppp_disconnect_cause_code,
+        })
+    }
+
+    /// Get the AVPs representing this `DisconnectCause`.
+    pub(crate) fn to_avps(&self) -> Vec<AVP> {
+        let mut avps = vec![AVP::ResultCode(self.result_code.clone())];
+        if let Some(x) = &self.q931_cause_code {
+            avps.push(AVP::Q931CauseCode(x.clone()));
+        }
+        if let Some(x) = &self.ppp_disconnect_cause_code {
+            avps.push(AVP::PppDisconnectCauseCode(x.clone()));
+        }
+        avps
+    }
+}
+
+/// # Summary
+/// A `SessionEvent` is the typed outcome of a control message handled by a `Session`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionEvent {
+    /// New ACCM values were received in a `SetLinkInfo` message.
+    SetLinkInfo(Accm),
+    /// Cumulative error counters were received in a `WanErrorNotify` message.
+    WanErrorNotify(CallErrors),
+    /// The call was disconnected by the peer with a `CallDisconnectNotify` message.
+    CallDisconnectNotify(DisconnectCause),
+    /// The modem of the call was put on hold or resumed, as signalled in a `ModemStatus` message.
+    ModemStatus(ModemOnHoldStatus),
+}
@@ -0,0 +1,186 @@
+use crate::avp::AVP;
+use crate::session::SequencerStatistics;
+use crate::{ControlMessage, DataMessage};
+use std::time::{Duration, Instant};
+
+/// # Summary
+/// Options for the reorder buffer of a `DataSequencer`.
+///
+/// # Data members
+/// * `capacity` - The maximum number of data messages held while waiting for a missing one. Zero disables
+///   reordering, in which case missing messages are given up on immediately.
+/// * `timeout` - The maximum time a data message is held while waiting for a missing one.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReorderOptions {
+    pub capacity: usize,
+    pub timeout: Duration,
+}
+
+struct Held<T> {
+    ns: u16,
+    received: Instant,
+    message: DataMessage<T>,
+}
+
+/// # Summary
+/// A `DataSequencer` handles the Ns field of the data messages of a session for which sequencing is in use.
+///
+/// Outgoing messages are stamped with consecutive Ns values. Incoming messages are delivered in order, optionally
+/// holding messages received ahead of a missing one in a reorder buffer. The sequencer performs no I/O and keeps
+/// no timers of its own: the caller supplies the current time and calls `poll` no later than `next_deadline`.
+pub struct DataSequencer<T> {
+    options: ReorderOptions,
+    next_ns: u16,
+    expected_ns: Option<u16>,
+    held: Vec<Held<T>>,
+    statistics: SequencerStatistics,
+}
+
+impl<T> DataSequencer<T> {
+    /// # Summary
+    /// Create a `DataSequencer`.
+    #[inline]
+    pub fn new(options: ReorderOptions) -> Self {
+        Self {
+            options,
+            next_ns: 0,
+            expected_ns: None,
+            held: Vec::new(),
+            statistics: SequencerStatistics::default(),
+        }
+    }
+
+    /// # Summary
+    /// Indicate whether a call establishment message requires sequencing of data messages.
+    #[inline]
+    pub fn is_required(message: &ControlMessage) -> bool {
+        message
+            .avps
+            .iter()
+            .any(|avp| matches!(avp, AVP::SequencingRequired(_)))
+    }
+
+    /// # Summary
+    /// Get the statistics of incoming data messages.
+    #[inline]
+    pub fn statistics(&self) -> &SequencerStatistics {
+        &self.statistics
+    }
+
+    /// # Summary
+    /// Stamp an outgoing data message with the next Ns value. The Nr field is reserved for data messages and set to
+    /// zero.
+    #[inline]
+    pub fn stamp(&mut self, message: &mut DataMessage<T>) {
+        message.ns_nr = Some((self.next_ns, 0));
+        self.next_ns = self.next_ns.wrapping_add(1);
+    }
+
+    /// Signed distance of `ns` from the expected Ns in sequence number space.
+    fn distance(&self, ns: u16) -> i16 {
+        ns.wrapping_sub(self.expected_ns.unwrap_or(ns)) as i16
+    }
+
+    fn deliver_in_order(&mut self, delivered: &mut Vec<DataMessage<T>>) {
+        while let Some(expected_ns) = self.expected_ns {
+            if self.held.first().is_none_or(|x| x.ns != expected_ns) {
+                break;
+            }
+            delivered.push(self.held.remove(0).message);
+            self.expected_ns = Some(expected_ns.wrapping_add(1));
+        }
+    }
+
+    fn skip_to_first_held(&mut self, delivered: &mut Vec<DataMessage<T>>) {
+        if let Some(first) = self.held.first() {
+            debug!(
+                expected_ns = self.expected_ns,
+                ns = first.ns,
+                "Giving up on missing data messages"
+            );
+            self.statistics.lost += self.distance(first.ns) as u64;
+            self.expected_ns = Some(first.ns);
+            self.deliver_in_order(delivered);
+        }
+    }
+
+    /// # Summary
+    /// Handle an incoming data message received at time `now`, returning the messages that can be delivered in
+    /// order as a result.
+    ///
+    /// Messages without sequence numbers are delivered immediately.
+    pub fn receive(&mut self, message: DataMessage<T>, now: Instant) -> Vec<DataMessage<T>> {
+        let Some((ns, _)) = message.ns_nr else {
+            self.statistics.unsequenced += 1;
+            return vec![message];
+        };
+        self.statistics.received += 1;
+
+        let expected_ns = *self.expected_ns.get_or_insert(ns);
+        let distance = self.distance(ns);
+        let mut delivered = Vec::new();
+
+        if distance < 0 {
+            trace!(ns, expected_ns, "Discarding late data message");
+            self.statistics.late += 1;
+        } else if distance == 0 {
+            delivered.push(message);
+            self.expected_ns = Some(expected_ns.wrapping_add(1));
+            self.deliver_in_order(&mut delivered);
+        } else if self.options.capacity == 0 {
+            self.statistics.out_of_order += 1;
+            self.statistics.lost += distance as u64;
+            delivered.push(message);
+            self.expected_ns = Some(ns.wrapping_add(1));
+        } else {
+            let position = self
+                .held
+                .binary_search_by_key(&distance, |x| self.distance(x.ns));
+            match position {
+                Ok(_) => self.statistics.duplicates += 1,
+                Err(position) => {
+                    self.statistics.out_of_order += 1;
+                    self.held.insert(
+                        position,
+                        Held {
+                            ns,
+                            received: now,
+                            message,
+                        },
+                    );
+                }
+            }
+
+            while self.held.len() > self.options.capacity {
+                self.skip_to_first_held(&mut delivered);
+            }
+        }
+
+        delivered
+    }
+
+    /// # Summary
+    /// Give up on missing messages for which held messages have waited longer than the reorder timeout, returning
+    /// the messages that can be delivered in order as a result.
+    pub fn poll(&mut self, now: Instant) -> Vec<DataMessage<T>> {
+        let mut delivered = Vec::new();
+        while self
+            .held
+            .iter()
+            .any(|x| now.saturating_duration_since(x.received) >= self.options.timeout)
+        {
+            self.skip_to_first_held(&mut delivered);
+        }
+        delivered
+    }
+
+    /// # Summary
+    /// Get the time at which `poll` must next be called, if any messages are held.
+    #[inline]
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.held
+            .iter()
+            .map(|x| x.received + self.options.timeout)
+            .min()
+    }
+}
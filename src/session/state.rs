@@ -0,0 +1,595 @@
+use crate::avp::types::{
+    result_code::CdnCode, Accm, CallErrors, ConnectSpeedUpdate, MessageType, ModemOnHoldStatus,
+    PhbId, ResultCode,
+};
+use crate::avp::AVP;
+use crate::common::{SessionError, SessionResult, SliceReader};
+use crate::ppp::{Frame, Protocol};
+use crate::session::{DisconnectCause, SessionEvent, SessionStatistics};
+use crate::tunnel::MessageStatistics;
+use crate::{ControlMessage, DataMessage};
+use core::borrow::Borrow;
+use std::time::{Duration, Instant};
+
+/// # Summary
+/// The call state of a `Session`, as tracked from the call management messages passing through its `Tunnel`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SessionState {
+    #[default]
+    Idle,
+    Establishing,
+    Established,
+    /// The modem of an established call is on hold (RFC 3573).
+    OnHold,
+    Closed,
+}
+
+impl SessionState {
+    /// # Summary
+    /// Get the state following this one after sending or receiving a message of the given `MessageType`.
+    ///
+    /// Ignorable message types leave the state unchanged, see `MessageType::is_ignorable`.
+    #[inline]
+    pub fn next(self, message_type: MessageType) -> Self {
+        use MessageType::*;
+        match message_type {
+            IncomingCallRequest
+            | IncomingCallReply
+            | OutgoingCallRequest
+            | OutgoingCallReply
+            | MulticastSessionResponse
+                if self == Self::Idle =>
+            {
+                Self::Establishing
+            }
+            IncomingCallConnected | OutgoingCallConnected | MulticastSessionEstablishment
+                if self != Self::Closed =>
+            {
+                Self::Established
+            }
+            CallDisconnectNotify | MulticastSessionEndNotify => Self::Closed,
+            _ => self,
+        }
+    }
+}
+
+/// # Summary
+/// A `Session` holds the state of a single L2TP session within a tunnel.
+///
+/// Outgoing messages have their tunnel and session identifiers set, while NS and NR are left for the tunnel to
+/// assign.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    tunnel_id: u16,
+    local_session_id: u16,
+    remote_session_id: u16,
+    state: SessionState,
+    accm: Option<Accm>,
+    tx_connect_speed: Option<u64>,
+    rx_connect_speed: Option<u64>,
+    phbs: Vec<PhbId>,
+    prioritize_lcp: bool,
+    hold_timeout: Option<Duration>,
+    held_since: Option<Instant>,
+    last_data_ns: Option<u16>,
+    statistics: SessionStatistics,
+}
+
+impl Session {
+    /// # Summary
+    /// Create a `Session`.
+    ///
+    /// # Parameters
+    /// * `tunnel_id` - The tunnel identifier assigned by the peer.
+    /// * `local_session_id` - The session identifier assigned locally.
+    /// * `remote_session_id` - The session identifier assigned by the peer.
+    #[inline]
+    pub fn new(tunnel_id: u16, local_session_id: u16, remote_session_id: u16) -> Self {
+        Self {
+            tunnel_id,
+            local_session_id,
+            remote_session_id,
+            state: SessionState::Idle,
+            accm: None,
+            tx_connect_speed: None,
+            rx_connect_speed: None,
+            phbs: Vec::new(),
+            prioritize_lcp: false,
+            hold_timeout: None,
+            held_since: None,
+            last_data_ns: None,
+            statistics: SessionStatistics::default(),
+        }
+    }
+
+    /// # Summary
+    /// Get the tunnel identifier assigned by the peer.
+    #[inline]
+    pub fn tunnel_id(&self) -> u16 {
+        self.tunnel_id
+    }
+
+    /// # Summary
+    /// Get the session identifier assigned locally.
+    #[inline]
+    pub fn local_session_id(&self) -> u16 {
+        self.local_session_id
+    }
+
+    /// # Summary
+    /// Get the session identifier assigned by the peer.
+    #[inline]
+    pub fn remote_session_id(&self) -> u16 {
+        self.remote_session_id
+    }
+
+    /// # Summary
+    /// Get the call state of this `Session`.
+    #[inline]
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    /// # Summary
+    /// Get the most recently sent or received ACCM values.
+    #[inline]
+    pub fn accm(&self) -> Option<Accm> {
+        self.accm
+    }
+
+    /// # Summary
+    /// Get the most recently announced transmit connect speed in bits per second.
+    ///
+    /// Speeds announced at call setup are superseded by those of connect speed updates (RFC 5515).
+    #[inline]
+    pub fn tx_connect_speed(&self) -> Option<u64> {
+        self.tx_connect_speed
+    }
+
+    /// # Summary
+    /// Get the most recently announced receive connect speed in bits per second.
+    ///
+    /// Speeds announced at call setup are superseded by those of connect speed updates (RFC 5515).
+    #[inline]
+    pub fn rx_connect_speed(&self) -> Option<u64> {
+        self.rx_connect_speed
+    }
+
+    /// # Summary
+    /// Set the current connect speeds in bits per second, e.g. after the rate of a DSL line changed.
+    ///
+    /// The speeds are announced to the peer by `Tunnel::connect_speed_update_notification`.
+    #[inline]
+    pub fn set_connect_speeds(&mut self, tx_connect_speed: u64, rx_connect_speed: u64) {
+        debug!(
+            tunnel_id = self.tunnel_id,
+            session_id = self.local_session_id,
+            tx_connect_speed,
+            rx_connect_speed,
+            "Connect speeds changed"
+        );
+        self.tx_connect_speed = Some(tx_connect_speed);
+        self.rx_connect_speed = Some(rx_connect_speed);
+    }
+
+    /// Get a `ConnectSpeedUpdate` AVP announcing the current connect speeds to the peer.
+    #[inline]
+    pub(crate) fn connect_speed_update(&self) -> ConnectSpeedUpdate {
+        ConnectSpeedUpdate {
+            remote_session_id: self.remote_session_id,
+            tx_connect_speed: self.tx_connect_speed.unwrap_or_default(),
+            rx_connect_speed: self.rx_connect_speed.unwrap_or_default(),
+        }
+    }
+
+    /// Record a `ConnectSpeedUpdate` AVP received from the peer.
+    #[inline]
+    pub(crate) fn record_connect_speed_update(&mut self, update: &ConnectSpeedUpdate) {
+        self.tx_connect_speed = Some(update.tx_connect_speed);
+        self.rx_connect_speed = Some(update.rx_connect_speed);
+    }
+
+    /// # Summary
+    /// Get the PHBs most recently requested or accepted for the data messages of this session (RFC 3308).
+    #[inline]
+    pub fn phbs(&self) -> &[PhbId] {
+        &self.phbs
+    }
+
+    /// # Summary
+    /// Get the DSCP to mark the IP packets carrying the data messages of this session with, i.e. that of the first
+    /// negotiated PHB identified by a DSCP.
+    ///
+    /// Marking is left to the transport sending the messages produced by `Session::data_message`.
+    #[inline]
+    pub fn dscp(&self) -> Option<u8> {
+        self.phbs.iter().find_map(PhbId::dscp)
+    }
+
+    /// # Summary
+    /// Indicate whether LCP frames of this session are sent as prioritized data messages.
+    #[inline]
+    pub fn prioritize_lcp(&self) -> bool {
+        self.prioritize_lcp
+    }
+
+    /// # Summary
+    /// Set whether LCP frames of this session, such as echo requests used as keepalives, are sent as prioritized data
+    /// messages, so the peer can treat them preferentially under congestion.
+    #[inline]
+    pub fn set_prioritize_lcp(&mut self, prioritize_lcp: bool) {
+        self.prioritize_lcp = prioritize_lcp;
+    }
+
+    /// # Summary
+    /// Create a `DataMessage` carrying a PPP frame on this session.
+    ///
+    /// The P bit is set for LCP frames if enabled with `Session::set_prioritize_lcp`. Ns is left for a `Sequencer` to
+    /// stamp if sequencing is required.
+    pub fn data_message<T: Borrow<[u8]>>(&self, frame: T) -> DataMessage<T> {
+        let is_prioritized = self.prioritize_lcp
+            && Frame::try_read(&mut SliceReader::from(frame.borrow()))
+                .is_ok_and(|x| x.protocol == Protocol::Lcp);
+        DataMessage {
+            is_prioritized,
+            length: None,
+            tunnel_id: self.tunnel_id,
+            session_id: self.remote_session_id,
+            ns_nr: None,
+            offset: None,
+            data: frame,
+        }
+    }
+
+    /// # Summary
+    /// Get the maximum time this session may be on hold, i.e. the smaller of the modem-on-hold timeouts announced at
+    /// call setup (RFC 3573).
+    #[inline]
+    pub fn hold_timeout(&self) -> Option<Duration> {
+        self.hold_timeout
+    }
+
+    /// # Summary
+    /// Get the time at which a call on hold is to be disconnected, if it is on hold and a hold timeout was
+    /// announced.
+    ///
+    /// The hold timer starts when the `ModemStatus` message putting the call on hold is handled, see `handle`.
+    #[inline]
+    pub fn hold_deadline(&self) -> Option<Instant> {
+        Some(self.held_since? + self.hold_timeout?)
+    }
+
+    /// Put this `Session` on hold or resume it, as signalled by a `ModemStatus` message.
+    fn set_on_hold(&mut self, on_hold: bool) {
+        match self.state {
+            SessionState::Established if on_hold => {
+                self.state = SessionState::OnHold;
+            }
+            SessionState::OnHold if !on_hold => {
+                self.state = SessionState::Established;
+                self.held_since = None;
+            }
+            _ => (),
+        }
+    }
+
+    /// # Summary
+    /// Get the statistics of this `Session`.
+    #[inline]
+    pub fn statistics(&self) -> &SessionStatistics {
+        &self.statistics
+    }
+
+    fn message(&self, message_type: MessageType, avps: Vec<AVP>) -> SessionResult<ControlMessage> {
+        let builder = avps.into_iter().fold(
+            ControlMessage::builder(message_type)
+                .tunnel(self.tunnel_id)
+                .session(self.remote_session_id),
+            |builder, avp| builder.avp(avp),
+        );
+        Ok(builder.build()?)
+    }
+
+    /// # Summary
+    /// Update the ACCM values of this session, returning a `SetLinkInfo` message for the LAC if they changed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tunnel_id = self.tunnel_id, session_id = self.local_session_id)
+        )
+    )]
+    pub fn set_accm(&mut self, accm: Accm) -> SessionResult<Option<ControlMessage>> {
+        if self.accm == Some(accm) {
+            return Ok(None);
+        }
+
+        let message = self.message(MessageType::SetLinkInfo, vec![AVP::Accm(accm)])?;
+        debug!(?accm, "Sending Set-Link-Info");
+        self.accm = Some(accm);
+        self.statistics.set_link_info_sent += 1;
+
+        Ok(Some(message))
+    }
+
+    /// # Summary
+    /// Create a `WanErrorNotify` message reporting cumulative error counters to the LNS.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tunnel_id = self.tunnel_id, session_id = self.local_session_id)
+        )
+    )]
+    pub fn wan_error_notify(&mut self, call_errors: CallErrors) -> SessionResult<ControlMessage> {
+        let message = self.message(
+            MessageType::WanErrorNotify,
+            vec![AVP::CallErrors(call_errors.clone())],
+        )?;
+        debug!(?call_errors, "Sending WAN-Error-Notify");
+        self.statistics.call_errors = call_errors;
+        self.statistics.wan_error_notify_sent += 1;
+
+        Ok(message)
+    }
+
+    /// # Summary
+    /// Create a `CallDisconnectNotify` message tearing down this session.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tunnel_id = self.tunnel_id, session_id = self.local_session_id)
+        )
+    )]
+    pub fn call_disconnect_notify(
+        &mut self,
+        cause: DisconnectCause,
+    ) -> SessionResult<ControlMessage> {
+        let mut avps = cause.to_avps();
+        avps.insert(0, AVP::AssignedSessionId(self.local_session_id.into()));
+        let message = self.message(MessageType::CallDisconnectNotify, avps)?;
+        debug!(?cause, "Sending Call-Disconnect-Notify");
+        self.statistics.disconnect_cause = Some(cause);
+
+        Ok(message)
+    }
+
+    /// # Summary
+    /// Create a `ModemStatus` message signalling the LNS that the modem of this call was put on hold or resumed,
+    /// instead of disconnecting it.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tunnel_id = self.tunnel_id, session_id = self.local_session_id)
+        )
+    )]
+    pub fn modem_status(&mut self, on_hold: bool) -> SessionResult<ControlMessage> {
+        let message = self.message(
+            MessageType::ModemStatus,
+            vec![AVP::ModemOnHoldStatus(ModemOnHoldStatus { on_hold })],
+        )?;
+        debug!(on_hold, "Sending Modem-Status");
+
+        Ok(message)
+    }
+
+    /// # Summary
+    /// Check the hold timer of this session at time `now`, returning a `CallDisconnectNotify` message if the call
+    /// has been on hold for longer than the hold timeout.
+    ///
+    /// A call put on hold by a received `ModemStatus` message is considered on hold from the time passed to
+    /// `handle`. A call put on hold by a sent `ModemStatus` message is considered on hold from the first call of
+    /// this function onwards, so it should be called after writing such a message.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tunnel_id = self.tunnel_id, session_id = self.local_session_id)
+        )
+    )]
+    pub fn poll(&mut self, now: Instant) -> SessionResult<Option<ControlMessage>> {
+        if self.state == SessionState::OnHold && self.held_since.is_none() {
+            self.held_since = Some(now);
+        }
+        if self.hold_deadline().is_none_or(|deadline| now < deadline) {
+            return Ok(None);
+        }
+
+        debug!("Hold timeout expired");
+        self.held_since = None;
+        let cause = DisconnectCause {
+            result_code: ResultCode {
+                code: CdnCode::CallDisconnectedAdministrative.into(),
+                error: None,
+            },
+            q931_cause_code: None,
+            ppp_disconnect_cause_code: None,
+        };
+        Ok(Some(self.call_disconnect_notify(cause)?))
+    }
+
+    /// # Summary
+    /// Handle a control message addressed to this session.
+    ///
+    /// Hidden AVPs must be revealed before calling this function.
+    ///
+    /// Ignorable messages, see `MessageType::is_ignorable`, yield no event and only need to be acknowledged.
+    ///
+    /// # Parameters
+    /// * `message` - The received control message.
+    /// * `now` - The time at which the message was received, used to start the hold timer.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tunnel_id = self.tunnel_id, session_id = self.local_session_id)
+        )
+    )]
+    pub fn handle(
+        &mut self,
+        message: &ControlMessage,
+        now: Instant,
+    ) -> SessionResult<Option<SessionEvent>> {
+        let message_type = message
+            .message_type()
+            .ok_or(SessionError::MissingMessageType)?;
+
+        if message.session_id != self.local_session_id {
+            return Err(SessionError::WrongSession(message.session_id));
+        }
+
+        match message_type {
+            MessageType::SetLinkInfo => {
+                let accm = message
+                    .avps
+                    .iter()
+                    .find_map(|avp| match avp {
+                        AVP::Accm(accm) => Some(*accm),
+                        _ => None,
+                    })
+                    .ok_or(SessionError::MissingAVP(message_type, Accm::ATTRIBUTE_TYPE))?;
+
+                debug!(?accm, "Received Set-Link-Info");
+                self.accm = Some(accm);
+                self.statistics.set_link_info_received += 1;
+
+                Ok(Some(SessionEvent::SetLinkInfo(accm)))
+            }
+            MessageType::WanErrorNotify => {
+                let call_errors = message
+                    .avps
+                    .iter()
+                    .find_map(|avp| match avp {
+                        AVP::CallErrors(call_errors) => Some(call_errors.clone()),
+                        _ => None,
+                    })
+                    .ok_or(SessionError::MissingAVP(
+                        message_type,
+                        CallErrors::ATTRIBUTE_TYPE,
+                    ))?;
+
+                debug!(?call_errors, "Received WAN-Error-Notify");
+                self.statistics.call_errors = call_errors.clone();
+                self.statistics.wan_error_notify_received += 1;
+
+                Ok(Some(SessionEvent::WanErrorNotify(call_errors)))
+            }
+            MessageType::CallDisconnectNotify => {
+                let cause = DisconnectCause::from_avps(&message.avps).ok_or(
+                    SessionError::MissingAVP(message_type, ResultCode::ATTRIBUTE_TYPE),
+                )?;
+
+                debug!(?cause, "Received Call-Disconnect-Notify");
+                self.statistics.disconnect_cause = Some(cause.clone());
+
+                Ok(Some(SessionEvent::CallDisconnectNotify(cause)))
+            }
+            MessageType::ModemStatus => {
+                let status = message
+                    .avps
+                    .iter()
+                    .find_map(|avp| match avp {
+                        AVP::ModemOnHoldStatus(x) => Some(*x),
+                        _ => None,
+                    })
+                    .ok_or(SessionError::MissingAVP(
+                        message_type,
+                        ModemOnHoldStatus::ATTRIBUTE_TYPE,
+                    ))?;
+
+                debug!(?status, "Received Modem-Status");
+                self.set_on_hold(status.on_hold);
+                if self.state == SessionState::OnHold && self.held_since.is_none() {
+                    self.held_since = Some(now);
+                }
+                Ok(Some(SessionEvent::ModemStatus(status)))
+            }
+            message_type if message_type.is_ignorable() => {
+                debug!(?message_type, "Ignoring control message");
+                Ok(None)
+            }
+            _ => {
+                debug!(?message_type, "Unhandled control message");
+                Err(SessionError::UnhandledMessageType(message_type))
+            }
+        }
+    }
+
+    fn record_avps(&mut self, message_type: MessageType, avps: &[AVP]) {
+        if message_type == MessageType::CallDisconnectNotify {
+            if let Some(cause) = DisconnectCause::from_avps(avps) {
+                self.statistics.disconnect_cause = Some(cause);
+            }
+        }
+
+        for avp in avps.iter() {
+            match avp {
+                AVP::TxConnectSpeed(x) => self.tx_connect_speed = Some(x.value as u64),
+                AVP::RxConnectSpeed(x) => self.rx_connect_speed = Some(x.value as u64),
+                AVP::SessionDs(x) => self.phbs = x.phbs.clone(),
+                AVP::ModemOnHoldCapable(x) => {
+                    let timeout = Duration::from_secs(x.timeout.into());
+                    self.hold_timeout = Some(self.hold_timeout.map_or(timeout, |t| t.min(timeout)));
+                }
+                AVP::ModemOnHoldStatus(x) if message_type == MessageType::ModemStatus => {
+                    self.set_on_hold(x.on_hold)
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Consider this `Session` established after its tunnel was recovered from a failover.
+    #[inline]
+    pub(crate) fn recover(&mut self) {
+        if self.state != SessionState::Closed {
+            self.state = SessionState::Established;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn record_control_sent(&mut self, message_type: MessageType, avps: &[AVP]) {
+        self.state = self.state.next(message_type);
+        self.record_avps(message_type, avps);
+        MessageStatistics::record_control(&mut self.statistics.messages.control_sent, message_type);
+    }
+
+    #[inline]
+    pub(crate) fn record_control_received(&mut self, message_type: MessageType, avps: &[AVP]) {
+        self.state = self.state.next(message_type);
+        self.record_avps(message_type, avps);
+        MessageStatistics::record_control(
+            &mut self.statistics.messages.control_received,
+            message_type,
+        );
+    }
+
+    #[inline]
+    pub(crate) fn record_data_sent(&mut self, length: u64) {
+        self.statistics.messages.data_packets_sent += 1;
+        self.statistics.messages.data_bytes_sent += length;
+    }
+
+    pub(crate) fn record_data_received(&mut self, ns_nr: Option<(u16, u16)>, length: u64) {
+        self.statistics.messages.data_packets_received += 1;
+        self.statistics.messages.data_bytes_received += length;
+
+        if let Some((ns, _)) = ns_nr {
+            if self
+                .last_data_ns
+                .is_some_and(|last| ns != last.wrapping_add(1))
+            {
+                self.statistics.data_sequence_errors += 1;
+            }
+            self.last_data_ns = Some(ns);
+        }
+    }
+}
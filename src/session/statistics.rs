@@ -0,0 +1,49 @@
+use crate::avp::types::CallErrors;
+use crate::session::DisconnectCause;
+use crate::tunnel::MessageStatistics;
+
+/// # Summary
+/// Per-session statistics maintained by a `Session`.
+///
+/// # Data members
+/// * `set_link_info_sent` - The number of `SetLinkInfo` messages sent.
+/// * `set_link_info_received` - The number of `SetLinkInfo` messages received.
+/// * `wan_error_notify_sent` - The number of `WanErrorNotify` messages sent.
+/// * `wan_error_notify_received` - The number of `WanErrorNotify` messages received.
+/// * `call_errors` - The most recently reported error counters, which are cumulative since the call was established.
+/// * `messages` - Counters of messages sent and received on the session, as recorded by its `Tunnel`.
+/// * `data_sequence_errors` - The number of data messages received with an Ns other than the one following the
+///   previous data message.
+/// * `disconnect_cause` - The reason the call was disconnected, from the `CallDisconnectNotify` message sent or
+///   received.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SessionStatistics {
+    pub set_link_info_sent: u64,
+    pub set_link_info_received: u64,
+    pub wan_error_notify_sent: u64,
+    pub wan_error_notify_received: u64,
+    pub call_errors: CallErrors,
+    pub messages: MessageStatistics,
+    pub data_sequence_errors: u64,
+    pub disconnect_cause: Option<DisconnectCause>,
+}
+
+/// # Summary
+/// Statistics of incoming data messages maintained by a `DataSequencer`.
+///
+/// # Data members
+/// * `received` - The number of sequenced data messages received.
+/// * `unsequenced` - The number of data messages received without sequence numbers.
+/// * `out_of_order` - The number of data messages received ahead of a missing one.
+/// * `duplicates` - The number of data messages received while a message with the same Ns was held for reordering.
+/// * `late` - The number of data messages received after their Ns was delivered or given up on.
+/// * `lost` - The number of sequence numbers given up on.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SequencerStatistics {
+    pub received: u64,
+    pub unsequenced: u64,
+    pub out_of_order: u64,
+    pub duplicates: u64,
+    pub late: u64,
+    pub lost: u64,
+}
@@ -0,0 +1,302 @@
+mod sequencer;
+
+use crate::avp::types::{
+    result_code, Accm, CallErrors, MessageType, ModemOnHoldCapable, ModemOnHoldStatus, PhbId,
+    PppDisconnectCauseCode, PppDisconnectCode, PppDisconnectDirection, ResultCode, SessionDs,
+};
+use crate::avp::AVP;
+use crate::common::{SessionError, SliceReader, VecWriter};
+use crate::session::*;
+use crate::{ControlMessage, Message};
+use std::time::{Duration, Instant};
+
+const ACCM: Accm = Accm {
+    send_accm: [0x00, 0x0a, 0x00, 0x00],
+    receive_accm: [0x00, 0x00, 0x00, 0x00],
+};
+
+fn call_errors() -> CallErrors {
+    CallErrors {
+        crc_errors: 1,
+        framing_errors: 2,
+        hardware_overruns: 3,
+        buffer_overruns: 4,
+        timeout_errors: 5,
+        alignment_errors: 6,
+    }
+}
+
+/// Write and read back a message, as if it had been sent to the peer.
+fn transmit(message: ControlMessage) -> ControlMessage {
+    let mut w = VecWriter::new();
+    Message::<Vec<u8>>::Control(message)
+        .try_write(&mut w)
+        .unwrap();
+    match Message::try_read(&mut SliceReader::from(&w.data)).unwrap() {
+        Message::Control(control) => control,
+        Message::Data(_) => panic!("Unexpected data message"),
+    }
+}
+
+#[test]
+fn set_link_info() {
+    let mut lns = Session::new(10, 1, 2);
+    let mut lac = Session::new(20, 2, 1);
+
+    let message = lns.set_accm(ACCM).unwrap().unwrap();
+    assert_eq!(message.message_type(), Some(MessageType::SetLinkInfo));
+    assert_eq!(message.tunnel_id, 10);
+    assert_eq!(message.session_id, 2);
+
+    // Unchanged values aren't sent again
+    assert_eq!(lns.set_accm(ACCM), Ok(None));
+    assert_eq!(lns.statistics().set_link_info_sent, 1);
+
+    assert_eq!(
+        lac.handle(&transmit(message), Instant::now()),
+        Ok(Some(SessionEvent::SetLinkInfo(ACCM)))
+    );
+    assert_eq!(lac.accm(), Some(ACCM));
+    assert_eq!(lac.statistics().set_link_info_received, 1);
+}
+
+#[test]
+fn wan_error_notify() {
+    let mut lns = Session::new(10, 1, 2);
+    let mut lac = Session::new(20, 2, 1);
+
+    let message = lac.wan_error_notify(call_errors()).unwrap();
+    assert_eq!(message.message_type(), Some(MessageType::WanErrorNotify));
+    assert_eq!(
+        lns.handle(&transmit(message), Instant::now()),
+        Ok(Some(SessionEvent::WanErrorNotify(call_errors())))
+    );
+
+    let mut later = call_errors();
+    later.crc_errors = 10;
+    lns.handle(
+        &transmit(lac.wan_error_notify(later.clone()).unwrap()),
+        Instant::now(),
+    )
+    .unwrap();
+
+    let statistics = lns.statistics();
+    assert_eq!(statistics.wan_error_notify_received, 2);
+    assert_eq!(statistics.call_errors, later);
+    assert_eq!(lac.statistics().wan_error_notify_sent, 2);
+}
+
+#[test]
+fn call_disconnect_notify() {
+    let mut lns = Session::new(10, 1, 2);
+    let mut lac = Session::new(20, 2, 1);
+
+    let cause = DisconnectCause {
+        result_code: ResultCode {
+            code: result_code::CdnCode::CallDisconnectedWithErrorCode.into(),
+            error: None,
+        },
+        q931_cause_code: None,
+        ppp_disconnect_cause_code: Some(PppDisconnectCauseCode {
+            disconnect_code: PppDisconnectCode::NoRecognizableLcpPackets.into(),
+            control_protocol_number: 0xc021,
+            direction: PppDisconnectDirection::AtLocal.into(),
+            message: None,
+        }),
+    };
+
+    let message = lac.call_disconnect_notify(cause.clone()).unwrap();
+    assert_eq!(
+        message.message_type(),
+        Some(MessageType::CallDisconnectNotify)
+    );
+    assert_eq!(message.avps[1], AVP::AssignedSessionId(2.into()));
+    assert_eq!(
+        lns.handle(&transmit(message), Instant::now()),
+        Ok(Some(SessionEvent::CallDisconnectNotify(cause.clone())))
+    );
+
+    let received = lns.statistics().disconnect_cause.as_ref().unwrap();
+    let ppp = received.ppp_disconnect_cause_code.as_ref().unwrap();
+    assert_eq!(
+        ppp.as_disconnect_code(),
+        PppDisconnectCode::NoRecognizableLcpPackets
+    );
+    assert_eq!(ppp.as_direction(), Ok(PppDisconnectDirection::AtLocal));
+    assert_eq!(lac.statistics().disconnect_cause, Some(cause));
+}
+
+#[test]
+fn handle_ignorable() {
+    let mut session = Session::new(10, 1, 2);
+    let message = ControlMessage {
+        length: 0,
+        tunnel_id: 20,
+        session_id: 1,
+        ns: 0,
+        nr: 0,
+        avps: vec![AVP::MessageType(MessageType::from_code(30))],
+    };
+
+    assert_eq!(session.handle(&message, Instant::now()), Ok(None));
+    assert_eq!(session.state(), SessionState::Idle);
+}
+
+#[test]
+fn handle_errors() {
+    let mut session = Session::new(10, 1, 2);
+    let message = |session_id, avps: Vec<AVP>| ControlMessage {
+        length: 0,
+        tunnel_id: 20,
+        session_id,
+        ns: 0,
+        nr: 0,
+        avps,
+    };
+
+    assert_eq!(
+        session.handle(&message(1, vec![]), Instant::now()),
+        Err(SessionError::MissingMessageType)
+    );
+    assert_eq!(
+        session.handle(
+            &message(3, vec![AVP::MessageType(MessageType::SetLinkInfo)]),
+            Instant::now()
+        ),
+        Err(SessionError::WrongSession(3))
+    );
+    assert_eq!(
+        session.handle(
+            &message(1, vec![AVP::MessageType(MessageType::SetLinkInfo)]),
+            Instant::now()
+        ),
+        Err(SessionError::MissingAVP(MessageType::SetLinkInfo, 35))
+    );
+    assert_eq!(
+        session.handle(
+            &message(1, vec![AVP::MessageType(MessageType::WanErrorNotify)]),
+            Instant::now()
+        ),
+        Err(SessionError::MissingAVP(MessageType::WanErrorNotify, 34))
+    );
+    assert_eq!(
+        session.handle(
+            &message(1, vec![AVP::MessageType(MessageType::CallDisconnectNotify)]),
+            Instant::now()
+        ),
+        Err(SessionError::MissingAVP(
+            MessageType::CallDisconnectNotify,
+            1
+        ))
+    );
+    assert_eq!(
+        session.handle(
+            &message(1, vec![AVP::MessageType(MessageType::ModemStatus)]),
+            Instant::now()
+        ),
+        Err(SessionError::MissingAVP(
+            MessageType::ModemStatus,
+            ModemOnHoldStatus::ATTRIBUTE_TYPE
+        ))
+    );
+    assert_eq!(
+        session.handle(
+            &message(1, vec![AVP::MessageType(MessageType::Hello)]),
+            Instant::now()
+        ),
+        Err(SessionError::UnhandledMessageType(MessageType::Hello))
+    );
+}
+
+#[test]
+fn differentiated_services() {
+    let mut session = Session::new(2, 1, 3);
+    assert_eq!(session.dscp(), None);
+
+    let phbs = vec![PhbId(0x1337), PhbId::from_dscp(46)];
+    session.record_control_received(
+        MessageType::IncomingCallReply,
+        &[AVP::SessionDs(SessionDs { phbs: phbs.clone() })],
+    );
+    assert_eq!(session.phbs(), phbs);
+    assert_eq!(session.dscp(), Some(46));
+
+    let echo_request = [0xff, 0x03, 0xc0, 0x21, 0x09, 0x01, 0x00, 0x08, 0, 0, 0, 0];
+    let ipv4 = [0xff, 0x03, 0x00, 0x21, 0x45, 0x00];
+    assert!(!session.data_message(&echo_request[..]).is_prioritized);
+
+    session.set_prioritize_lcp(true);
+    let message = session.data_message(&echo_request[..]);
+    assert!(message.is_prioritized);
+    assert_eq!(message.tunnel_id, 2);
+    assert_eq!(message.session_id, 3);
+    assert!(!session.data_message(&ipv4[..]).is_prioritized);
+}
+
+#[test]
+fn modem_on_hold() {
+    let mut lac = Session::new(2, 1, 3);
+    let mut lns = Session::new(4, 3, 1);
+    lac.record_control_sent(
+        MessageType::IncomingCallRequest,
+        &[AVP::ModemOnHoldCapable(ModemOnHoldCapable { timeout: 60 })],
+    );
+    lns.record_control_received(
+        MessageType::IncomingCallRequest,
+        &[AVP::ModemOnHoldCapable(ModemOnHoldCapable { timeout: 60 })],
+    );
+    lns.record_control_sent(
+        MessageType::IncomingCallReply,
+        &[AVP::ModemOnHoldCapable(ModemOnHoldCapable { timeout: 30 })],
+    );
+    lns.record_control_received(MessageType::IncomingCallConnected, &[]);
+    assert_eq!(lns.hold_timeout(), Some(Duration::from_secs(30)));
+    assert_eq!(lns.hold_deadline(), None);
+
+    // The hold timer starts when the Modem-Status message is handled
+    let held = Instant::now();
+    let hold = transmit(lac.modem_status(true).unwrap());
+    assert_eq!(hold.session_id, 3);
+    assert_eq!(
+        lns.handle(&hold, held),
+        Ok(Some(SessionEvent::ModemStatus(ModemOnHoldStatus {
+            on_hold: true
+        })))
+    );
+    lns.record_control_received(MessageType::ModemStatus, &hold.avps);
+    assert_eq!(lns.state(), SessionState::OnHold);
+    let deadline = lns.hold_deadline().unwrap();
+    assert_eq!(deadline, held + Duration::from_secs(30));
+    assert_eq!(lns.poll(held + Duration::from_secs(1)), Ok(None));
+    assert_eq!(lns.hold_deadline(), Some(deadline));
+
+    let resume = transmit(lac.modem_status(false).unwrap());
+    lns.record_control_received(MessageType::ModemStatus, &resume.avps);
+    assert_eq!(lns.state(), SessionState::Established);
+    assert_eq!(lns.hold_deadline(), None);
+    assert_eq!(lns.poll(deadline), Ok(None));
+
+    // A call on hold for too long is disconnected
+    lns.record_control_received(MessageType::ModemStatus, &hold.avps);
+    assert_eq!(lns.poll(deadline), Ok(None));
+    let deadline = lns.hold_deadline().unwrap();
+    assert_eq!(lns.poll(deadline - Duration::from_secs(1)), Ok(None));
+    let message = lns.poll(deadline).unwrap().unwrap();
+    assert_eq!(
+        message.message_type(),
+        Some(MessageType::CallDisconnectNotify)
+    );
+    assert_eq!(lns.poll(deadline), Ok(None));
+
+    // A late first poll still times out relative to the start of the hold
+    lns.record_control_received(MessageType::ModemStatus, &resume.avps);
+    lns.handle(&hold, deadline).unwrap();
+    let message = lns
+        .poll(deadline + Duration::from_secs(40))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        message.message_type(),
+        Some(MessageType::CallDisconnectNotify)
+    );
+}
@@ -0,0 +1,145 @@
+use crate::session::*;
+use crate::DataMessage;
+use std::time::{Duration, Instant};
+
+fn message(ns: u16) -> DataMessage<Vec<u8>> {
+    DataMessage {
+        is_prioritized: false,
+        length: None,
+        tunnel_id: 1,
+        session_id: 2,
+        ns_nr: Some((ns, 0)),
+        offset: None,
+        data: vec![ns as u8],
+    }
+}
+
+fn ns_of(messages: Vec<DataMessage<Vec<u8>>>) -> Vec<u16> {
+    messages.iter().map(|x| x.ns_nr.unwrap().0).collect()
+}
+
+fn reordering() -> DataSequencer<Vec<u8>> {
+    DataSequencer::new(ReorderOptions {
+        capacity: 4,
+        timeout: Duration::from_millis(100),
+    })
+}
+
+#[test]
+fn stamp() {
+    let mut sequencer = DataSequencer::new(ReorderOptions::default());
+    let mut m = message(0);
+    m.ns_nr = None;
+
+    for ns in [0, 1, 2] {
+        sequencer.stamp(&mut m);
+        assert_eq!(m.ns_nr, Some((ns, 0)));
+    }
+}
+
+#[test]
+fn in_order_with_wraparound() {
+    let mut sequencer = reordering();
+    let now = Instant::now();
+
+    let mut delivered = Vec::new();
+    for ns in [65534, 65535, 0, 1] {
+        delivered.extend(sequencer.receive(message(ns), now));
+    }
+    assert_eq!(ns_of(delivered), [65534, 65535, 0, 1]);
+    assert_eq!(sequencer.statistics().received, 4);
+    assert_eq!(sequencer.statistics().lost, 0);
+    assert_eq!(sequencer.next_deadline(), None);
+}
+
+#[test]
+fn reorder() {
+    let mut sequencer = reordering();
+    let now = Instant::now();
+
+    assert_eq!(ns_of(sequencer.receive(message(0), now)), [0]);
+    assert_eq!(ns_of(sequencer.receive(message(2), now)), []);
+    assert_eq!(ns_of(sequencer.receive(message(3), now)), []);
+    assert_eq!(ns_of(sequencer.receive(message(3), now)), []);
+    assert_eq!(ns_of(sequencer.receive(message(1), now)), [1, 2, 3]);
+    assert_eq!(ns_of(sequencer.receive(message(2), now)), []);
+
+    let statistics = sequencer.statistics();
+    assert_eq!(statistics.out_of_order, 2);
+    assert_eq!(statistics.duplicates, 1);
+    assert_eq!(statistics.late, 1);
+    assert_eq!(statistics.lost, 0);
+}
+
+#[test]
+fn reorder_timeout() {
+    let mut sequencer = reordering();
+    let now = Instant::now();
+
+    sequencer.receive(message(10), now);
+    sequencer.receive(message(12), now);
+    sequencer.receive(message(15), now + Duration::from_millis(50));
+    assert_eq!(
+        sequencer.next_deadline(),
+        Some(now + Duration::from_millis(100))
+    );
+
+    assert_eq!(ns_of(sequencer.poll(now + Duration::from_millis(99))), []);
+    assert_eq!(
+        ns_of(sequencer.poll(now + Duration::from_millis(100))),
+        [12]
+    );
+    assert_eq!(
+        ns_of(sequencer.poll(now + Duration::from_millis(150))),
+        [15]
+    );
+    assert_eq!(sequencer.statistics().lost, 3);
+    assert_eq!(sequencer.next_deadline(), None);
+
+    // Messages given up on are late if they arrive after all
+    sequencer.receive(message(11), now + Duration::from_millis(200));
+    assert_eq!(sequencer.statistics().late, 1);
+}
+
+#[test]
+fn reorder_capacity() {
+    let mut sequencer = reordering();
+    let now = Instant::now();
+
+    sequencer.receive(message(0), now);
+    for ns in [2, 3, 5, 6] {
+        assert_eq!(ns_of(sequencer.receive(message(ns), now)), []);
+    }
+    assert_eq!(ns_of(sequencer.receive(message(7), now)), [2, 3]);
+    assert_eq!(sequencer.statistics().lost, 1);
+}
+
+#[test]
+fn without_reordering() {
+    let mut sequencer = DataSequencer::new(ReorderOptions::default());
+    let now = Instant::now();
+
+    assert_eq!(ns_of(sequencer.receive(message(0), now)), [0]);
+    assert_eq!(ns_of(sequencer.receive(message(3), now)), [3]);
+    assert_eq!(ns_of(sequencer.receive(message(2), now)), []);
+
+    let mut unsequenced = message(0);
+    unsequenced.ns_nr = None;
+    assert_eq!(sequencer.receive(unsequenced, now).len(), 1);
+
+    let statistics = sequencer.statistics();
+    assert_eq!(statistics.lost, 2);
+    assert_eq!(statistics.late, 1);
+    assert_eq!(statistics.unsequenced, 1);
+}
+
+#[test]
+fn is_required() {
+    use crate::avp::{types, AVP};
+
+    let message = crate::ControlMessage::builder(types::MessageType::IncomingCallConnected)
+        .avp(AVP::SequencingRequired(types::SequencingRequired::default()))
+        .build()
+        .unwrap();
+    assert!(DataSequencer::<Vec<u8>>::is_required(&message));
+}
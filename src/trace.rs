@@ -0,0 +1,32 @@
+//! Crate-internal macros which forward to `tracing` when the `tracing` feature is enabled and expand to nothing
+//! otherwise.
+//!
+//! Arguments aren't evaluated when the feature is disabled, so they must be free of side effects. Code which only
+//! computes values for logging should itself be conditional on the feature.
+
+#[allow(unused_macros)]
+macro_rules! trace {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!($($arg)*);
+    }};
+}
+
+#[allow(unused_macros)]
+macro_rules! debug {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($arg)*);
+    }};
+}
+
+#[allow(unused_macros)]
+macro_rules! span {
+    ($level:ident, $name:literal $(, $($field:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::span!(::tracing::Level::$level, $name $(, $($field)*)?).entered();
+    };
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests;
@@ -0,0 +1,98 @@
+use crate::common::SliceReader;
+use crate::Message;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// A subscriber which records the messages of all events and the names of all spans.
+#[derive(Clone, Default)]
+struct Recorder {
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut records = self.records.lock().unwrap();
+        records.push(format!("span {}", span.metadata().name()));
+        Id::from_u64(records.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = MessageVisitor(String::new());
+        event.record(&mut visitor);
+        self.records
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", event.metadata().level(), visitor.0));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn record(f: impl FnOnce()) -> Vec<String> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    let records = recorder.records.lock().unwrap().clone();
+    records
+}
+
+#[test]
+fn control_message() {
+    let input = [
+        0x13, 0x20, 0x00, 0x14, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0x05, // Header
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, // Hello
+    ];
+    let records = record(|| {
+        Message::try_read(&mut SliceReader::from(&input)).unwrap();
+    });
+
+    assert_eq!(records, ["span control_message", "TRACE Read AVP"]);
+}
+
+#[test]
+fn malformed_message() {
+    let records = record(|| {
+        assert!(Message::try_read(&mut SliceReader::from(&[0x13])).is_err());
+    });
+
+    assert_eq!(records, ["DEBUG Failed to read message"]);
+}
+
+#[test]
+fn session() {
+    use crate::avp::types::Accm;
+    use crate::session::Session;
+
+    let mut session = Session::new(1, 2, 3);
+    let records = record(|| {
+        session
+            .set_accm(Accm {
+                send_accm: [0; 4],
+                receive_accm: [0; 4],
+            })
+            .unwrap();
+    });
+
+    assert_eq!(records, ["span set_accm", "DEBUG Sending Set-Link-Info"]);
+}
@@ -0,0 +1,16 @@
+//! Types and implementations related to the tunnel-level handling of L2TP messages.
+//!
+//! Like a `Session`, a `Tunnel` performs no I/O of its own. Messages are read and written through it so that it can
+//! maintain statistics with the full context of each message.
+
+#[cfg(test)]
+mod tests;
+
+mod peer;
+pub use peer::*;
+
+mod statistics;
+pub use statistics::*;
+
+mod state;
+pub use state::*;
@@ -0,0 +1,59 @@
+use crate::avp::types::{
+    BearerCapabilities, ControlConnectionDs, FailoverCapability, FramingCapabilities, MessageType,
+    MulticastCapability, ProtocolVersion,
+};
+use crate::avp::AVP;
+
+/// # Summary
+/// Information about the peer of a `Tunnel`, as announced in its `StartControlConnectionRequest` or
+/// `StartControlConnectionReply`.
+///
+/// # Data members
+/// * `protocol_version` - The protocol version of the peer.
+/// * `host_name` - The host name of the peer.
+/// * `vendor_name` - The vendor name of the peer.
+/// * `firmware_revision` - The firmware revision of the peer.
+/// * `framing_capabilities` - The framing capabilities of the peer.
+/// * `bearer_capabilities` - The bearer capabilities of the peer.
+/// * `failover_capability` - The failover capability of the peer (RFC 4951).
+/// * `control_connection_ds` - The PHB requested by the peer for the control connection (RFC 3308).
+/// * `multicast_capability` - The multicast capability of the peer (RFC 4045).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PeerInfo {
+    pub protocol_version: Option<ProtocolVersion>,
+    pub host_name: Option<Vec<u8>>,
+    pub vendor_name: Option<String>,
+    pub firmware_revision: Option<u16>,
+    pub framing_capabilities: Option<FramingCapabilities>,
+    pub bearer_capabilities: Option<BearerCapabilities>,
+    pub failover_capability: Option<FailoverCapability>,
+    pub control_connection_ds: Option<ControlConnectionDs>,
+    pub multicast_capability: Option<MulticastCapability>,
+}
+
+impl PeerInfo {
+    /// Update this `PeerInfo` from the AVPs of a received message, if it is a control connection request or reply.
+    pub(crate) fn update(&mut self, message_type: MessageType, avps: &[AVP]) {
+        if !matches!(
+            message_type,
+            MessageType::StartControlConnectionRequest | MessageType::StartControlConnectionReply
+        ) {
+            return;
+        }
+
+        for avp in avps.iter() {
+            match avp {
+                AVP::ProtocolVersion(x) => self.protocol_version = Some(*x),
+                AVP::HostName(x) => self.host_name = Some(x.value.clone()),
+                AVP::VendorName(x) => self.vendor_name = Some(x.value.clone()),
+                AVP::FirmwareRevision(x) => self.firmware_revision = Some(x.value),
+                AVP::FramingCapabilities(x) => self.framing_capabilities = Some(*x),
+                AVP::BearerCapabilities(x) => self.bearer_capabilities = Some(*x),
+                AVP::FailoverCapability(x) => self.failover_capability = Some(*x),
+                AVP::ControlConnectionDs(x) => self.control_connection_ds = Some(*x),
+                AVP::MulticastCapability(x) => self.multicast_capability = Some(*x),
+                _ => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,722 @@
+use crate::avp::types::{
+    ConnectSpeedUpdate, FailoverSessionState, MessageType, NewOutgoingSessions,
+    SuggestedControlSequence, TunnelRecovery, WithdrawOutgoingSessions,
+};
+use crate::avp::AVP;
+use crate::common::{BuildResult, DecodeError, EncodeResult, Reader, Writer};
+use crate::session::Session;
+use crate::tunnel::{
+    MessageStatistics, PeerInfo, SessionSnapshot, TunnelSnapshot, TunnelStatistics,
+};
+use crate::{ControlMessage, Message};
+use core::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// # Summary
+/// The state of a `Tunnel`, as tracked from the control connection management messages passing through it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TunnelState {
+    #[default]
+    Idle,
+    Establishing,
+    Established,
+    Closed,
+}
+
+impl TunnelState {
+    /// # Summary
+    /// Get the state following this one after sending or receiving a message of the given `MessageType`.
+    ///
+    /// Ignorable message types leave the state unchanged, see `MessageType::is_ignorable`.
+    #[inline]
+    pub fn next(self, message_type: MessageType) -> Self {
+        use MessageType::*;
+        match message_type {
+            StartControlConnectionRequest | StartControlConnectionReply if self == Self::Idle => {
+                Self::Establishing
+            }
+            StartControlConnectionConnected if self != Self::Closed => Self::Established,
+            StopControlConnectionNotification => Self::Closed,
+            _ => self,
+        }
+    }
+}
+
+/// Get the result code of a teardown message, if it is one.
+fn teardown_result_code(message_type: MessageType, avps: &[AVP]) -> Option<u16> {
+    match message_type {
+        MessageType::StopControlConnectionNotification | MessageType::CallDisconnectNotify => {
+            avps.iter().find_map(|avp| match avp {
+                AVP::ResultCode(result_code) => Some(result_code.code.into()),
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// # Summary
+/// A `Tunnel` holds the state of a single L2TP tunnel and its sessions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tunnel {
+    local_tunnel_id: u16,
+    remote_tunnel_id: u16,
+    state: TunnelState,
+    next_ns: u16,
+    expected_ns: u16,
+    peer: PeerInfo,
+    sessions: BTreeMap<u16, Session>,
+    multicast_session_id: Option<u16>,
+    multicast_members: BTreeSet<u16>,
+    statistics: TunnelStatistics,
+}
+
+impl Tunnel {
+    /// # Summary
+    /// Create a `Tunnel`.
+    ///
+    /// # Parameters
+    /// * `local_tunnel_id` - The tunnel identifier assigned locally.
+    /// * `remote_tunnel_id` - The tunnel identifier assigned by the peer.
+    #[inline]
+    pub fn new(local_tunnel_id: u16, remote_tunnel_id: u16) -> Self {
+        Self {
+            local_tunnel_id,
+            remote_tunnel_id,
+            state: TunnelState::Idle,
+            next_ns: 0,
+            expected_ns: 0,
+            peer: PeerInfo::default(),
+            sessions: BTreeMap::new(),
+            multicast_session_id: None,
+            multicast_members: BTreeSet::new(),
+            statistics: TunnelStatistics::default(),
+        }
+    }
+
+    /// # Summary
+    /// Get the tunnel identifier assigned locally.
+    #[inline]
+    pub fn local_tunnel_id(&self) -> u16 {
+        self.local_tunnel_id
+    }
+
+    /// # Summary
+    /// Get the tunnel identifier assigned by the peer.
+    #[inline]
+    pub fn remote_tunnel_id(&self) -> u16 {
+        self.remote_tunnel_id
+    }
+
+    /// # Summary
+    /// Get the state of this `Tunnel`.
+    #[inline]
+    pub fn state(&self) -> TunnelState {
+        self.state
+    }
+
+    /// # Summary
+    /// Get the Ns of the next non-ZLB control message written through this `Tunnel`.
+    #[inline]
+    pub fn next_ns(&self) -> u16 {
+        self.next_ns
+    }
+
+    /// # Summary
+    /// Get the Ns expected of the next non-ZLB control message read through this `Tunnel`.
+    #[inline]
+    pub fn expected_ns(&self) -> u16 {
+        self.expected_ns
+    }
+
+    /// # Summary
+    /// Get the information announced by the peer of this `Tunnel`.
+    #[inline]
+    pub fn peer(&self) -> &PeerInfo {
+        &self.peer
+    }
+
+    /// # Summary
+    /// Create a `Session` in this tunnel, replacing any existing session with the same local identifier.
+    pub fn add_session(&mut self, local_session_id: u16, remote_session_id: u16) -> &mut Session {
+        let session = Session::new(self.remote_tunnel_id, local_session_id, remote_session_id);
+        self.sessions.insert(local_session_id, session);
+        self.sessions.get_mut(&local_session_id).unwrap()
+    }
+
+    /// # Summary
+    /// Remove a session from this tunnel.
+    #[inline]
+    pub fn remove_session(&mut self, local_session_id: u16) -> Option<Session> {
+        if self.multicast_session_id == Some(local_session_id) {
+            self.multicast_session_id = None;
+            self.multicast_members.clear();
+        }
+        self.multicast_members.remove(&local_session_id);
+        self.sessions.remove(&local_session_id)
+    }
+
+    /// # Summary
+    /// Get a session by its locally assigned identifier.
+    #[inline]
+    pub fn session(&self, local_session_id: u16) -> Option<&Session> {
+        self.sessions.get(&local_session_id)
+    }
+
+    /// # Summary
+    /// Get a mutable session by its locally assigned identifier.
+    #[inline]
+    pub fn session_mut(&mut self, local_session_id: u16) -> Option<&mut Session> {
+        self.sessions.get_mut(&local_session_id)
+    }
+
+    /// # Summary
+    /// Get an iterator over the sessions of this tunnel.
+    #[inline]
+    pub fn sessions(&self) -> impl Iterator<Item = &Session> {
+        self.sessions.values()
+    }
+
+    /// # Summary
+    /// Create the multicast session of this tunnel (RFC 4045), replacing any existing session with the same local
+    /// identifier.
+    ///
+    /// A tunnel carries at most one multicast session, so a previous multicast session is removed.
+    pub fn add_multicast_session(
+        &mut self,
+        local_session_id: u16,
+        remote_session_id: u16,
+    ) -> &mut Session {
+        if let Some(id) = self.multicast_session_id {
+            self.remove_session(id);
+        }
+        self.multicast_members.remove(&local_session_id);
+        self.multicast_session_id = Some(local_session_id);
+        self.add_session(local_session_id, remote_session_id)
+    }
+
+    /// # Summary
+    /// Get the multicast session of this tunnel.
+    #[inline]
+    pub fn multicast_session(&self) -> Option<&Session> {
+        self.sessions.get(&self.multicast_session_id?)
+    }
+
+    /// # Summary
+    /// Get the locally assigned identifiers of the unicast sessions the multicast session of this tunnel is
+    /// replicated to.
+    #[inline]
+    pub fn multicast_members(&self) -> impl Iterator<Item = u16> + '_ {
+        self.multicast_members.iter().copied()
+    }
+
+    /// # Summary
+    /// Create a `MulticastSessionInformation` message updating the sessions the multicast session of this tunnel is
+    /// replicated to, or `None` if the tunnel has no multicast session.
+    ///
+    /// Membership takes effect once the message is written through this tunnel.
+    ///
+    /// # Parameters
+    /// * `added` - The locally assigned identifiers of the sessions to add. Unknown sessions are skipped.
+    /// * `withdrawn` - The locally assigned identifiers of the sessions to remove. Unknown sessions are skipped.
+    pub fn multicast_session_information(
+        &self,
+        added: &[u16],
+        withdrawn: &[u16],
+    ) -> BuildResult<Option<ControlMessage>> {
+        let Some(multicast_session) = self.multicast_session() else {
+            return Ok(None);
+        };
+        let remote_session_ids = |ids| {
+            self.sessions_by_ids(ids)
+                .map(Session::remote_session_id)
+                .collect::<Vec<_>>()
+        };
+
+        let mut builder = ControlMessage::builder(MessageType::MulticastSessionInformation)
+            .tunnel(self.remote_tunnel_id)
+            .session(multicast_session.remote_session_id());
+        let added = remote_session_ids(added);
+        if !added.is_empty() {
+            builder = builder.avp(AVP::NewOutgoingSessions(NewOutgoingSessions {
+                session_ids: added,
+            }));
+        }
+        let withdrawn = remote_session_ids(withdrawn);
+        if !withdrawn.is_empty() {
+            builder = builder.avp(AVP::WithdrawOutgoingSessions(WithdrawOutgoingSessions {
+                session_ids: withdrawn,
+            }));
+        }
+        builder.build().map(Some)
+    }
+
+    /// Update the members of the multicast session from a message of a session, whose AVPs refer to sessions by
+    /// the identifiers `local_session_id` maps to local ones.
+    fn record_multicast_members(
+        &mut self,
+        message_type: MessageType,
+        session_id: Option<u16>,
+        avps: &[AVP],
+        local_session_id: impl Fn(&Self, u16) -> Option<u16>,
+    ) {
+        match message_type {
+            MessageType::CallDisconnectNotify => {
+                if let Some(id) = session_id {
+                    self.multicast_members.remove(&id);
+                }
+            }
+            MessageType::MulticastSessionEndNotify if session_id == self.multicast_session_id => {
+                self.multicast_members.clear();
+            }
+            MessageType::MulticastSessionInformation
+                if session_id.is_some() && session_id == self.multicast_session_id =>
+            {
+                for avp in avps.iter() {
+                    match avp {
+                        AVP::NewOutgoingSessions(x) => {
+                            let added: Vec<u16> = x
+                                .session_ids
+                                .iter()
+                                .filter_map(|id| local_session_id(self, *id))
+                                .filter(|id| Some(*id) != self.multicast_session_id)
+                                .collect();
+                            self.multicast_members.extend(added);
+                        }
+                        AVP::WithdrawOutgoingSessions(x) => {
+                            for id in x.session_ids.iter() {
+                                if let Some(id) = local_session_id(self, *id) {
+                                    self.multicast_members.remove(&id);
+                                }
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// # Summary
+    /// Get the per-tunnel statistics.
+    #[inline]
+    pub fn statistics(&self) -> &TunnelStatistics {
+        &self.statistics
+    }
+
+    /// # Summary
+    /// Take a point-in-time copy of the statistics of this tunnel and its sessions.
+    pub fn snapshot(&self) -> TunnelSnapshot {
+        TunnelSnapshot {
+            tunnel_id: self.local_tunnel_id,
+            remote_tunnel_id: self.remote_tunnel_id,
+            state: self.state,
+            peer: self.peer.clone(),
+            tunnel: self.statistics.clone(),
+            sessions: self
+                .sessions
+                .iter()
+                .map(|(id, session)| {
+                    (
+                        *id,
+                        SessionSnapshot {
+                            remote_session_id: session.remote_session_id(),
+                            state: session.state(),
+                            tx_connect_speed: session.tx_connect_speed(),
+                            rx_connect_speed: session.rx_connect_speed(),
+                            statistics: session.statistics().clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// # Summary
+    /// Create a `ConnectSpeedUpdateRequest` message asking the peer for the current connect speeds of the given
+    /// sessions, which the peer answers with a `ConnectSpeedUpdateNotification`.
+    ///
+    /// # Parameters
+    /// * `local_session_ids` - The locally assigned identifiers of the sessions. Unknown sessions are skipped.
+    pub fn connect_speed_update_request(
+        &self,
+        local_session_ids: &[u16],
+    ) -> BuildResult<ControlMessage> {
+        let avps = self
+            .sessions_by_ids(local_session_ids)
+            .map(|session| ConnectSpeedUpdate {
+                tx_connect_speed: 0,
+                rx_connect_speed: 0,
+                ..session.connect_speed_update()
+            });
+        self.connect_speed_update_message(MessageType::ConnectSpeedUpdateRequest, avps)
+    }
+
+    /// # Summary
+    /// Create a `ConnectSpeedUpdateNotification` message announcing the current connect speeds of the given sessions,
+    /// as set with `Session::set_connect_speeds`.
+    ///
+    /// # Parameters
+    /// * `local_session_ids` - The locally assigned identifiers of the sessions. Unknown sessions are skipped.
+    pub fn connect_speed_update_notification(
+        &self,
+        local_session_ids: &[u16],
+    ) -> BuildResult<ControlMessage> {
+        let avps = self
+            .sessions_by_ids(local_session_ids)
+            .map(Session::connect_speed_update);
+        self.connect_speed_update_message(MessageType::ConnectSpeedUpdateNotification, avps)
+    }
+
+    fn sessions_by_ids<'a>(
+        &'a self,
+        local_session_ids: &'a [u16],
+    ) -> impl Iterator<Item = &'a Session> + 'a {
+        local_session_ids
+            .iter()
+            .filter_map(|id| self.sessions.get(id))
+    }
+
+    fn connect_speed_update_message(
+        &self,
+        message_type: MessageType,
+        updates: impl Iterator<Item = ConnectSpeedUpdate>,
+    ) -> BuildResult<ControlMessage> {
+        updates
+            .fold(
+                ControlMessage::builder(message_type).tunnel(self.remote_tunnel_id),
+                |builder, update| builder.avp(AVP::ConnectSpeedUpdate(update)),
+            )
+            .build()
+    }
+
+    /// # Summary
+    /// Get the `TunnelRecovery` AVP identifying this tunnel, to be included in the `StartControlConnectionRequest` of
+    /// a recovery tunnel after this endpoint restarted (RFC 4951).
+    #[inline]
+    pub fn tunnel_recovery(&self) -> TunnelRecovery {
+        TunnelRecovery {
+            recover_tunnel_id: self.local_tunnel_id,
+            recover_remote_tunnel_id: self.remote_tunnel_id,
+        }
+    }
+
+    /// # Summary
+    /// Check whether a `TunnelRecovery` AVP received from a restarted peer refers to this tunnel.
+    #[inline]
+    pub fn is_recovered_by(&self, recovery: &TunnelRecovery) -> bool {
+        recovery.recover_tunnel_id == self.remote_tunnel_id
+            && recovery.recover_remote_tunnel_id == self.local_tunnel_id
+    }
+
+    /// # Summary
+    /// Get the `SuggestedControlSequence` AVP a restarted peer should continue this tunnel with, to be included in
+    /// the `StartControlConnectionReply` of the recovery tunnel.
+    #[inline]
+    pub fn suggested_control_sequence(&self) -> SuggestedControlSequence {
+        SuggestedControlSequence {
+            suggested_ns: self.expected_ns,
+            suggested_nr: self.next_ns,
+        }
+    }
+
+    /// # Summary
+    /// Resume this tunnel after a restart of this endpoint, instead of tearing it and its sessions down.
+    ///
+    /// The control channel continues with the sequence numbers suggested by the peer, and the tunnel and its
+    /// sessions are considered established. Sessions the peer no longer knows about are found with a
+    /// `FailoverSessionQuery`.
+    ///
+    /// # Parameters
+    /// * `sequence` - The sequence numbers suggested by the peer in the reply on the recovery tunnel.
+    pub fn resynchronise(&mut self, sequence: &SuggestedControlSequence) {
+        debug!(
+            ns = sequence.suggested_ns,
+            nr = sequence.suggested_nr,
+            "Resynchronised recovered tunnel"
+        );
+        self.next_ns = sequence.suggested_ns;
+        self.expected_ns = sequence.suggested_nr;
+        if self.state != TunnelState::Closed {
+            self.state = TunnelState::Established;
+        }
+        self.sessions.values_mut().for_each(Session::recover);
+    }
+
+    /// # Summary
+    /// Create a `FailoverSessionQuery` message asking the peer whether the given sessions still exist after a
+    /// failover, which the peer answers with a `FailoverSessionResponse`.
+    ///
+    /// # Parameters
+    /// * `local_session_ids` - The locally assigned identifiers of the sessions. Unknown sessions are skipped.
+    pub fn failover_session_query(&self, local_session_ids: &[u16]) -> BuildResult<ControlMessage> {
+        let states = self
+            .sessions_by_ids(local_session_ids)
+            .map(|session| FailoverSessionState {
+                sender_session_id: session.local_session_id(),
+                receiver_session_id: session.remote_session_id(),
+            });
+        self.failover_session_message(MessageType::FailoverSessionQuery, states)
+    }
+
+    /// # Summary
+    /// Create the `FailoverSessionResponse` message answering a `FailoverSessionQuery` of the peer.
+    ///
+    /// Sessions unknown to this tunnel are reported with a sender session identifier of zero, so the peer can clear
+    /// them.
+    pub fn failover_session_response(&self, query: &ControlMessage) -> BuildResult<ControlMessage> {
+        let states = query.avps.iter().filter_map(|avp| match avp {
+            AVP::FailoverSessionState(x) => Some(FailoverSessionState {
+                sender_session_id: self
+                    .sessions
+                    .get(&x.receiver_session_id)
+                    .filter(|session| session.remote_session_id() == x.sender_session_id)
+                    .map_or(0, Session::local_session_id),
+                receiver_session_id: x.sender_session_id,
+            }),
+            _ => None,
+        });
+        self.failover_session_message(MessageType::FailoverSessionResponse, states)
+    }
+
+    /// # Summary
+    /// Handle a `FailoverSessionResponse` of the peer, removing the sessions it no longer knows about.
+    ///
+    /// Returns the locally assigned identifiers of the removed sessions.
+    pub fn handle_failover_session_response(&mut self, response: &ControlMessage) -> Vec<u16> {
+        response
+            .avps
+            .iter()
+            .filter_map(|avp| match avp {
+                AVP::FailoverSessionState(x) if x.sender_session_id == 0 => {
+                    self.remove_session(x.receiver_session_id)?;
+                    debug!(
+                        session_id = x.receiver_session_id,
+                        "Cleared session unknown to peer after failover"
+                    );
+                    Some(x.receiver_session_id)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn failover_session_message(
+        &self,
+        message_type: MessageType,
+        states: impl Iterator<Item = FailoverSessionState>,
+    ) -> BuildResult<ControlMessage> {
+        states
+            .fold(
+                ControlMessage::builder(message_type).tunnel(self.remote_tunnel_id),
+                |builder, state| builder.avp(AVP::FailoverSessionState(state)),
+            )
+            .build()
+    }
+
+    /// # Summary
+    /// Attempt to read a `Message` using a `Reader`, recording it or the errors that prevented reading it.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(tunnel_id = self.local_tunnel_id))
+    )]
+    pub fn read<T: Borrow<[u8]>>(
+        &mut self,
+        reader: &mut impl Reader<T>,
+    ) -> Result<Message<T>, Vec<DecodeError>> {
+        let result = Message::try_read(reader);
+        match &result {
+            Ok(message) => self.record_received(message),
+            Err(errors) => errors
+                .iter()
+                .for_each(|e| self.statistics.record_decode_error(e)),
+        }
+        result
+    }
+
+    /// # Summary
+    /// Attempt to write a `Message` using a `Writer`, recording it if successful.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(tunnel_id = self.local_tunnel_id))
+    )]
+    pub fn write<T: Borrow<[u8]>>(
+        &mut self,
+        message: &Message<T>,
+        writer: &mut impl Writer,
+    ) -> EncodeResult<()> {
+        message.try_write(writer)?;
+        self.record_sent(message);
+        Ok(())
+    }
+
+    /// # Summary
+    /// Attempt to write a previously sent `ControlMessage` using a `Writer`, recording it as a retransmission if
+    /// successful.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tunnel_id = self.local_tunnel_id))
+    )]
+    pub fn retransmit(
+        &mut self,
+        message: &ControlMessage,
+        writer: &mut impl Writer,
+    ) -> EncodeResult<()> {
+        message.try_write(writer)?;
+        debug!(
+            session_id = message.session_id,
+            ns = message.ns,
+            "Retransmitted control message"
+        );
+        self.statistics.retransmissions += 1;
+        Ok(())
+    }
+
+    fn record_received<T: Borrow<[u8]>>(&mut self, message: &Message<T>) {
+        let session_id = match message {
+            Message::Control(control) => control.session_id,
+            Message::Data(data) => data.session_id,
+        };
+        let mut session = match session_id {
+            0 => None,
+            id => {
+                let session = self.sessions.get_mut(&id);
+                if session.is_none() {
+                    debug!(session_id = id, "Message for unknown session");
+                    self.statistics.unknown_session += 1;
+                }
+                session
+            }
+        };
+
+        match message {
+            Message::Control(control) => {
+                let Some(message_type) = control.message_type() else {
+                    trace!(nr = control.nr, "Received ZLB");
+                    self.statistics.zlb_received += 1;
+                    return;
+                };
+
+                if control.ns == self.expected_ns {
+                    self.expected_ns = self.expected_ns.wrapping_add(1);
+                } else {
+                    debug!(
+                        ns = control.ns,
+                        expected_ns = self.expected_ns,
+                        "Control message with unexpected Ns"
+                    );
+                    self.statistics.control_sequence_errors += 1;
+                }
+
+                MessageStatistics::record_control(
+                    &mut self.statistics.messages.control_received,
+                    message_type,
+                );
+                if message_type.is_ignorable() {
+                    debug!(?message_type, "Ignoring control message");
+                    return;
+                }
+                self.state = self.state.next(message_type);
+                self.peer.update(message_type, &control.avps);
+                if let Some(code) = teardown_result_code(message_type, &control.avps) {
+                    *self
+                        .statistics
+                        .teardowns_received
+                        .entry((message_type, code))
+                        .or_default() += 1;
+                }
+                if let Some(session) = session.as_mut() {
+                    session.record_control_received(message_type, &control.avps);
+                }
+                if message_type == MessageType::ConnectSpeedUpdateNotification {
+                    self.record_connect_speed_updates(&control.avps);
+                }
+                let session_id = Some(session_id).filter(|id| self.sessions.contains_key(id));
+                self.record_multicast_members(message_type, session_id, &control.avps, |x, id| {
+                    x.sessions.contains_key(&id).then_some(id)
+                });
+            }
+            Message::Data(data) => {
+                let length = data.data.borrow().len() as u64;
+                self.statistics.messages.data_packets_received += 1;
+                self.statistics.messages.data_bytes_received += length;
+                if let Some(session) = session.as_mut() {
+                    session.record_data_received(data.ns_nr, length);
+                }
+            }
+        }
+    }
+
+    fn record_connect_speed_updates(&mut self, avps: &[AVP]) {
+        for avp in avps.iter() {
+            let AVP::ConnectSpeedUpdate(update) = avp else {
+                continue;
+            };
+            // The remote session identifier of the peer is the locally assigned one
+            match self.sessions.get_mut(&update.remote_session_id) {
+                Some(session) => session.record_connect_speed_update(update),
+                None => {
+                    debug!(
+                        session_id = update.remote_session_id,
+                        "Connect speed update for unknown session"
+                    );
+                    self.statistics.unknown_session += 1;
+                }
+            }
+        }
+    }
+
+    fn record_sent<T: Borrow<[u8]>>(&mut self, message: &Message<T>) {
+        // Outgoing messages carry the session identifier assigned by the peer
+        let remote_session_id = match message {
+            Message::Control(control) => control.session_id,
+            Message::Data(data) => data.session_id,
+        };
+        let mut session = self
+            .sessions
+            .values_mut()
+            .find(|x| remote_session_id != 0 && x.remote_session_id() == remote_session_id);
+
+        match message {
+            Message::Control(control) => {
+                let Some(message_type) = control.message_type() else {
+                    self.statistics.zlb_sent += 1;
+                    return;
+                };
+                self.next_ns = control.ns.wrapping_add(1);
+
+                MessageStatistics::record_control(
+                    &mut self.statistics.messages.control_sent,
+                    message_type,
+                );
+                self.state = self.state.next(message_type);
+                if let Some(code) = teardown_result_code(message_type, &control.avps) {
+                    *self
+                        .statistics
+                        .teardowns_sent
+                        .entry((message_type, code))
+                        .or_default() += 1;
+                }
+                let session_id = session.as_mut().map(|session| {
+                    session.record_control_sent(message_type, &control.avps);
+                    session.local_session_id()
+                });
+                self.record_multicast_members(message_type, session_id, &control.avps, |x, id| {
+                    x.sessions
+                        .values()
+                        .find(|session| session.remote_session_id() == id)
+                        .map(Session::local_session_id)
+                });
+            }
+            Message::Data(data) => {
+                let length = data.data.borrow().len() as u64;
+                self.statistics.messages.data_packets_sent += 1;
+                self.statistics.messages.data_bytes_sent += length;
+                if let Some(session) = session.as_mut() {
+                    session.record_data_sent(length);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::avp::types::MessageType;
+use crate::common::DecodeError;
+use crate::session::{SessionState, SessionStatistics};
+use crate::tunnel::{PeerInfo, TunnelState};
+use std::collections::BTreeMap;
+
+/// # Summary
+/// Counters of messages sent and received, maintained both per tunnel and per session.
+///
+/// # Data members
+/// * `control_sent` - The number of control messages sent by `MessageType`, excluding retransmissions and ZLBs.
+/// * `control_received` - The number of control messages received by `MessageType`, excluding ZLBs.
+/// * `data_packets_sent` - The number of data messages sent.
+/// * `data_packets_received` - The number of data messages received.
+/// * `data_bytes_sent` - The number of payload octets of data messages sent.
+/// * `data_bytes_received` - The number of payload octets of data messages received.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MessageStatistics {
+    pub control_sent: BTreeMap<MessageType, u64>,
+    pub control_received: BTreeMap<MessageType, u64>,
+    pub data_packets_sent: u64,
+    pub data_packets_received: u64,
+    pub data_bytes_sent: u64,
+    pub data_bytes_received: u64,
+}
+
+impl MessageStatistics {
+    #[inline]
+    pub(crate) fn record_control(
+        counters: &mut BTreeMap<MessageType, u64>,
+        message_type: MessageType,
+    ) {
+        *counters.entry(message_type).or_default() += 1;
+    }
+
+    /// # Summary
+    /// Get the total number of control messages sent, excluding retransmissions and ZLBs.
+    #[inline]
+    pub fn control_sent_total(&self) -> u64 {
+        self.control_sent.values().sum()
+    }
+
+    /// # Summary
+    /// Get the total number of control messages received, excluding ZLBs.
+    #[inline]
+    pub fn control_received_total(&self) -> u64 {
+        self.control_received.values().sum()
+    }
+}
+
+/// # Summary
+/// Per-tunnel statistics maintained by a `Tunnel`.
+///
+/// # Data members
+/// * `messages` - Counters of messages sent and received on the tunnel, including those of its sessions.
+/// * `retransmissions` - The number of control messages retransmitted.
+/// * `zlb_sent` - The number of ZLB acknowledgements sent.
+/// * `zlb_received` - The number of ZLB acknowledgements received.
+/// * `control_sequence_errors` - The number of control messages received with an unexpected Ns.
+/// * `unknown_session` - The number of messages received for sessions unknown to the tunnel.
+/// * `decode_errors` - The number of decode errors by `DecodeError` variant name.
+/// * `teardowns_sent` - The number of `StopControlConnectionNotification` and `CallDisconnectNotify` messages sent,
+///   by `MessageType` and result code.
+/// * `teardowns_received` - The number of `StopControlConnectionNotification` and `CallDisconnectNotify` messages
+///   received, by `MessageType` and result code.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TunnelStatistics {
+    pub messages: MessageStatistics,
+    pub retransmissions: u64,
+    pub zlb_sent: u64,
+    pub zlb_received: u64,
+    pub control_sequence_errors: u64,
+    pub unknown_session: u64,
+    pub decode_errors: BTreeMap<&'static str, u64>,
+    pub teardowns_sent: BTreeMap<(MessageType, u16), u64>,
+    pub teardowns_received: BTreeMap<(MessageType, u16), u64>,
+}
+
+impl TunnelStatistics {
+    pub(crate) fn record_decode_error(&mut self, error: &DecodeError) {
+        *self.decode_errors.entry(error.name()).or_default() += 1;
+    }
+
+    /// # Summary
+    /// Get the total number of decode errors.
+    #[inline]
+    pub fn decode_errors_total(&self) -> u64 {
+        self.decode_errors.values().sum()
+    }
+}
+
+/// # Summary
+/// A point-in-time copy of the state and statistics of a `Session`.
+///
+/// # Data members
+/// * `remote_session_id` - The session identifier assigned by the peer.
+/// * `state` - The call state of the session.
+/// * `tx_connect_speed` - The most recently announced transmit connect speed in bits per second.
+/// * `rx_connect_speed` - The most recently announced receive connect speed in bits per second.
+/// * `statistics` - The per-session statistics.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SessionSnapshot {
+    pub remote_session_id: u16,
+    pub state: SessionState,
+    pub tx_connect_speed: Option<u64>,
+    pub rx_connect_speed: Option<u64>,
+    pub statistics: SessionStatistics,
+}
+
+/// # Summary
+/// A point-in-time copy of the state and statistics of a `Tunnel` and its sessions.
+///
+/// # Data members
+/// * `tunnel_id` - The locally assigned tunnel identifier.
+/// * `remote_tunnel_id` - The tunnel identifier assigned by the peer.
+/// * `state` - The state of the tunnel.
+/// * `peer` - The information announced by the peer.
+/// * `tunnel` - The per-tunnel statistics.
+/// * `sessions` - The per-session snapshots by locally assigned session identifier.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TunnelSnapshot {
+    pub tunnel_id: u16,
+    pub remote_tunnel_id: u16,
+    pub state: TunnelState,
+    pub peer: PeerInfo,
+    pub tunnel: TunnelStatistics,
+    pub sessions: BTreeMap<u16, SessionSnapshot>,
+}
@@ -0,0 +1,517 @@
+use crate::avp::types::{Accm, ConnectSpeedUpdate, FailoverSessionState, MessageType};
+use crate::avp::AVP;
+use crate::common::{DecodeError, SliceReader, VecWriter};
+use crate::session::SessionState;
+use crate::tunnel::*;
+use crate::{ControlMessage, DataMessage, Message};
+
+fn control(session_id: u16, ns: u16, avps: Vec<AVP>) -> Message<Vec<u8>> {
+    let mut message = ControlMessage {
+        length: 0,
+        tunnel_id: 1,
+        session_id,
+        ns,
+        nr: 0,
+        avps,
+    };
+    message.length = message.get_checked_length().unwrap() as u16;
+    Message::Control(message)
+}
+
+fn data(session_id: u16, ns: Option<u16>, payload: &[u8]) -> Message<Vec<u8>> {
+    Message::Data(DataMessage {
+        is_prioritized: false,
+        length: None,
+        tunnel_id: 1,
+        session_id,
+        ns_nr: ns.map(|ns| (ns, 0)),
+        offset: None,
+        data: payload.to_vec(),
+    })
+}
+
+fn encode(message: &Message<Vec<u8>>) -> Vec<u8> {
+    let mut w = VecWriter::new();
+    message.try_write(&mut w).unwrap();
+    w.data
+}
+
+#[test]
+fn received() {
+    let mut tunnel = Tunnel::new(1, 2);
+    tunnel.add_session(10, 20);
+
+    let messages = [
+        control(0, 0, vec![AVP::MessageType(MessageType::Hello)]),
+        control(0, 1, vec![]),
+        control(
+            10,
+            1,
+            vec![AVP::MessageType(MessageType::IncomingCallConnected)],
+        ),
+        // Duplicate Ns
+        control(0, 1, vec![AVP::MessageType(MessageType::Hello)]),
+        data(10, Some(0), &[1, 2, 3]),
+        data(10, Some(2), &[4]),
+        data(11, None, &[5, 6]),
+    ];
+    for message in messages.iter() {
+        let encoded = encode(message);
+        assert!(tunnel.read(&mut SliceReader::from(&encoded)).is_ok());
+    }
+
+    let statistics = tunnel.statistics();
+    assert_eq!(
+        statistics
+            .messages
+            .control_received
+            .get(&MessageType::Hello),
+        Some(&2)
+    );
+    assert_eq!(statistics.messages.control_received_total(), 3);
+    assert_eq!(statistics.zlb_received, 1);
+    assert_eq!(statistics.control_sequence_errors, 1);
+    assert_eq!(statistics.messages.data_packets_received, 3);
+    assert_eq!(statistics.messages.data_bytes_received, 6);
+    assert_eq!(statistics.unknown_session, 1);
+
+    let session = tunnel.session(10).unwrap().statistics();
+    assert_eq!(
+        session
+            .messages
+            .control_received
+            .get(&MessageType::IncomingCallConnected),
+        Some(&1)
+    );
+    assert_eq!(session.messages.data_packets_received, 2);
+    assert_eq!(session.messages.data_bytes_received, 4);
+    assert_eq!(session.data_sequence_errors, 1);
+}
+
+#[test]
+fn sent() {
+    let mut tunnel = Tunnel::new(1, 2);
+    let session = tunnel.add_session(10, 20);
+    let sli = session
+        .set_accm(Accm {
+            send_accm: [0; 4],
+            receive_accm: [0; 4],
+        })
+        .unwrap()
+        .unwrap();
+
+    let mut w = VecWriter::new();
+    tunnel
+        .write(&Message::<Vec<u8>>::Control(sli.clone()), &mut w)
+        .unwrap();
+    tunnel.write(&control(0, 0, vec![]), &mut w).unwrap();
+    tunnel.write(&data(20, None, &[1, 2]), &mut w).unwrap();
+    tunnel.retransmit(&sli, &mut w).unwrap();
+
+    let statistics = tunnel.statistics();
+    assert_eq!(
+        statistics
+            .messages
+            .control_sent
+            .get(&MessageType::SetLinkInfo),
+        Some(&1)
+    );
+    assert_eq!(statistics.zlb_sent, 1);
+    assert_eq!(statistics.retransmissions, 1);
+    assert_eq!(statistics.messages.data_bytes_sent, 2);
+
+    let session = tunnel.session(10).unwrap().statistics();
+    assert_eq!(session.messages.control_sent_total(), 1);
+    assert_eq!(session.messages.data_packets_sent, 1);
+    assert_eq!(session.set_link_info_sent, 1);
+}
+
+#[test]
+fn ignorable_message() {
+    let mut tunnel = Tunnel::new(1, 2);
+    tunnel.add_session(10, 20);
+
+    let messages = [
+        control(
+            0,
+            0,
+            vec![AVP::MessageType(MessageType::StartControlConnectionRequest)],
+        ),
+        control(10, 1, vec![AVP::MessageType(MessageType::from_code(30))]),
+        control(0, 2, vec![AVP::MessageType(MessageType::from_code(5))]),
+    ];
+    for message in messages.iter() {
+        let encoded = encode(message);
+        assert!(tunnel.read(&mut SliceReader::from(&encoded)).is_ok());
+    }
+
+    assert_eq!(tunnel.state(), TunnelState::Establishing);
+    assert_eq!(tunnel.expected_ns(), 3);
+    assert_eq!(tunnel.statistics().control_sequence_errors, 0);
+    assert_eq!(
+        tunnel
+            .statistics()
+            .messages
+            .control_received
+            .get(&MessageType::from_code(30)),
+        Some(&1)
+    );
+    assert_eq!(tunnel.session(10).unwrap().state(), SessionState::Idle);
+
+    // An unknown message type with the M bit set must clear the tunnel and cannot be decoded
+    let mut encoded = encode(&control(
+        0,
+        3,
+        vec![AVP::MessageType(MessageType::from_code(30))],
+    ));
+    encoded[12] |= 0x01;
+    assert_eq!(
+        tunnel.read(&mut SliceReader::from(&encoded)).err(),
+        Some(vec![DecodeError::UnknownMessageType(30)])
+    );
+}
+
+#[test]
+fn decode_errors() {
+    let mut tunnel = Tunnel::new(1, 2);
+
+    for input in [&[0x13][..], &[0x00, 0x00], &[0x00, 0x00, 0x00]] {
+        assert!(tunnel.read(&mut SliceReader::from(input)).is_err());
+    }
+
+    let statistics = tunnel.statistics();
+    assert_eq!(statistics.decode_errors_total(), 3);
+    assert_eq!(statistics.decode_errors.get("IncompleteFlags"), Some(&1));
+    assert_eq!(statistics.decode_errors.get("InvalidVersion"), Some(&2));
+    assert!(matches!(
+        tunnel.read(&mut SliceReader::from(&[0x13])),
+        Err(e) if e == [DecodeError::IncompleteFlags]
+    ));
+}
+
+#[test]
+fn snapshot() {
+    let mut tunnel = Tunnel::new(1, 2);
+    tunnel.add_session(10, 20);
+    tunnel.add_session(11, 21);
+
+    let encoded = encode(&data(10, None, &[1]));
+    tunnel.read(&mut SliceReader::from(&encoded)).unwrap();
+
+    let snapshot = tunnel.snapshot();
+    assert_eq!(snapshot.tunnel_id, 1);
+    assert_eq!(snapshot.tunnel, *tunnel.statistics());
+    assert_eq!(snapshot.sessions.len(), 2);
+    assert_eq!(
+        snapshot.sessions[&10]
+            .statistics
+            .messages
+            .data_packets_received,
+        1
+    );
+
+    // Snapshots are unaffected by later traffic
+    tunnel.read(&mut SliceReader::from(&encoded)).unwrap();
+    assert_eq!(snapshot.tunnel.messages.data_packets_received, 1);
+
+    tunnel.remove_session(11);
+    assert_eq!(tunnel.sessions().count(), 1);
+}
+
+#[test]
+fn states_and_teardowns() {
+    use crate::avp::types::{result_code::CdnCode, ResultCode};
+
+    let mut tunnel = Tunnel::new(1, 2);
+    tunnel.add_session(10, 20);
+    assert_eq!(tunnel.state(), TunnelState::Idle);
+
+    let mut w = VecWriter::new();
+    let mut ns = 0;
+    let mut receive = |tunnel: &mut Tunnel, session_id, avps| {
+        let encoded = encode(&control(session_id, ns, avps));
+        ns += 1;
+        tunnel.read(&mut SliceReader::from(&encoded)).unwrap();
+    };
+
+    receive(
+        &mut tunnel,
+        0,
+        vec![AVP::MessageType(MessageType::StartControlConnectionRequest)],
+    );
+    assert_eq!(tunnel.state(), TunnelState::Establishing);
+    receive(
+        &mut tunnel,
+        0,
+        vec![AVP::MessageType(
+            MessageType::StartControlConnectionConnected,
+        )],
+    );
+    assert_eq!(tunnel.state(), TunnelState::Established);
+
+    tunnel
+        .write(
+            &control(
+                20,
+                0,
+                vec![AVP::MessageType(MessageType::IncomingCallReply)],
+            ),
+            &mut w,
+        )
+        .unwrap();
+    assert_eq!(
+        tunnel.session(10).unwrap().state(),
+        SessionState::Establishing
+    );
+    receive(
+        &mut tunnel,
+        10,
+        vec![AVP::MessageType(MessageType::IncomingCallConnected)],
+    );
+    assert_eq!(
+        tunnel.session(10).unwrap().state(),
+        SessionState::Established
+    );
+
+    let result_code = ResultCode {
+        code: CdnCode::CallDisconnectedAdministrative.into(),
+        error: None,
+    };
+    tunnel
+        .write(
+            &control(
+                20,
+                1,
+                vec![
+                    AVP::MessageType(MessageType::CallDisconnectNotify),
+                    AVP::ResultCode(result_code.clone()),
+                ],
+            ),
+            &mut w,
+        )
+        .unwrap();
+    assert_eq!(tunnel.session(10).unwrap().state(), SessionState::Closed);
+    assert_eq!(
+        tunnel
+            .statistics()
+            .teardowns_sent
+            .get(&(MessageType::CallDisconnectNotify, 3)),
+        Some(&1)
+    );
+
+    let snapshot = tunnel.snapshot();
+    assert_eq!(snapshot.state, TunnelState::Established);
+    assert_eq!(snapshot.sessions[&10].state, SessionState::Closed);
+    assert_eq!(
+        snapshot.sessions[&10]
+            .statistics
+            .disconnect_cause
+            .as_ref()
+            .map(|x| &x.result_code),
+        Some(&result_code)
+    );
+}
+
+#[test]
+fn connect_speed_update() {
+    let mut lns = Tunnel::new(1, 2);
+    lns.add_session(10, 20);
+    let mut lac = Tunnel::new(2, 1);
+    lac.add_session(20, 10);
+    lac.add_session(21, 11);
+
+    // The LAC is asked for the speeds of a session known to both sides and of one known to the LNS only
+    let request = lns.connect_speed_update_request(&[10, 99]).unwrap();
+    assert_eq!(request.tunnel_id, 2);
+    assert_eq!(
+        request.avps,
+        vec![
+            AVP::MessageType(MessageType::ConnectSpeedUpdateRequest),
+            AVP::ConnectSpeedUpdate(ConnectSpeedUpdate {
+                remote_session_id: 20,
+                tx_connect_speed: 0,
+                rx_connect_speed: 0,
+            }),
+        ]
+    );
+    let encoded = encode(&Message::Control(request));
+    let Ok(Message::Control(request)) = lac.read(&mut SliceReader::from(&encoded)) else {
+        panic!("Expected a control message");
+    };
+
+    let requested: Vec<u16> = request
+        .avps
+        .iter()
+        .filter_map(|avp| match avp {
+            AVP::ConnectSpeedUpdate(x) => Some(x.remote_session_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(requested, [20]);
+
+    lac.session_mut(20)
+        .unwrap()
+        .set_connect_speeds(8_000_000_000, 1_000_000);
+    lac.session_mut(21).unwrap().set_connect_speeds(1, 1);
+    let notification = lac.connect_speed_update_notification(&[20, 21]).unwrap();
+    assert_eq!(notification.avps.len(), 3);
+
+    // Session 11 is unknown to the LNS
+    let encoded = encode(&Message::Control(notification));
+    lns.read(&mut SliceReader::from(&encoded)).unwrap();
+    let session = lns.session(10).unwrap();
+    assert_eq!(session.tx_connect_speed(), Some(8_000_000_000));
+    assert_eq!(session.rx_connect_speed(), Some(1_000_000));
+    assert_eq!(lns.statistics().unknown_session, 1);
+}
+
+#[test]
+fn failover_recovery() {
+    let mut lac = Tunnel::new(2, 1);
+    lac.add_session(20, 10);
+    lac.add_session(21, 11);
+    let hello = || vec![AVP::MessageType(MessageType::Hello)];
+    for ns in 0..3 {
+        let mut w = VecWriter::new();
+        lac.write(&control(10, ns, hello()), &mut w).unwrap();
+    }
+    for ns in 0..2 {
+        let encoded = encode(&control(0, ns, hello()));
+        lac.read(&mut SliceReader::from(&encoded)).unwrap();
+    }
+    assert_eq!(lac.next_ns(), 3);
+    assert_eq!(lac.expected_ns(), 2);
+
+    // The LNS restarts with only one of the sessions restored
+    let mut lns = Tunnel::new(1, 2);
+    lns.add_session(10, 20);
+    assert!(lac.is_recovered_by(&lns.tunnel_recovery()));
+    assert!(!Tunnel::new(1, 3).is_recovered_by(&lns.tunnel_recovery()));
+
+    lns.resynchronise(&lac.suggested_control_sequence());
+    assert_eq!(lns.state(), TunnelState::Established);
+    assert_eq!(lns.session(10).unwrap().state(), SessionState::Established);
+    assert_eq!(lns.next_ns(), 2);
+    assert_eq!(lns.expected_ns(), 3);
+
+    let mut w = VecWriter::new();
+    lns.write(&control(0, lns.next_ns(), hello()), &mut w)
+        .unwrap();
+    lac.read(&mut SliceReader::from(&w.data)).unwrap();
+    assert_eq!(lac.statistics().control_sequence_errors, 0);
+
+    // The LAC clears the session lost by the LNS
+    let query = lac.failover_session_query(&[20, 21, 99]).unwrap();
+    assert_eq!(query.tunnel_id, 1);
+    let response = lns.failover_session_response(&query).unwrap();
+    assert_eq!(response.tunnel_id, 2);
+    assert_eq!(
+        response.avps,
+        vec![
+            AVP::MessageType(MessageType::FailoverSessionResponse),
+            AVP::FailoverSessionState(FailoverSessionState {
+                sender_session_id: 10,
+                receiver_session_id: 20,
+            }),
+            AVP::FailoverSessionState(FailoverSessionState {
+                sender_session_id: 0,
+                receiver_session_id: 21,
+            }),
+        ]
+    );
+    assert_eq!(lac.handle_failover_session_response(&response), [21]);
+    assert!(lac.session(20).is_some());
+    assert!(lac.session(21).is_none());
+}
+
+#[test]
+fn multicast_session() {
+    let mut lns = Tunnel::new(1, 2);
+    let mut lac = Tunnel::new(2, 1);
+    for (lns_id, lac_id) in [(10, 20), (11, 21), (12, 22)] {
+        lns.add_session(lns_id, lac_id);
+        lac.add_session(lac_id, lns_id);
+    }
+    assert_eq!(lns.multicast_session_information(&[10], &[]), Ok(None));
+
+    lns.add_multicast_session(30, 40);
+    lac.add_multicast_session(40, 30);
+    let transmit = |from: &mut Tunnel, to: &mut Tunnel, message: ControlMessage| {
+        let mut w = VecWriter::new();
+        from.write(&Message::<Vec<u8>>::Control(message), &mut w)
+            .unwrap();
+        to.read(&mut SliceReader::from(&w.data)).unwrap();
+    };
+
+    let message = lns
+        .multicast_session_information(&[10, 11, 99], &[])
+        .unwrap()
+        .unwrap();
+    assert_eq!(message.session_id, 40);
+    transmit(&mut lns, &mut lac, message);
+    assert_eq!(lns.multicast_members().collect::<Vec<_>>(), [10, 11]);
+    assert_eq!(lac.multicast_members().collect::<Vec<_>>(), [20, 21]);
+
+    let message = lns
+        .multicast_session_information(&[12], &[10])
+        .unwrap()
+        .unwrap();
+    transmit(&mut lns, &mut lac, message);
+    assert_eq!(lns.multicast_members().collect::<Vec<_>>(), [11, 12]);
+    assert_eq!(lac.multicast_members().collect::<Vec<_>>(), [21, 22]);
+
+    // Members leave when their call is disconnected
+    let message = ControlMessage::builder(MessageType::CallDisconnectNotify)
+        .tunnel(1)
+        .session(11)
+        .build()
+        .unwrap();
+    transmit(&mut lac, &mut lns, message);
+    assert_eq!(lns.multicast_members().collect::<Vec<_>>(), [12]);
+    assert_eq!(lac.multicast_members().collect::<Vec<_>>(), [22]);
+
+    lns.remove_session(30);
+    assert!(lns.multicast_session().is_none());
+    assert_eq!(lns.multicast_members().count(), 0);
+    assert_eq!(lac.multicast_session().unwrap().local_session_id(), 40);
+}
+
+#[test]
+fn multicast_failover() {
+    let mut lns = Tunnel::new(1, 2);
+    let mut lac = Tunnel::new(2, 1);
+    for (lns_id, lac_id) in [(10, 20), (11, 21)] {
+        lns.add_session(lns_id, lac_id);
+        lac.add_session(lac_id, lns_id);
+    }
+    lns.add_multicast_session(30, 40);
+    lac.add_multicast_session(40, 30);
+    let message = lns
+        .multicast_session_information(&[10, 11], &[])
+        .unwrap()
+        .unwrap();
+    let mut w = VecWriter::new();
+    lns.write(&Message::<Vec<u8>>::Control(message), &mut w)
+        .unwrap();
+    lac.read(&mut SliceReader::from(&w.data)).unwrap();
+    assert_eq!(lac.multicast_members().collect::<Vec<_>>(), [20, 21]);
+
+    // A member session lost by the LNS leaves the multicast session
+    let mut lns = Tunnel::new(1, 2);
+    lns.add_session(10, 20);
+    lns.add_multicast_session(30, 40);
+    let query = lac.failover_session_query(&[20, 21, 40]).unwrap();
+    let response = lns.failover_session_response(&query).unwrap();
+    assert_eq!(lac.handle_failover_session_response(&response), [21]);
+    assert_eq!(lac.multicast_members().collect::<Vec<_>>(), [20]);
+
+    // A multicast session lost by the LNS takes its members along
+    let mut lns = Tunnel::new(1, 2);
+    lns.add_session(10, 20);
+    let query = lac.failover_session_query(&[20, 40]).unwrap();
+    let response = lns.failover_session_response(&query).unwrap();
+    assert_eq!(lac.handle_failover_session_response(&response), [40]);
+    assert!(lac.multicast_session().is_none());
+    assert_eq!(lac.multicast_members().count(), 0);
+    assert!(lac.session(20).is_some());
+}